@@ -0,0 +1,311 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ContentRegistryError;
+use crate::contexts::ecosystem_subscription::{ecosystem_subscription_expiry, patron_subscription_expiry};
+use crate::contexts::rent::{fetch_rental_attributes, rental_expires_at};
+use crate::contexts::trial::{ecosystem_trial_expiry, patron_trial_expiry};
+use crate::contexts::subscription_vault::{ecosystem_vault_expiry, patron_vault_expiry};
+
+// ============================================================================
+// CHECK CONTENT ACCESS - Single authoritative access decision
+// ============================================================================
+
+/// Evaluate every way a user can access content: public visibility, owned
+/// content NFT or rental, owned bundle NFT or rental (with the BundleItem
+/// linking the bundle to the content), creator or ecosystem subscription, vault or trial
+/// All proofs are optional - pass whichever the user holds
+#[derive(Accounts)]
+pub struct CheckContentAccess<'info> {
+    /// Content to check access for
+    pub content: Box<Account<'info, ContentEntry>>,
+
+    /// CHECK: Optional content NFT or rental asset - ownership and collection verified in handler
+    pub content_asset: Option<AccountInfo<'info>>,
+
+    /// Optional bundle containing the content
+    pub bundle: Option<Box<Account<'info, Bundle>>>,
+
+    /// CHECK: Optional bundle NFT or rental asset - ownership and collection verified in handler
+    pub bundle_asset: Option<AccountInfo<'info>>,
+
+    /// Optional link between the bundle and the content
+    #[account(
+        seeds = [BUNDLE_ITEM_SEED, bundle_item.bundle.as_ref(), content.key().as_ref()],
+        bump
+    )]
+    pub bundle_item: Option<Box<Account<'info, BundleItem>>>,
+
+    /// Optional: Content creator's patron config (tier ladder)
+    pub patron_config: Option<Box<Account<'info, CreatorPatronConfig>>>,
+
+    /// Optional: User's patron subscription to the content creator
+    /// CHECK: PDA verified if account exists
+    pub patron_subscription: Option<AccountInfo<'info>>,
+
+    /// Optional: Streamflow stream paying for the patron subscription
+    /// CHECK: Matched against the subscription's stream_id and Streamflow ownership
+    pub patron_stream: Option<AccountInfo<'info>>,
+
+    /// Optional: User's ecosystem subscription
+    /// CHECK: PDA verified if account exists
+    pub ecosystem_subscription: Option<AccountInfo<'info>>,
+
+    /// Optional: Streamflow stream paying for the ecosystem subscription
+    /// CHECK: Matched against the subscription's stream_id and Streamflow ownership
+    pub ecosystem_stream: Option<AccountInfo<'info>>,
+
+    /// Optional: User's trial marker for the content creator
+    /// CHECK: PDA verified if account exists
+    pub patron_trial: Option<AccountInfo<'info>>,
+
+    /// Optional: User's ecosystem trial marker
+    /// CHECK: PDA verified if account exists
+    pub ecosystem_trial: Option<AccountInfo<'info>>,
+
+    /// Optional: User's subscription vault for the content creator
+    /// CHECK: PDA verified if account exists
+    pub patron_vault: Option<AccountInfo<'info>>,
+
+    /// Optional: User's ecosystem subscription vault
+    /// CHECK: PDA verified if account exists
+    pub ecosystem_vault: Option<AccountInfo<'info>>,
+
+    /// The user checking access
+    pub user: Signer<'info>,
+}
+
+/// An asset the user holds in a content or bundle collection
+enum HeldAsset {
+    /// Permanent edition NFT
+    Edition,
+    /// Unexpired rental NFT
+    Rental { expires_at: i64 },
+}
+
+/// Classify `asset` if `user` holds it in `collection`
+/// Rentals carry an expires_at attribute; expired rentals grant nothing
+fn held_asset(asset: &AccountInfo, user: &Pubkey, collection: &Pubkey, now: i64) -> Result<Option<HeldAsset>> {
+    if !crate::verify_core_nft_ownership(asset, user, collection)? {
+        return Ok(None);
+    }
+    let rental_expiry = fetch_rental_attributes(asset)
+        .ok()
+        .and_then(|(_, attributes)| rental_expires_at(&attributes).ok());
+    Ok(match rental_expiry {
+        None => Some(HeldAsset::Edition),
+        Some(expires_at) if now <= expires_at => Some(HeldAsset::Rental { expires_at }),
+        Some(_) => None,
+    })
+}
+
+impl<'info> CheckContentAccess<'info> {
+    /// Evaluate access at `now`
+    /// Permanent grants are preferred over rentals, rentals over subscriptions
+    ///
+    /// Visibility Levels:
+    /// - Level 0: Public - anyone
+    /// - Level 1: Ecosystem sub OR creator sub OR NFT/Rental
+    /// - Level 2: Creator sub OR NFT/Rental
+    /// - Level 3: NFT/Rental only
+    pub(crate) fn evaluate(&self, now: i64) -> Result<AccessDecision> {
+        let content = &self.content;
+        let user = self.user.key();
+        let visibility = content.visibility_level;
+
+        let decision = |source: Option<AccessSource>, expires_at: Option<i64>| AccessDecision {
+            content: content.key(),
+            user,
+            visibility_level: visibility,
+            granted: source.is_some(),
+            source,
+            expires_at,
+        };
+
+        if visibility == 0 {
+            return Ok(decision(Some(AccessSource::Public), None));
+        }
+
+        let mut grants: Vec<(AccessSource, Option<i64>)> = Vec::new();
+
+        if let Some(asset) = &self.content_asset {
+            match held_asset(asset, &user, &content.collection_asset, now)? {
+                Some(HeldAsset::Edition) => grants.push((AccessSource::ContentNft, None)),
+                Some(HeldAsset::Rental { expires_at }) => grants.push((AccessSource::ContentRental, Some(expires_at))),
+                None => {}
+            }
+        }
+
+        // Bundle grants require the BundleItem linking this bundle to the content
+        if let (Some(bundle), Some(asset), Some(item)) = (&self.bundle, &self.bundle_asset, &self.bundle_item) {
+            if item.bundle == bundle.key() {
+                match held_asset(asset, &user, &bundle.collection_asset, now)? {
+                    Some(HeldAsset::Edition) => grants.push((AccessSource::BundleNft, None)),
+                    Some(HeldAsset::Rental { expires_at }) => grants.push((AccessSource::BundleRental, Some(expires_at))),
+                    None => {}
+                }
+            }
+        }
+
+        if let Some(info) = &self.patron_subscription {
+            let patron_config = self.patron_config.as_deref().map(|c| &**c);
+            if let Some(expires_at) = patron_subscription_expiry(
+                info,
+                patron_config,
+                self.patron_stream.as_ref(),
+                &user,
+                &content.creator,
+                visibility,
+                now,
+            )? {
+                grants.push((AccessSource::CreatorSubscription, Some(expires_at)));
+            }
+        }
+
+        if let Some(info) = &self.patron_vault {
+            let patron_config = self.patron_config.as_deref().map(|c| &**c);
+            if let Some(expires_at) = patron_vault_expiry(info, patron_config, &user, &content.creator, visibility, now)? {
+                grants.push((AccessSource::CreatorSubscription, Some(expires_at)));
+            }
+        }
+
+        if let Some(info) = &self.patron_trial {
+            let patron_config = self.patron_config.as_deref().map(|c| &**c);
+            if let Some(expires_at) = patron_trial_expiry(info, patron_config, &user, &content.creator, visibility, now)? {
+                grants.push((AccessSource::CreatorTrial, Some(expires_at)));
+            }
+        }
+
+        if visibility == 1 {
+            if let Some(info) = &self.ecosystem_subscription {
+                if let Some(expires_at) = ecosystem_subscription_expiry(info, self.ecosystem_stream.as_ref(), &user, now)? {
+                    grants.push((AccessSource::EcosystemSubscription, Some(expires_at)));
+                }
+            }
+            if let Some(info) = &self.ecosystem_vault {
+                if let Some(expires_at) = ecosystem_vault_expiry(info, &user, now)? {
+                    grants.push((AccessSource::EcosystemSubscription, Some(expires_at)));
+                }
+            }
+            if let Some(info) = &self.ecosystem_trial {
+                if let Some(expires_at) = ecosystem_trial_expiry(info, &user, now)? {
+                    grants.push((AccessSource::EcosystemTrial, Some(expires_at)));
+                }
+            }
+        }
+
+        // Permanent grant first, otherwise the one lasting longest
+        let best = grants
+            .into_iter()
+            .max_by_key(|(_, expires_at)| expires_at.unwrap_or(i64::MAX));
+
+        Ok(match best {
+            Some((source, expires_at)) => decision(Some(source), expires_at),
+            None => decision(None, None),
+        })
+    }
+}
+
+/// Return the access decision for the user (via return data)
+/// Denials are returned as granted = false rather than an error so callers can branch
+pub fn handle_check_content_access(ctx: Context<CheckContentAccess>) -> Result<AccessDecision> {
+    let decision = ctx.accounts.evaluate(Clock::get()?.unix_timestamp)?;
+
+    if decision.granted {
+        msg!("Access granted: {:?}", decision.source);
+    } else {
+        msg!("Access denied: no valid grant for visibility level {}", decision.visibility_level);
+    }
+
+    Ok(decision)
+}
+
+// ============================================================================
+// ACCESS GRANTS - Short-lived proofs for the decryption key service
+// ============================================================================
+
+/// Run the access check and record the result in an AccessGrant PDA
+#[derive(Accounts)]
+#[instruction(session: Pubkey)]
+pub struct IssueAccessGrant<'info> {
+    pub access: CheckContentAccess<'info>,
+
+    /// Re-issuing for the same session refreshes the grant
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + AccessGrant::INIT_SPACE,
+        seeds = [
+            ACCESS_GRANT_SEED,
+            access.content.key().as_ref(),
+            access.user.key().as_ref(),
+            session.as_ref(),
+        ],
+        bump
+    )]
+    pub access_grant: Box<Account<'info, AccessGrant>>,
+
+    /// Pays the grant rent (refunded to the user on close)
+    #[account(mut, address = access.user.key() @ ContentRegistryError::Unauthorized)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_issue_access_grant(ctx: Context<IssueAccessGrant>, session: Pubkey) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let decision = ctx.accounts.access.evaluate(now)?;
+    let source = decision.source.ok_or(ContentRegistryError::AccessDenied)?;
+
+    // Never outlive the rental or subscription that granted access
+    let max_expiry = now + ACCESS_GRANT_DURATION;
+    let expires_at = decision.expires_at.map_or(max_expiry, |e| e.min(max_expiry));
+
+    let grant = &mut ctx.accounts.access_grant;
+    grant.user = decision.user;
+    grant.content = decision.content;
+    grant.source = source;
+    grant.expires_at = expires_at;
+    grant.session = session;
+    grant.issued_at = now;
+
+    msg!("Access grant issued: {:?} until {}", source, expires_at);
+
+    Ok(())
+}
+
+/// Close an AccessGrant - anyone after expiry, the user at any time
+/// Rent is refunded to the user
+#[derive(Accounts)]
+pub struct CloseAccessGrant<'info> {
+    #[account(
+        mut,
+        seeds = [
+            ACCESS_GRANT_SEED,
+            access_grant.content.as_ref(),
+            access_grant.user.as_ref(),
+            access_grant.session.as_ref(),
+        ],
+        bump,
+        has_one = user,
+        close = user
+    )]
+    pub access_grant: Box<Account<'info, AccessGrant>>,
+
+    /// CHECK: Grant owner receiving the rent refund
+    #[account(mut)]
+    pub user: AccountInfo<'info>,
+
+    pub caller: Signer<'info>,
+}
+
+pub fn handle_close_access_grant(ctx: Context<CloseAccessGrant>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.caller.key() == ctx.accounts.user.key() || ctx.accounts.access_grant.is_expired(now),
+        ContentRegistryError::AccessGrantNotExpired
+    );
+
+    msg!("Access grant closed");
+
+    Ok(())
+}
@@ -0,0 +1,625 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::ContentRegistryError;
+use crate::events::{AuctionBidEvent, NftMintEvent};
+use crate::contexts::simple_mint::{SIMPLE_NFT_SEED, determine_rarity_from_bytes};
+use crate::contexts::mint_request::{pay_from_escrow, load_randomness, read_revealed_randomness};
+use crate::contexts::content_edition::{ContentEditionPools, EditionNftAccounts};
+use crate::MPL_CORE_ID;
+
+// ============================================================================
+// ENGLISH AUCTION - 1-of-1 editions (Artwork/Photo)
+// ============================================================================
+// 1. create_auction: creator opens an auction; the single edition is reserved
+//    via content.pending_count so simple_mint/request_mint can't sell it
+// 2. place_bid: the bid is escrowed in the Auction PDA and the previous highest
+//    bidder is refunded. Late bids extend end_at (anti-sniping)
+// 3. commit_auction_randomness: after end_at the winner commits a fresh
+//    Switchboard randomness account once (same rules as request_mint)
+// 4. settle_auction: in the oracle's reveal slot, mints the NFT to the winner
+//    (same edition registration as simple_mint) and pays the winning bid out
+//    with the 80/5/3/12 primary split. Anyone can settle with the forfeited
+//    roll (lowest available tier) once the commitment is MINT_REQUEST_TIMEOUT
+//    old, or AUCTION_SETTLE_GRACE after end_at if nothing was committed, so a
+//    winner can't stall or re-roll
+// 5. cancel_auction: creator closes an auction that has no bids
+//
+// Content type (Artwork/Photo) lives in the off-chain collection metadata and
+// isn't recorded in ContentEntry, so it can't be enforced here; the 1-of-1
+// requirement is enforced via max_supply, which is frozen while the auction
+// reserves the edition.
+
+// ============================================================================
+// CREATE AUCTION
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CreateAuction<'info> {
+    #[account(
+        mut,
+        has_one = creator
+    )]
+    pub content: Box<Account<'info, ContentEntry>>,
+
+    #[account(
+        has_one = creator,
+        seeds = [MINT_CONFIG_SEED, content.key().as_ref()],
+        bump
+    )]
+    pub mint_config: Box<Account<'info, MintConfig>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Auction::INIT_SPACE,
+        seeds = [AUCTION_SEED, content.key().as_ref()],
+        bump
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_auction(
+    ctx: Context<CreateAuction>,
+    reserve_price: u64,
+    min_increment_bps: u16,
+    start_at: i64,
+    end_at: i64,
+    extension_window: Option<i64>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let content = &ctx.accounts.content;
+    let mint_config = &ctx.accounts.mint_config;
+
+    // Only unminted 1-of-1 editions, priced in SOL (escrow is lamports)
+    // Content type isn't stored on-chain, so Artwork/Photo can't be checked here
+    require!(
+        mint_config.max_supply == Some(1)
+            && mint_config.currency == PaymentCurrency::Sol
+            && content.minted_count == 0
+            && content.pending_count == 0,
+        ContentRegistryError::NotAuctionable
+    );
+
+    let extension_window = extension_window.unwrap_or(DEFAULT_AUCTION_EXTENSION);
+    require!(
+        MintConfig::validate_price(reserve_price, PaymentCurrency::Sol),
+        ContentRegistryError::PriceTooLow
+    );
+    require!(
+        end_at - start_at >= MIN_AUCTION_DURATION
+            && end_at > now
+            && min_increment_bps <= 10000
+            && (0..=MAX_AUCTION_EXTENSION).contains(&extension_window),
+        ContentRegistryError::InvalidAuctionParams
+    );
+
+    let auction = &mut ctx.accounts.auction;
+    auction.content = content.key();
+    auction.creator = ctx.accounts.creator.key();
+    auction.reserve_price = reserve_price;
+    auction.min_increment_bps = min_increment_bps;
+    auction.start_at = start_at;
+    auction.end_at = end_at;
+    auction.extension_window = extension_window;
+    auction.highest_bidder = None;
+    auction.highest_bid = 0;
+    auction.platform = None;
+    auction.bid_count = 0;
+    auction.created_at = now;
+    auction.randomness_account = None;
+    auction.randomness_seed_slot = 0;
+    auction.randomness_committed_at = 0;
+
+    // Reserve the single edition until settled or cancelled
+    ctx.accounts.content.increment_pending();
+
+    msg!("Auction created: reserve={} lamports, ends at {}", reserve_price, end_at);
+
+    Ok(())
+}
+
+// ============================================================================
+// PLACE BID
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct PlaceBid<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, auction.content.as_ref()],
+        bump
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+
+    /// CHECK: Previous highest bidder (refunded) - required once the auction has a bid
+    #[account(mut)]
+    pub previous_bidder: Option<AccountInfo<'info>>,
+
+    /// CHECK: Optional platform wallet for commission (recorded for settlement)
+    pub platform: Option<AccountInfo<'info>>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_place_bid(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let auction = &ctx.accounts.auction;
+
+    require!(auction.is_open(now), ContentRegistryError::AuctionNotOpen);
+    require!(amount >= auction.min_next_bid(), ContentRegistryError::BidTooLow);
+
+    // Escrow the new bid
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.bidder.to_account_info(),
+                to: ctx.accounts.auction.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    // Refund the outbid bidder
+    if let Some(previous) = ctx.accounts.auction.highest_bidder {
+        let previous_bidder = ctx.accounts.previous_bidder.as_ref()
+            .ok_or(ContentRegistryError::InvalidBidder)?;
+        require!(previous_bidder.key() == previous, ContentRegistryError::InvalidBidder);
+        pay_from_escrow(
+            &ctx.accounts.auction.to_account_info(),
+            previous_bidder,
+            ctx.accounts.auction.highest_bid,
+        )?;
+    }
+
+    let bidder_key = ctx.accounts.bidder.key();
+    let auction = &mut ctx.accounts.auction;
+    auction.highest_bidder = Some(bidder_key);
+    auction.highest_bid = amount;
+    auction.platform = ctx.accounts.platform.as_ref().map(|p| p.key());
+    auction.bid_count += 1;
+    auction.extend_if_sniped(now);
+
+    emit!(AuctionBidEvent {
+        auction: auction.key(),
+        content: auction.content,
+        bidder: bidder_key,
+        amount,
+        end_at: auction.end_at,
+        timestamp: now,
+    });
+
+    msg!("Bid placed: {} lamports, auction ends at {}", amount, auction.end_at);
+
+    Ok(())
+}
+
+// ============================================================================
+// COMMIT AUCTION RANDOMNESS
+// ============================================================================
+
+/// Winner commits the Switchboard randomness account for the rarity roll
+#[derive(Accounts)]
+pub struct CommitAuctionRandomness<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, auction.content.as_ref()],
+        bump,
+        constraint = auction.highest_bidder == Some(winner.key()) @ ContentRegistryError::InvalidBidder
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+
+    /// Switchboard randomness account committed in the previous slot
+    /// CHECK: Owner and layout verified in handler
+    pub randomness_account: AccountInfo<'info>,
+
+    pub winner: Signer<'info>,
+}
+
+pub fn handle_commit_auction_randomness(ctx: Context<CommitAuctionRandomness>) -> Result<()> {
+    let clock = Clock::get()?;
+    let auction = &ctx.accounts.auction;
+    require!(clock.unix_timestamp >= auction.end_at, ContentRegistryError::AuctionNotEnded);
+
+    // One-shot: a commitment is never replaced, so withholding the reveal can't
+    // buy a re-roll - once it times out only the forfeited roll is left
+    require!(auction.randomness_account.is_none(), ContentRegistryError::RandomnessAlreadyCommitted);
+
+    // Randomness must have been committed in the previous slot and not yet revealed
+    let randomness = load_randomness(&ctx.accounts.randomness_account)?;
+    require!(
+        randomness.seed_slot == clock.slot.saturating_sub(1) && randomness.reveal_slot == 0,
+        ContentRegistryError::RandomnessAlreadyRevealed
+    );
+
+    let auction = &mut ctx.accounts.auction;
+    auction.randomness_account = Some(ctx.accounts.randomness_account.key());
+    auction.randomness_seed_slot = randomness.seed_slot;
+    auction.randomness_committed_at = clock.unix_timestamp;
+
+    msg!("Auction randomness committed, randomness slot {}", randomness.seed_slot);
+
+    Ok(())
+}
+
+// ============================================================================
+// SETTLE AUCTION
+// ============================================================================
+
+/// Mint the edition to the winner and pay out the winning bid
+/// Permissionless - the caller pays rent for the new NFT accounts
+/// Must run in the oracle's reveal slot unless the roll has been forfeited
+#[derive(Accounts)]
+pub struct SettleAuction<'info> {
+    // =========================================================================
+    // Core accounts
+    // =========================================================================
+
+    #[account(
+        seeds = [ECOSYSTEM_CONFIG_SEED],
+        bump
+    )]
+    pub ecosystem_config: Box<Account<'info, EcosystemConfig>>,
+
+    #[account(mut)]
+    pub content: Box<Account<'info, ContentEntry>>,
+
+    /// MintConfig PDA - authority for collection operations (signs NFT creation)
+    #[account(
+        mut,
+        seeds = [MINT_CONFIG_SEED, content.key().as_ref()],
+        bump
+    )]
+    pub mint_config: Box<Account<'info, MintConfig>>,
+
+    /// Auction - escrow is paid out, remaining rent returns to creator
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, content.key().as_ref()],
+        bump,
+        close = creator
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+
+    /// Content-specific reward pool (IMMEDIATE distribution)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ContentRewardPool::INIT_SPACE,
+        seeds = [CONTENT_REWARD_POOL_SEED, content.key().as_ref()],
+        bump
+    )]
+    pub content_reward_pool: Box<Account<'info, ContentRewardPool>>,
+
+    /// The Metaplex Core Collection asset
+    /// CHECK: Verified via content.collection_asset
+    #[account(
+        mut,
+        constraint = collection_asset.key() == content.collection_asset @ ContentRegistryError::InvalidCollection
+    )]
+    pub collection_asset: AccountInfo<'info>,
+
+    /// CHECK: Creator to receive payment and auction rent
+    #[account(mut, constraint = content.creator == creator.key())]
+    pub creator: AccountInfo<'info>,
+
+    /// CHECK: Ecosystem treasury
+    #[account(mut, constraint = ecosystem_config.treasury == treasury.key())]
+    pub treasury: AccountInfo<'info>,
+
+    /// CHECK: Platform wallet recorded with the winning bid (must match)
+    #[account(mut)]
+    pub platform: Option<AccountInfo<'info>>,
+
+    // =========================================================================
+    // NFT accounts
+    // =========================================================================
+
+    /// CHECK: NFT asset - PDA based on winner, content, and edition
+    #[account(
+        mut,
+        seeds = [SIMPLE_NFT_SEED, winner.key().as_ref(), content.key().as_ref(), &(content.minted_count + 1).to_le_bytes()],
+        bump
+    )]
+    pub nft_asset: AccountInfo<'info>,
+
+    /// Unified NFT reward state - tracks debts for all pools + rarity
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + UnifiedNftRewardState::INIT_SPACE,
+        seeds = [UNIFIED_NFT_REWARD_STATE_SEED, nft_asset.key().as_ref()],
+        bump
+    )]
+    pub unified_nft_state: Box<Account<'info, UnifiedNftRewardState>>,
+
+    // =========================================================================
+    // Subscription pool accounts (LAZY distribution pools)
+    // =========================================================================
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_HOLDER_POOL_SEED],
+        bump
+    )]
+    pub global_holder_pool: Box<Account<'info, GlobalHolderPool>>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_DIST_POOL_SEED],
+        bump
+    )]
+    pub creator_dist_pool: Box<Account<'info, CreatorDistPool>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CreatorPatronPool::INIT_SPACE,
+        seeds = [CREATOR_PATRON_POOL_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_patron_pool: Box<Account<'info, CreatorPatronPool>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CreatorWeight::INIT_SPACE,
+        seeds = [CREATOR_WEIGHT_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_weight: Box<Account<'info, CreatorWeight>>,
+
+    /// CHECK: PDA verified by seeds
+    #[account(
+        mut,
+        seeds = [CREATOR_PATRON_TREASURY_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_patron_treasury: AccountInfo<'info>,
+
+    /// CHECK: PDA verified by seeds
+    #[account(
+        mut,
+        seeds = [ECOSYSTEM_STREAMING_TREASURY_SEED],
+        bump
+    )]
+    pub ecosystem_streaming_treasury: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [ECOSYSTEM_EPOCH_STATE_SEED],
+        bump
+    )]
+    pub ecosystem_epoch_state: Box<Account<'info, EcosystemEpochState>>,
+
+    // =========================================================================
+    // Winner, payer and system accounts
+    // =========================================================================
+
+    /// CHECK: Highest bidder recorded in the auction (receives the NFT)
+    #[account(
+        constraint = auction.highest_bidder == Some(winner.key()) @ ContentRegistryError::InvalidBidder
+    )]
+    pub winner: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Switchboard randomness account committed by the winner
+    /// CHECK: Must match auction.randomness_account, layout verified in handler
+    pub randomness_account: Option<AccountInfo<'info>>,
+
+    /// CHECK: MPL Core program
+    #[account(address = MPL_CORE_ID)]
+    pub mpl_core_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SettleAuction<'info> {
+    pub fn handler(ctx: Context<SettleAuction>, content_name: String) -> Result<()> {
+        require!(
+            !content_name.is_empty() && content_name.len() <= 32,
+            ContentRegistryError::InvalidContentName
+        );
+
+        let clock = Clock::get()?;
+        let timestamp = clock.unix_timestamp;
+
+        let winner_key = ctx.accounts.winner.key();
+        let content_key = ctx.accounts.content.key();
+        let creator_key = ctx.accounts.creator.key();
+        let nft_asset_key = ctx.accounts.nft_asset.key();
+
+        // =====================================================================
+        // STEP 0: Validation
+        // =====================================================================
+
+        require!(timestamp >= ctx.accounts.auction.end_at, ContentRegistryError::AuctionNotEnded);
+        require!(
+            ctx.accounts.platform.as_ref().map(|p| p.key()) == ctx.accounts.auction.platform,
+            ContentRegistryError::Unauthorized
+        );
+
+        let mint_price = ctx.accounts.auction.highest_bid;
+
+        // =====================================================================
+        // STEP 1: Read the committed oracle randomness
+        // =====================================================================
+
+        let random_bytes = match &ctx.accounts.randomness_account {
+            Some(randomness_account) => {
+                require!(
+                    ctx.accounts.auction.randomness_account == Some(randomness_account.key()),
+                    ContentRegistryError::InvalidRandomnessAccount
+                );
+                let value = read_revealed_randomness(
+                    randomness_account,
+                    ctx.accounts.auction.randomness_seed_slot,
+                    clock.slot,
+                )?;
+                // Bind the oracle value to this NFT so one reveal can't be replayed elsewhere
+                solana_sha256_hasher::hashv(&[
+                    &value,
+                    nft_asset_key.as_ref(),
+                    winner_key.as_ref(),
+                ]).to_bytes()
+            }
+            None => {
+                // Forfeited roll: zero bytes always draw the lowest available tier,
+                // so stalling or withholding the reveal never pays off
+                require!(
+                    ctx.accounts.auction.roll_forfeited(timestamp),
+                    ContentRegistryError::RandomnessNotResolved
+                );
+                [0u8; 32]
+            }
+        };
+
+        let (rarity, weight) = determine_rarity_from_bytes(random_bytes, &mut ctx.accounts.mint_config)?;
+
+        // =====================================================================
+        // STEP 2: Initialize pools (lazy) and trigger epoch distribution if needed
+        // =====================================================================
+
+        let platform_treasury_info = ctx.accounts.platform.as_ref()
+            .map(|p| p.to_account_info())
+            .unwrap_or_else(|| ctx.accounts.treasury.to_account_info());
+        let system_program_info = ctx.accounts.system_program.to_account_info();
+
+        let mut pools = ContentEditionPools {
+            content_reward_pool: &mut ctx.accounts.content_reward_pool,
+            creator_patron_pool: &mut ctx.accounts.creator_patron_pool,
+            global_holder_pool: &mut ctx.accounts.global_holder_pool,
+            creator_dist_pool: &mut ctx.accounts.creator_dist_pool,
+            creator_weight: &mut ctx.accounts.creator_weight,
+            ecosystem_epoch_state: &mut ctx.accounts.ecosystem_epoch_state,
+            creator_patron_treasury: &ctx.accounts.creator_patron_treasury,
+            ecosystem_streaming_treasury: &ctx.accounts.ecosystem_streaming_treasury,
+        };
+        pools.prepare(
+            content_key,
+            &ctx.accounts.creator,
+            &platform_treasury_info,
+            &ctx.accounts.treasury,
+            &system_program_info,
+            timestamp,
+        )?;
+
+        // =====================================================================
+        // STEP 3: Create NFT via Metaplex Core
+        // =====================================================================
+
+        let edition = ctx.accounts.content.minted_count + 1;
+
+        let mint_config_seeds = &[
+            MINT_CONFIG_SEED,
+            content_key.as_ref(),
+            &[ctx.bumps.mint_config],
+        ];
+
+        let nft_seeds = &[
+            SIMPLE_NFT_SEED,
+            winner_key.as_ref(),
+            content_key.as_ref(),
+            &edition.to_le_bytes(),
+            &[ctx.bumps.nft_asset],
+        ];
+
+        EditionNftAccounts {
+            mpl_core_program: &ctx.accounts.mpl_core_program,
+            nft_asset: &ctx.accounts.nft_asset,
+            collection_asset: &ctx.accounts.collection_asset,
+            mint_config: &ctx.accounts.mint_config.to_account_info(),
+            payer: &ctx.accounts.payer.to_account_info(),
+            owner: &ctx.accounts.winner,
+            system_program: &system_program_info,
+        }
+        .create(&content_name, &content_key, rarity, edition, &[mint_config_seeds, nft_seeds])?;
+
+        // Reserved edition becomes a minted edition (locks content)
+        ctx.accounts.content.decrement_pending();
+        ctx.accounts.content.increment_minted();
+
+        // =====================================================================
+        // STEP 4: Pay out winning bid (80/5/3/12 split)
+        // =====================================================================
+
+        pools.pay_primary_sale_from_escrow(
+            &ctx.accounts.auction.to_account_info(),
+            &ctx.accounts.creator,
+            &platform_treasury_info,
+            &ctx.accounts.treasury,
+            mint_price,
+        )?;
+
+        // =====================================================================
+        // STEP 5: Set reward debts, then add NFT weight to ALL pools
+        // =====================================================================
+
+        pools.register_edition(&mut ctx.accounts.unified_nft_state, nft_asset_key, rarity, weight, timestamp);
+
+        emit!(NftMintEvent {
+            content: content_key,
+            buyer: winner_key,
+            creator: creator_key,
+            edition_number: edition,
+            price: mint_price,
+            timestamp,
+            nft_asset: nft_asset_key,
+        });
+
+        msg!("Auction settled!");
+        msg!("NFT: {} | Rarity: {:?} | Weight: {} | Winning bid: {}", nft_asset_key, rarity, weight, mint_price);
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// CANCEL AUCTION
+// ============================================================================
+
+/// Close an auction that has no bids and release the reserved edition
+#[derive(Accounts)]
+pub struct CancelAuction<'info> {
+    #[account(
+        mut,
+        has_one = creator
+    )]
+    pub content: Box<Account<'info, ContentEntry>>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        seeds = [AUCTION_SEED, content.key().as_ref()],
+        bump,
+        close = creator
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+pub fn handle_cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
+    require!(
+        ctx.accounts.auction.highest_bidder.is_none(),
+        ContentRegistryError::InvalidAuctionParams
+    );
+
+    ctx.accounts.content.decrement_pending();
+
+    msg!("Auction cancelled");
+
+    Ok(())
+}
@@ -18,7 +18,7 @@ use crate::{MPL_CORE_ID, DEFAULT_MAX_SUPPLY};
 /// This combines:
 /// - CreateBundle: Creates the bundle PDA
 /// - ConfigureBundleMint: Sets up minting with Metaplex collection
-/// - ConfigureBundleRent: Sets up rental tiers
+/// - ConfigureBundleRent: Sets up rental options
 ///
 /// Bundle is created as published (is_active=true) with mint and rent enabled
 #[derive(Accounts)]
@@ -89,6 +89,7 @@ pub struct CreateBundleWithMintAndRent<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handle_create_bundle_with_mint_and_rent(
     ctx: Context<CreateBundleWithMintAndRent>,
     bundle_id: String,
@@ -96,19 +97,18 @@ pub fn handle_create_bundle_with_mint_and_rent(
     bundle_type: BundleType,
     // Mint config
     mint_price: u64,
+    mint_currency: PaymentCurrency,
     mint_max_supply: Option<u64>,
     creator_royalty_bps: u16,
     // Rent config
-    rent_fee_6h: u64,
-    rent_fee_1d: u64,
-    rent_fee_7d: u64,
+    rent_options: Vec<RentOption>,
     // Collection naming
     collection_name: Option<String>,
 ) -> Result<()> {
     require!(metadata_cid.len() <= 64, ContentRegistryError::CidTooLong);
     // Validate mint price
     require!(
-        MintConfig::validate_price(mint_price, PaymentCurrency::Sol),
+        MintConfig::validate_price(mint_price, mint_currency),
         ContentRegistryError::PriceTooLow
     );
 
@@ -123,19 +123,8 @@ pub fn handle_create_bundle_with_mint_and_rent(
         require!(supply <= DEFAULT_MAX_SUPPLY, ContentRegistryError::MaxSupplyTooHigh);
     }
 
-    // Validate rent fees
-    require!(
-        RentConfig::validate_fee(rent_fee_6h),
-        ContentRegistryError::RentFeeTooLow
-    );
-    require!(
-        RentConfig::validate_fee(rent_fee_1d),
-        ContentRegistryError::RentFeeTooLow
-    );
-    require!(
-        RentConfig::validate_fee(rent_fee_7d),
-        ContentRegistryError::RentFeeTooLow
-    );
+    // Validate rent options
+    RentConfig::validate_options(&rent_options)?;
 
     let clock = Clock::get()?;
     let bundle_key = ctx.accounts.bundle.key();
@@ -160,27 +149,36 @@ pub fn handle_create_bundle_with_mint_and_rent(
     mint_config.item = bundle_key;
     mint_config.creator = ctx.accounts.creator.key();
     mint_config.price = mint_price;
-    mint_config.currency = PaymentCurrency::Sol;
+    mint_config.currency = mint_currency;
     // Default to DEFAULT_MAX_SUPPLY if not specified
     mint_config.max_supply = Some(mint_max_supply.unwrap_or(DEFAULT_MAX_SUPPLY));
     mint_config.creator_royalty_bps = creator_royalty_bps;
     mint_config.is_active = true;
     mint_config.created_at = clock.unix_timestamp;
     mint_config.updated_at = clock.unix_timestamp;
+    mint_config.rarity_table = RarityTable::default();
+    mint_config.rarity_quota = None;
+    mint_config.allowlist_phase = None;
+    mint_config.public_start_at = 0;
+    mint_config.public_end_at = None;
+    mint_config.max_per_wallet = None;
+    mint_config.pricing_mode = PricingMode::Fixed;
+    mint_config.rent_to_own_bps = None;
+    mint_config.instant_rarity = false;
 
     // ========== 3. Initialize Rent Config ==========
     let rent_config = &mut ctx.accounts.rent_config;
     rent_config.item_type = ItemType::Bundle;
     rent_config.item = bundle_key;
     rent_config.creator = ctx.accounts.creator.key();
-    rent_config.rent_fee_6h = rent_fee_6h;
-    rent_config.rent_fee_1d = rent_fee_1d;
-    rent_config.rent_fee_7d = rent_fee_7d;
+    rent_config.rent_options = rent_options;
     rent_config.is_active = true;
     rent_config.total_rentals = 0;
     rent_config.total_fees_collected = 0;
     rent_config.created_at = clock.unix_timestamp;
     rent_config.updated_at = clock.unix_timestamp;
+    rent_config.max_concurrent_rentals = None;
+    rent_config.active_rental_count = 0;
 
     // ========== 4. Create Metaplex Core Collection ==========
     // Derive RewardPool PDA for holder royalties
@@ -242,7 +240,7 @@ pub fn handle_create_bundle_with_mint_and_rent(
 
     msg!("Bundle created with mint and rent: id={}, type={:?}", bundle_id, bundle_type);
     msg!("Mint: price={}, max_supply={:?}, royalty_bps={}", mint_price, mint_max_supply, creator_royalty_bps);
-    msg!("Rent: 6h={}, 1d={}, 7d={}", rent_fee_6h, rent_fee_1d, rent_fee_7d);
+    msg!("Rent: {:?}", ctx.accounts.rent_config.rent_options);
 
     Ok(())
 }
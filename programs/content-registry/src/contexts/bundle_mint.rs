@@ -68,14 +68,15 @@ pub fn handle_configure_bundle_mint(
     ctx: Context<ConfigureBundleMint>,
     metadata_cid: String,
     price: u64,
+    currency: PaymentCurrency,
     max_supply: Option<u64>,
     creator_royalty_bps: u16,
 ) -> Result<()> {
     require!(metadata_cid.len() <= 64, ContentRegistryError::CidTooLong);
 
-    // Validate price
+    // Validate price (minimum depends on currency)
     require!(
-        MintConfig::validate_price(price, PaymentCurrency::Sol),
+        MintConfig::validate_price(price, currency),
         ContentRegistryError::PriceTooLow
     );
 
@@ -99,13 +100,22 @@ pub fn handle_configure_bundle_mint(
     mint_config.item = bundle_key;
     mint_config.creator = ctx.accounts.creator.key();
     mint_config.price = price;
-    mint_config.currency = PaymentCurrency::Sol;
+    mint_config.currency = currency;
     // Default to DEFAULT_MAX_SUPPLY if not specified
     mint_config.max_supply = Some(max_supply.unwrap_or(DEFAULT_MAX_SUPPLY));
     mint_config.creator_royalty_bps = creator_royalty_bps;
     mint_config.is_active = true;
     mint_config.created_at = clock.unix_timestamp;
     mint_config.updated_at = clock.unix_timestamp;
+    mint_config.rarity_table = RarityTable::default();
+    mint_config.rarity_quota = None;
+    mint_config.allowlist_phase = None;
+    mint_config.public_start_at = 0;
+    mint_config.public_end_at = None;
+    mint_config.max_per_wallet = None;
+    mint_config.pricing_mode = PricingMode::Fixed;
+    mint_config.rent_to_own_bps = None;
+    mint_config.instant_rarity = false;
 
     // Store collection_asset directly in bundle
     ctx.accounts.bundle.collection_asset = ctx.accounts.collection_asset.key();
@@ -193,11 +203,20 @@ pub struct UpdateBundleMintSettings<'info> {
 
 pub fn handle_update_bundle_mint_settings(
     ctx: Context<UpdateBundleMintSettings>,
-    price: Option<u64>,
-    max_supply: Option<Option<u64>>,
-    creator_royalty_bps: Option<u16>,
-    is_active: Option<bool>,
+    settings: MintSettingsUpdate,
 ) -> Result<()> {
+    let MintSettingsUpdate {
+        price,
+        max_supply,
+        creator_royalty_bps,
+        is_active,
+        rarity_table,
+        rarity_quota,
+        max_per_wallet,
+        pricing_mode,
+        // Bundles have no commit-reveal path, so simple_mint_bundle always rolls instantly
+        instant_rarity: _,
+    } = settings;
     let bundle = &ctx.accounts.bundle;
     let mint_config = &mut ctx.accounts.mint_config;
     let clock = Clock::get()?;
@@ -205,7 +224,7 @@ pub fn handle_update_bundle_mint_settings(
     // Price can always be updated
     if let Some(new_price) = price {
         require!(
-            MintConfig::validate_price(new_price, PaymentCurrency::Sol),
+            MintConfig::validate_price(new_price, mint_config.currency),
             ContentRegistryError::PriceTooLow
         );
         mint_config.price = new_price;
@@ -253,6 +272,36 @@ pub fn handle_update_bundle_mint_settings(
         mint_config.is_active = active;
     }
 
+    // Rarity table is frozen once locked
+    if let Some(new_table) = rarity_table {
+        require!(!bundle.is_locked, ContentRegistryError::BundleLocked);
+        require!(new_table.is_valid(), ContentRegistryError::InvalidRarityTable);
+        mint_config.rarity_table = new_table;
+    }
+
+    // Quota mode (Some(None) disables) - same freeze rule as the table
+    if let Some(new_quota) = rarity_quota {
+        require!(!bundle.is_locked, ContentRegistryError::BundleLocked);
+        mint_config.rarity_quota = new_quota.map(RarityQuota::new);
+    }
+    // Also rejects supply changes that would break an active quota
+    require!(mint_config.quota_matches_supply(), ContentRegistryError::InvalidRarityQuota);
+
+    // Per-wallet cap can always be changed (Some(None) removes it)
+    if let Some(new_max_per_wallet) = max_per_wallet {
+        require!(new_max_per_wallet != Some(0), ContentRegistryError::InvalidMaxPerWallet);
+        mint_config.max_per_wallet = new_max_per_wallet;
+    }
+
+    // Pricing mode can always be updated, like price
+    if let Some(new_mode) = pricing_mode {
+        require!(
+            new_mode.is_valid(mint_config.currency),
+            ContentRegistryError::InvalidPricingMode
+        );
+        mint_config.pricing_mode = new_mode;
+    }
+
     mint_config.updated_at = clock.unix_timestamp;
 
     Ok(())
@@ -7,6 +7,12 @@ use mpl_core::types::{
 
 use crate::state::*;
 use crate::errors::ContentRegistryError;
+use crate::contexts::rent::{
+    distribute_rent_fee, extend_rental_expiry, record_rental_credit, release_expired_rental_slots,
+    rental_slot_attribute,
+};
+use crate::contexts::gift::{emit_gift, validate_gift_message};
+use crate::events::GiftType;
 use crate::MPL_CORE_ID;
 
 /// Seed for bundle rental NFT assets
@@ -43,22 +49,14 @@ pub struct ConfigureBundleRent<'info> {
 
 pub fn handle_configure_bundle_rent(
     ctx: Context<ConfigureBundleRent>,
-    rent_fee_6h: u64,
-    rent_fee_1d: u64,
-    rent_fee_7d: u64,
+    rent_options: Vec<RentOption>,
+    max_concurrent_rentals: Option<u16>,
 ) -> Result<()> {
-    // Validate all rent fees meet minimum
-    require!(
-        RentConfig::validate_fee(rent_fee_6h),
-        ContentRegistryError::RentFeeTooLow
-    );
-    require!(
-        RentConfig::validate_fee(rent_fee_1d),
-        ContentRegistryError::RentFeeTooLow
-    );
+    // Validate durations and fees
+    RentConfig::validate_options(&rent_options)?;
     require!(
-        RentConfig::validate_fee(rent_fee_7d),
-        ContentRegistryError::RentFeeTooLow
+        RentConfig::validate_max_concurrent(max_concurrent_rentals),
+        ContentRegistryError::InvalidMaxConcurrentRentals
     );
 
     let clock = Clock::get()?;
@@ -67,17 +65,16 @@ pub fn handle_configure_bundle_rent(
     rent_config.item_type = ItemType::Bundle;
     rent_config.item = ctx.accounts.bundle.key();
     rent_config.creator = ctx.accounts.creator.key();
-    rent_config.rent_fee_6h = rent_fee_6h;
-    rent_config.rent_fee_1d = rent_fee_1d;
-    rent_config.rent_fee_7d = rent_fee_7d;
+    rent_config.rent_options = rent_options;
     rent_config.is_active = true;
     rent_config.total_rentals = 0;
     rent_config.total_fees_collected = 0;
     rent_config.created_at = clock.unix_timestamp;
     rent_config.updated_at = clock.unix_timestamp;
+    rent_config.max_concurrent_rentals = max_concurrent_rentals;
+    rent_config.active_rental_count = 0;
 
-    msg!("Bundle rent configured: 6h={}, 1d={}, 7d={}",
-        rent_fee_6h, rent_fee_1d, rent_fee_7d);
+    msg!("Bundle rent configured: {:?}", rent_config.rent_options);
 
     Ok(())
 }
@@ -107,40 +104,28 @@ pub struct UpdateBundleRentConfig<'info> {
 
 pub fn handle_update_bundle_rent_config(
     ctx: Context<UpdateBundleRentConfig>,
-    rent_fee_6h: Option<u64>,
-    rent_fee_1d: Option<u64>,
-    rent_fee_7d: Option<u64>,
+    rent_options: Option<Vec<RentOption>>,
     is_active: Option<bool>,
+    max_concurrent_rentals: Option<Option<u16>>,
 ) -> Result<()> {
     let rent_config = &mut ctx.accounts.rent_config;
     let clock = Clock::get()?;
 
-    if let Some(fee) = rent_fee_6h {
-        require!(
-            RentConfig::validate_fee(fee),
-            ContentRegistryError::RentFeeTooLow
-        );
-        rent_config.rent_fee_6h = fee;
+    if let Some(options) = rent_options {
+        RentConfig::validate_options(&options)?;
+        rent_config.rent_options = options;
     }
 
-    if let Some(fee) = rent_fee_1d {
-        require!(
-            RentConfig::validate_fee(fee),
-            ContentRegistryError::RentFeeTooLow
-        );
-        rent_config.rent_fee_1d = fee;
+    if let Some(active) = is_active {
+        rent_config.is_active = active;
     }
 
-    if let Some(fee) = rent_fee_7d {
+    if let Some(new_max) = max_concurrent_rentals {
         require!(
-            RentConfig::validate_fee(fee),
-            ContentRegistryError::RentFeeTooLow
+            RentConfig::validate_max_concurrent(new_max),
+            ContentRegistryError::InvalidMaxConcurrentRentals
         );
-        rent_config.rent_fee_7d = fee;
-    }
-
-    if let Some(active) = is_active {
-        rent_config.is_active = active;
+        rent_config.max_concurrent_rentals = new_max;
     }
 
     rent_config.updated_at = clock.unix_timestamp;
@@ -215,9 +200,22 @@ pub struct RentBundleSol<'info> {
     #[account(mut)]
     pub nft_asset: Signer<'info>,
 
+    /// Rent-to-own spend record of the rental holder (required while mint_config.rent_to_own_bps is set)
+    #[account(
+        init_if_needed,
+        payer = renter,
+        space = 8 + RentalCredit::INIT_SPACE,
+        seeds = [RENTAL_CREDIT_SEED, bundle.key().as_ref(), recipient.key().as_ref()],
+        bump
+    )]
+    pub rental_credit: Option<Box<Account<'info, RentalCredit>>>,
+
     #[account(mut)]
     pub renter: Signer<'info>,
 
+    /// CHECK: Receives the rental NFT - the renter for a regular rental, another wallet for a gift
+    pub recipient: AccountInfo<'info>,
+
     /// CHECK: MPL Core program
     #[account(address = MPL_CORE_ID)]
     pub mpl_core_program: AccountInfo<'info>,
@@ -225,8 +223,13 @@ pub struct RentBundleSol<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handle_rent_bundle_sol(ctx: Context<RentBundleSol>, tier: RentTier) -> Result<()> {
+pub fn handle_rent_bundle_sol<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RentBundleSol<'info>>,
+    option_index: u8,
+    gift_message_cid: Option<String>,
+) -> Result<()> {
     let clock = Clock::get()?;
+    validate_gift_message(&gift_message_cid)?;
 
     // Check ecosystem not paused
     require!(!ctx.accounts.ecosystem_config.is_paused, ContentRegistryError::EcosystemPaused);
@@ -242,9 +245,10 @@ pub fn handle_rent_bundle_sol(ctx: Context<RentBundleSol>, tier: RentTier) -> Re
         ctx.accounts.reward_pool.created_at = clock.unix_timestamp;
     }
 
-    // Get fee and period based on selected tier
-    let rent_fee = ctx.accounts.rent_config.get_fee_for_tier(tier);
-    let rent_period = tier.period_seconds();
+    // Get fee and period based on selected option
+    let option = ctx.accounts.rent_config.get_option(option_index)?;
+    let rent_fee = option.fee;
+    let rent_period = option.duration_seconds;
     let expires_at = clock.unix_timestamp + rent_period;
     let had_existing_nfts = ctx.accounts.reward_pool.total_weight > 0;
 
@@ -293,10 +297,6 @@ pub fn handle_rent_bundle_sol(ctx: Context<RentBundleSol>, tier: RentTier) -> Re
 
         // Transfer to platform
         if platform_amount > 0 {
-            let platform_wallet = ctx.accounts.platform.as_ref()
-                .map(|p| p.key())
-                .unwrap_or(ctx.accounts.ecosystem_config.treasury);
-
             let platform_account = ctx.accounts.platform.as_ref()
                 .map(|p| p.to_account_info())
                 .unwrap_or_else(|| ctx.accounts.treasury.to_account_info());
@@ -344,6 +344,21 @@ pub fn handle_rent_bundle_sol(ctx: Context<RentBundleSol>, tier: RentTier) -> Re
         &[mint_config_bump],
     ]];
 
+    // Free the slots of expired rentals passed in, then occupy one (if capped)
+    // The NFT is marked so return/reap free it
+    release_expired_rental_slots(
+        ctx.remaining_accounts,
+        &mut ctx.accounts.rent_config,
+        &ctx.accounts.mpl_core_program,
+        &ctx.accounts.collection_asset,
+        &ctx.accounts.mint_config.to_account_info(),
+        &ctx.accounts.renter.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        signer_seeds,
+        clock.unix_timestamp,
+    )?;
+    let holds_slot = ctx.accounts.rent_config.occupy_rental_slot()?;
+
     // Create rental NFT with all plugins in one call:
     // - PermanentBurnDelegate: only our program can burn
     // - FreezeDelegate(frozen=true): non-transferable rental
@@ -358,24 +373,26 @@ pub fn handle_rent_bundle_sol(ctx: Context<RentBundleSol>, tier: RentTier) -> Re
         authority: Some(PluginAuthority::None), // Permanently frozen, no one can unfreeze
     };
 
+    let mut attribute_list = vec![
+        Attribute {
+            key: "expires_at".to_string(),
+            value: expires_at.to_string(),
+        },
+        Attribute {
+            key: "rented_at".to_string(),
+            value: clock.unix_timestamp.to_string(),
+        },
+        Attribute {
+            key: "duration".to_string(),
+            value: rent_period.to_string(),
+        },
+    ];
+    if holds_slot {
+        attribute_list.push(rental_slot_attribute());
+    }
     let attributes_plugin = PluginAuthorityPair {
-        plugin: Plugin::Attributes(Attributes {
-            attribute_list: vec![
-                Attribute {
-                    key: "expires_at".to_string(),
-                    value: expires_at.to_string(),
-                },
-                Attribute {
-                    key: "rented_at".to_string(),
-                    value: clock.unix_timestamp.to_string(),
-                },
-                Attribute {
-                    key: "tier".to_string(),
-                    value: format!("{:?}", tier),
-                },
-            ],
-        }),
-        authority: Some(PluginAuthority::None), // Immutable attributes
+        plugin: Plugin::Attributes(Attributes { attribute_list }),
+        authority: Some(PluginAuthority::UpdateAuthority), // Program-updatable for extensions
     };
 
     CreateV2CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
@@ -383,7 +400,7 @@ pub fn handle_rent_bundle_sol(ctx: Context<RentBundleSol>, tier: RentTier) -> Re
         .collection(Some(&ctx.accounts.collection_asset))
         .authority(Some(&ctx.accounts.mint_config.to_account_info()))
         .payer(&ctx.accounts.renter.to_account_info())
-        .owner(Some(&ctx.accounts.renter.to_account_info()))
+        .owner(Some(&ctx.accounts.recipient.to_account_info()))
         .system_program(&ctx.accounts.system_program.to_account_info())
         .name(rental_nft_name)
         .uri(rental_nft_uri)
@@ -391,6 +408,15 @@ pub fn handle_rent_bundle_sol(ctx: Context<RentBundleSol>, tier: RentTier) -> Re
         .plugins(vec![burn_delegate_plugin, freeze_delegate_plugin, attributes_plugin])
         .invoke_signed(signer_seeds)?;
 
+    record_rental_credit(
+        &ctx.accounts.mint_config,
+        ctx.accounts.rental_credit.as_deref_mut().map(|c| &mut **c),
+        ctx.accounts.recipient.key(),
+        bundle_key,
+        rent_fee,
+        clock.unix_timestamp,
+    )?;
+
     // Update rent config stats
     {
         let rent_config = &mut ctx.accounts.rent_config;
@@ -399,8 +425,163 @@ pub fn handle_rent_bundle_sol(ctx: Context<RentBundleSol>, tier: RentTier) -> Re
         rent_config.updated_at = clock.unix_timestamp;
     }
 
-    msg!("Bundle rented: tier={:?}, fee={}, expires_at={}",
-        tier, rent_fee, expires_at);
+    emit_gift(
+        ctx.accounts.renter.key(),
+        ctx.accounts.recipient.key(),
+        GiftType::BundleRental,
+        bundle_key,
+        ctx.accounts.nft_asset.key(),
+        gift_message_cid,
+        clock.unix_timestamp,
+    );
+
+    msg!("Bundle rented: duration={}, fee={}, expires_at={}",
+        rent_period, rent_fee, expires_at);
+
+    Ok(())
+}
+
+// ============================================================================
+// EXTEND BUNDLE RENTAL - Push expiry forward on an existing rental NFT
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ExtendBundleRental<'info> {
+    #[account(
+        seeds = [ECOSYSTEM_CONFIG_SEED],
+        bump
+    )]
+    pub ecosystem_config: Box<Account<'info, EcosystemConfig>>,
+
+    pub bundle: Box<Account<'info, Bundle>>,
+
+    #[account(
+        mut,
+        seeds = [RENT_CONFIG_SEED, bundle.key().as_ref()],
+        bump,
+        constraint = rent_config.is_active @ ContentRegistryError::RentingNotActive
+    )]
+    pub rent_config: Box<Account<'info, RentConfig>>,
+
+    /// Bundle reward pool (created by the original rental)
+    #[account(
+        mut,
+        seeds = [REWARD_POOL_SEED, bundle.key().as_ref()],
+        bump
+    )]
+    pub reward_pool: Box<Account<'info, RewardPool>>,
+
+    /// Mint config PDA - collection update authority (signs the attribute update)
+    #[account(
+        seeds = [MINT_CONFIG_SEED, bundle.key().as_ref()],
+        bump
+    )]
+    pub mint_config: Box<Account<'info, MintConfig>>,
+
+    /// CHECK: Verified via bundle.collection_asset
+    #[account(
+        mut,
+        constraint = collection_asset.key() == bundle.collection_asset @ ContentRegistryError::BundleMismatch
+    )]
+    pub collection_asset: AccountInfo<'info>,
+
+    /// CHECK: Creator to receive payment
+    #[account(mut, constraint = bundle.creator == creator.key())]
+    pub creator: AccountInfo<'info>,
+
+    /// CHECK: Ecosystem treasury
+    #[account(mut, constraint = ecosystem_config.treasury == treasury.key())]
+    pub treasury: AccountInfo<'info>,
+
+    /// CHECK: Optional platform wallet for commission
+    #[account(mut)]
+    pub platform: Option<AccountInfo<'info>>,
+
+    /// CHECK: Rental NFT owned by renter - verified in handler
+    #[account(mut)]
+    pub nft_asset: AccountInfo<'info>,
+
+    /// Rent-to-own spend record (required while mint_config.rent_to_own_bps is set)
+    /// Extensions are paid by the rental holder, so the credit is theirs
+    #[account(
+        init_if_needed,
+        payer = renter,
+        space = 8 + RentalCredit::INIT_SPACE,
+        seeds = [RENTAL_CREDIT_SEED, bundle.key().as_ref(), renter.key().as_ref()],
+        bump
+    )]
+    pub rental_credit: Option<Box<Account<'info, RentalCredit>>>,
+
+    #[account(mut)]
+    pub renter: Signer<'info>,
+
+    /// CHECK: MPL Core program
+    #[account(address = MPL_CORE_ID)]
+    pub mpl_core_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_extend_bundle_rental(ctx: Context<ExtendBundleRental>, option_index: u8) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(!ctx.accounts.ecosystem_config.is_paused, ContentRegistryError::EcosystemPaused);
+
+    let option = ctx.accounts.rent_config.get_option(option_index)?;
+    let bundle_key = ctx.accounts.bundle.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        MINT_CONFIG_SEED,
+        bundle_key.as_ref(),
+        &[ctx.bumps.mint_config],
+    ]];
+
+    let expires_at = extend_rental_expiry(
+        &ctx.accounts.mpl_core_program,
+        &ctx.accounts.nft_asset,
+        &ctx.accounts.collection_asset,
+        &ctx.accounts.mint_config.to_account_info(),
+        &ctx.accounts.renter.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        signer_seeds,
+        &mut ctx.accounts.rent_config,
+        option.duration_seconds,
+        clock.unix_timestamp,
+    )?;
+
+    // Same primary split as a new rental
+    let rent_fee = option.fee;
+    let pool_info = ctx.accounts.reward_pool.to_account_info();
+    let holder_pool = (ctx.accounts.reward_pool.total_weight > 0).then_some(&pool_info);
+    let platform_info = ctx.accounts.platform.as_ref()
+        .map(|p| p.to_account_info())
+        .unwrap_or_else(|| ctx.accounts.treasury.to_account_info());
+    let holder_reward = distribute_rent_fee(
+        &ctx.accounts.renter.to_account_info(),
+        &ctx.accounts.creator,
+        &platform_info,
+        &ctx.accounts.treasury,
+        holder_pool,
+        &ctx.accounts.system_program.to_account_info(),
+        rent_fee,
+    )?;
+    if holder_reward > 0 {
+        ctx.accounts.reward_pool.add_rewards(holder_reward);
+    }
+
+    record_rental_credit(
+        &ctx.accounts.mint_config,
+        ctx.accounts.rental_credit.as_deref_mut().map(|c| &mut **c),
+        ctx.accounts.renter.key(),
+        bundle_key,
+        rent_fee,
+        clock.unix_timestamp,
+    )?;
+
+    let rent_config = &mut ctx.accounts.rent_config;
+    rent_config.total_fees_collected += rent_fee;
+    rent_config.updated_at = clock.unix_timestamp;
+
+    msg!("Bundle rental extended: duration={}, fee={}, expires_at={}",
+        option.duration_seconds, rent_fee, expires_at);
 
     Ok(())
 }
@@ -428,7 +609,7 @@ pub fn handle_check_bundle_rent_expiry(ctx: Context<CheckBundleRentExpiry>) -> R
 
     // Fetch the asset data
     let asset_data = ctx.accounts.nft_asset.try_borrow_data()?;
-    let asset = BaseAssetV1::from_bytes(&asset_data)?;
+    let _asset = BaseAssetV1::from_bytes(&asset_data)?;
 
     // Fetch the Attributes plugin
     let (_, attributes, _) = fetch_plugin::<BaseAssetV1, Attributes>(
@@ -143,7 +143,7 @@ pub fn handle_batch_claim_bundle_rewards(ctx: Context<BatchClaimBundleRewards>)
 
     let remaining = ctx.remaining_accounts;
     require!(
-        remaining.len() % 2 == 0,
+        remaining.len().is_multiple_of(2),
         ContentRegistryError::InvalidAccountPairs
     );
 
@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+use mpl_core::instructions::CreateV2CpiBuilder;
+use mpl_core::types::{DataState, Plugin, PluginAuthority, PluginAuthorityPair, PermanentBurnDelegate};
+
+use crate::state::*;
+use crate::contexts::mint_request::pay_from_escrow;
+use crate::contexts::subscription_mint::{
+    calculate_virtual_rps,
+    maybe_distribute_patron_pool,
+    maybe_distribute_ecosystem_pools,
+};
+
+// ============================================================================
+// CONTENT EDITION HELPERS
+// ============================================================================
+// simple_mint, reveal_mint and settle_auction mint a content edition the same
+// way and differ only in where the randomness and the payment come from:
+// 1. ContentEditionPools::prepare - lazy pool init + due epoch distributions
+// 2. EditionNftAccounts::create - Metaplex Core NFT with the burn delegate
+// 3. payment (payer transfers, or pay_primary_sale_from_escrow)
+// 4. ContentEditionPools::register_edition - NFT debts, then weight to all pools
+
+/// Reward pools a content edition registers its weight in
+pub(crate) struct ContentEditionPools<'a, 'info> {
+    pub content_reward_pool: &'a mut Account<'info, ContentRewardPool>,
+    pub creator_patron_pool: &'a mut Account<'info, CreatorPatronPool>,
+    pub global_holder_pool: &'a mut Account<'info, GlobalHolderPool>,
+    pub creator_dist_pool: &'a mut Account<'info, CreatorDistPool>,
+    pub creator_weight: &'a mut Account<'info, CreatorWeight>,
+    pub ecosystem_epoch_state: &'a mut Account<'info, EcosystemEpochState>,
+    pub creator_patron_treasury: &'a AccountInfo<'info>,
+    pub ecosystem_streaming_treasury: &'a AccountInfo<'info>,
+}
+
+impl<'info> ContentEditionPools<'_, 'info> {
+    /// Initialize the per-content and per-creator pools on first use, then
+    /// trigger any due epoch distribution (Option B - call before debts are set)
+    pub fn prepare(
+        &mut self,
+        content_key: Pubkey,
+        creator: &AccountInfo<'info>,
+        platform_treasury: &AccountInfo<'info>,
+        treasury: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        now: i64,
+    ) -> Result<()> {
+        let creator_key = creator.key();
+
+        if self.content_reward_pool.content == Pubkey::default() {
+            self.content_reward_pool.content = content_key;
+            self.content_reward_pool.created_at = now;
+        }
+
+        if self.creator_patron_pool.creator == Pubkey::default() {
+            self.creator_patron_pool.creator = creator_key;
+            self.creator_patron_pool.last_distribution_at = now;
+            self.creator_patron_pool.epoch_duration = DEFAULT_EPOCH_DURATION;
+            self.creator_patron_pool.created_at = now;
+        }
+
+        if self.creator_weight.creator == Pubkey::default() {
+            self.creator_weight.creator = creator_key;
+            self.creator_weight.created_at = now;
+        }
+
+        // Patron pool distribution (drains creator_patron_treasury at epoch end)
+        maybe_distribute_patron_pool(
+            self.creator_patron_pool,
+            self.creator_patron_treasury,
+            creator,
+            platform_treasury,
+            treasury,
+            now,
+            system_program,
+            &creator_key,
+        )?;
+
+        // Ecosystem pools distribution (drains ecosystem_streaming_treasury at epoch end)
+        maybe_distribute_ecosystem_pools(
+            self.global_holder_pool,
+            self.creator_dist_pool,
+            self.ecosystem_epoch_state,
+            self.ecosystem_streaming_treasury,
+            platform_treasury,
+            treasury,
+            now,
+            system_program,
+        )?;
+
+        Ok(())
+    }
+
+    /// Pay a primary sale held in a program-owned escrow with the 80/5/3/12 split
+    /// Call before register_edition so the holder share goes to existing holders
+    pub fn pay_primary_sale_from_escrow(
+        &mut self,
+        escrow: &AccountInfo<'info>,
+        creator: &AccountInfo<'info>,
+        platform_treasury: &AccountInfo<'info>,
+        treasury: &AccountInfo<'info>,
+        price: u64,
+    ) -> Result<()> {
+        if price == 0 {
+            return Ok(());
+        }
+        let had_existing_nfts = self.content_reward_pool.total_weight > 0;
+        let (creator_amount, platform_amount, ecosystem_amount, holder_reward_amount) =
+            EcosystemConfig::calculate_primary_split(price);
+
+        // For first NFT, holder reward goes to creator (no holders yet)
+        let final_creator_amount = if !had_existing_nfts {
+            creator_amount + holder_reward_amount
+        } else {
+            creator_amount
+        };
+
+        pay_from_escrow(escrow, creator, final_creator_amount)?;
+        pay_from_escrow(escrow, platform_treasury, platform_amount)?;
+        pay_from_escrow(escrow, treasury, ecosystem_amount)?;
+
+        if had_existing_nfts && holder_reward_amount > 0 {
+            pay_from_escrow(escrow, &self.content_reward_pool.to_account_info(), holder_reward_amount)?;
+            // Update reward_per_share BEFORE adding new NFT weight
+            self.content_reward_pool.add_rewards(holder_reward_amount);
+        }
+
+        msg!("Escrow payout: creator={}, platform={}, ecosystem={}, holder_pool={}",
+            final_creator_amount, platform_amount, ecosystem_amount,
+            if had_existing_nfts { holder_reward_amount } else { 0 });
+
+        Ok(())
+    }
+
+    /// Set the new NFT's reward debts, then add its weight to ALL pools
+    /// Lazy pools use virtual RPS (includes undistributed treasury) so late
+    /// minters don't claim rewards streamed before they minted
+    pub fn register_edition(
+        &mut self,
+        nft_state: &mut UnifiedNftRewardState,
+        nft_asset: Pubkey,
+        rarity: Rarity,
+        weight: u16,
+        now: i64,
+    ) {
+        // Content pool debts (IMMEDIATE pool - use actual RPS)
+        let content_debt = (weight as u128) * self.content_reward_pool.reward_per_share;
+        let usdc_debt = (weight as u128) * self.content_reward_pool.usdc_reward_per_share;
+
+        let patron_treasury_balance = self.creator_patron_treasury.lamports();
+        let eco_treasury_balance = self.ecosystem_streaming_treasury.lamports();
+
+        let virtual_patron_rps = calculate_virtual_rps(
+            self.creator_patron_pool.reward_per_share,
+            patron_treasury_balance,
+            12, // holder share percentage
+            self.creator_patron_pool.total_weight + weight as u64, // include new NFT weight
+        );
+        let virtual_global_rps = calculate_virtual_rps(
+            self.global_holder_pool.reward_per_share,
+            eco_treasury_balance,
+            12, // holder share percentage
+            self.global_holder_pool.total_weight + weight as u64,
+        );
+        let virtual_creator_dist_rps = calculate_virtual_rps(
+            self.creator_dist_pool.reward_per_share,
+            eco_treasury_balance,
+            80, // creator share percentage
+            self.creator_dist_pool.total_weight + weight as u64,
+        );
+
+        // UnifiedNftRewardState (tracks rarity + all pool debts)
+        nft_state.nft_asset = nft_asset;
+        nft_state.creator = self.creator_weight.creator;
+        nft_state.rarity = rarity;
+        nft_state.weight = weight;
+        nft_state.is_bundle = false;
+        nft_state.content_or_bundle = self.content_reward_pool.content;
+        nft_state.content_or_bundle_debt = content_debt;
+        nft_state.patron_debt = (weight as u128) * virtual_patron_rps;
+        nft_state.global_debt = (weight as u128) * virtual_global_rps;
+        nft_state.created_at = now;
+        nft_state.usdc_debt = usdc_debt;
+
+        // Add NFT weight to ALL pools (AFTER setting debts)
+        self.content_reward_pool.add_nft(weight);
+        self.creator_patron_pool.total_weight += weight as u64;
+        self.global_holder_pool.total_weight += weight as u64;
+        self.creator_dist_pool.total_weight += weight as u64;
+        // Creator weight (ADD debt, not SET)
+        self.creator_weight.total_weight += weight as u64;
+        self.creator_weight.reward_debt += (weight as u128) * virtual_creator_dist_rps;
+    }
+}
+
+/// Accounts used to create a content edition NFT via Metaplex Core
+pub(crate) struct EditionNftAccounts<'a, 'info> {
+    pub mpl_core_program: &'a AccountInfo<'info>,
+    pub nft_asset: &'a AccountInfo<'info>,
+    pub collection_asset: &'a AccountInfo<'info>,
+    /// MintConfig PDA - collection authority and permanent burn delegate
+    pub mint_config: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub owner: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl EditionNftAccounts<'_, '_> {
+    /// Create the edition NFT in the content collection
+    /// signer_seeds: the mint_config PDA seeds followed by the nft_asset PDA seeds
+    pub fn create(
+        &self,
+        content_name: &str,
+        content_key: &Pubkey,
+        rarity: Rarity,
+        edition: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        // NFT name format: "<ContentName> (<R> #XXXXXX)" where R is single-letter rarity code
+        let nft_name = format!("{} ({} #{:06})", content_name, rarity.code(), edition);
+        // NFT metadata served from API (not stored on-chain)
+        let nft_uri = format!("https://handcraft.art/api/content/{}/metadata", content_key);
+
+        // PermanentBurnDelegate with mint_config PDA as authority
+        // This allows our program to burn NFTs via burn_nft_with_subscription instruction
+        let burn_delegate_plugin = PluginAuthorityPair {
+            plugin: Plugin::PermanentBurnDelegate(PermanentBurnDelegate {}),
+            authority: Some(PluginAuthority::Address {
+                address: self.mint_config.key(),
+            }),
+        };
+
+        CreateV2CpiBuilder::new(self.mpl_core_program)
+            .asset(self.nft_asset)
+            .collection(Some(self.collection_asset))
+            .authority(Some(self.mint_config))
+            .payer(self.payer)
+            .owner(Some(self.owner))
+            .system_program(self.system_program)
+            .name(nft_name)
+            .uri(nft_uri)
+            .data_state(DataState::AccountState)
+            .plugins(vec![burn_delegate_plugin])
+            .invoke_signed(signer_seeds)?;
+
+        Ok(())
+    }
+}
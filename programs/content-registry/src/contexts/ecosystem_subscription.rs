@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::ContentRegistryError;
+use crate::contexts::streamflow_membership::{stream_paid_through, validate_subscription_stream};
+use crate::contexts::trial::{ecosystem_trial_expiry, patron_trial_expiry};
+use crate::contexts::subscription_vault::{ecosystem_vault_expiry, patron_vault_expiry};
 
 // ============================================================================
 // SUBSCRIBE TO ECOSYSTEM (epoch-based lazy distribution)
@@ -9,6 +12,7 @@ use crate::errors::ContentRegistryError;
 /// Subscribe to ecosystem (Streamflow payment)
 /// Payment handled by Streamflow stream to treasury, distributed on epoch end
 #[derive(Accounts)]
+#[instruction(stream_id: Pubkey)]
 pub struct SubscribeEcosystem<'info> {
     /// Ecosystem subscription config
     #[account(
@@ -28,6 +32,10 @@ pub struct SubscribeEcosystem<'info> {
     )]
     pub ecosystem_subscription: Account<'info, EcosystemSubscription>,
 
+    /// CHECK: Streamflow stream paying for the subscription - terms verified in handler
+    #[account(address = stream_id @ ContentRegistryError::InvalidStreamId)]
+    pub stream_metadata: AccountInfo<'info>,
+
     /// The subscriber creating the subscription
     #[account(mut)]
     pub subscriber: Signer<'info>,
@@ -38,9 +46,19 @@ pub struct SubscribeEcosystem<'info> {
 /// Subscribe to ecosystem (Streamflow handles payment)
 /// Creates subscription record - actual payment is via Streamflow stream to treasury
 /// stream_id: The Streamflow stream ID for this subscription's payment
+/// The stream must come from the subscriber, pay the ecosystem streaming treasury
+/// in WSOL and stream at the configured price
 pub fn handle_subscribe_ecosystem(ctx: Context<SubscribeEcosystem>, stream_id: Pubkey) -> Result<()> {
     let timestamp = Clock::get()?.unix_timestamp;
 
+    let (treasury, _) = Pubkey::find_program_address(&[ECOSYSTEM_STREAMING_TREASURY_SEED], &crate::id());
+    validate_subscription_stream(
+        &ctx.accounts.stream_metadata,
+        &ctx.accounts.subscriber.key(),
+        &treasury,
+        ctx.accounts.ecosystem_sub_config.price,
+    )?;
+
     // Initialize subscription record (payment handled by Streamflow)
     let subscription = &mut ctx.accounts.ecosystem_subscription;
     subscription.subscriber = ctx.accounts.subscriber.key();
@@ -135,6 +153,94 @@ pub fn handle_renew_ecosystem_subscription(ctx: Context<RenewEcosystemSubscripti
 // CHECK SUBSCRIPTION VALIDITY
 // ============================================================================
 
+/// Expiry of the user's creator subscription, if its tier unlocks `visibility`
+/// The tier is looked up in the creator's patron config, which must be passed
+/// Validity comes from the subscription's Streamflow stream, which must be passed
+pub(crate) fn patron_subscription_expiry(
+    patron_sub_info: &AccountInfo,
+    patron_config: Option<&CreatorPatronConfig>,
+    stream_info: Option<&AccountInfo>,
+    user: &Pubkey,
+    creator: &Pubkey,
+    visibility: u8,
+    now: i64,
+) -> Result<Option<i64>> {
+    // Verify PDA
+    let (expected_pda, _bump) = Pubkey::find_program_address(
+        &[CREATOR_PATRON_SUB_SEED, user.as_ref(), creator.as_ref()],
+        &crate::id(),
+    );
+    if patron_sub_info.key() != expected_pda {
+        return Ok(None);
+    }
+
+    // Try to deserialize
+    let data = patron_sub_info.try_borrow_data()?;
+    if data.len() < 8 + CreatorPatronSubscription::INIT_SPACE {
+        return Ok(None);
+    }
+    let patron_sub = CreatorPatronSubscription::try_deserialize(&mut &data[..])?;
+
+    // Check the subscribed tier unlocks this visibility level
+    let Some(config) = patron_config.filter(|config| config.creator == *creator) else {
+        return Ok(None);
+    };
+    let unlocks = config
+        .tiers
+        .get(patron_sub.tier_index as usize)
+        .is_some_and(|tier| tier.grants_visibility(visibility));
+    if !patron_sub.is_active || !unlocks {
+        return Ok(None);
+    }
+
+    // Valid while the stream is paid through
+    subscription_stream_expiry(stream_info, &patron_sub.stream_id, now)
+}
+
+/// Expiry of the user's valid ecosystem subscription, if any
+/// Validity comes from the subscription's Streamflow stream, which must be passed
+pub(crate) fn ecosystem_subscription_expiry(
+    eco_sub_info: &AccountInfo,
+    stream_info: Option<&AccountInfo>,
+    user: &Pubkey,
+    now: i64,
+) -> Result<Option<i64>> {
+    // Verify PDA
+    let (expected_pda, _bump) = Pubkey::find_program_address(
+        &[ECOSYSTEM_SUB_SEED, user.as_ref()],
+        &crate::id(),
+    );
+    if eco_sub_info.key() != expected_pda {
+        return Ok(None);
+    }
+
+    // Try to deserialize
+    let data = eco_sub_info.try_borrow_data()?;
+    if data.len() < 8 + EcosystemSubscription::INIT_SPACE {
+        return Ok(None);
+    }
+    let eco_sub = EcosystemSubscription::try_deserialize(&mut &data[..])?;
+
+    if !eco_sub.is_active {
+        return Ok(None);
+    }
+
+    // Valid while the stream is paid through
+    subscription_stream_expiry(stream_info, &eco_sub.stream_id, now)
+}
+
+/// Paid-through time of a subscription's stream, if still in the future
+fn subscription_stream_expiry(
+    stream_info: Option<&AccountInfo>,
+    stream_id: &Pubkey,
+    now: i64,
+) -> Result<Option<i64>> {
+    let Some(stream_info) = stream_info else {
+        return Ok(None);
+    };
+    Ok(stream_paid_through(stream_info, stream_id)?.filter(|paid_through| now < *paid_through))
+}
+
 /// Check if a user has valid access via patron or ecosystem subscription
 /// This is a view-only instruction that doesn't modify state
 #[derive(Accounts)]
@@ -152,6 +258,35 @@ pub struct CheckSubscriptionAccess<'info> {
 
     /// The user checking access
     pub user: Signer<'info>,
+
+    // Accounts below were appended after `user` so existing clients keep working
+
+    /// Optional: Content creator's patron config (tier ladder)
+    pub patron_config: Option<Box<Account<'info, CreatorPatronConfig>>>,
+
+    /// Optional: Streamflow stream paying for the patron subscription
+    /// CHECK: Matched against the subscription's stream_id and Streamflow ownership
+    pub patron_stream: Option<AccountInfo<'info>>,
+
+    /// Optional: Streamflow stream paying for the ecosystem subscription
+    /// CHECK: Matched against the subscription's stream_id and Streamflow ownership
+    pub ecosystem_stream: Option<AccountInfo<'info>>,
+
+    /// Optional: User's trial marker for the content creator
+    /// CHECK: PDA verified if account exists
+    pub patron_trial: Option<AccountInfo<'info>>,
+
+    /// Optional: User's ecosystem trial marker
+    /// CHECK: PDA verified if account exists
+    pub ecosystem_trial: Option<AccountInfo<'info>>,
+
+    /// Optional: User's subscription vault for the content creator
+    /// CHECK: PDA verified if account exists
+    pub patron_vault: Option<AccountInfo<'info>>,
+
+    /// Optional: User's ecosystem subscription vault
+    /// CHECK: PDA verified if account exists
+    pub ecosystem_vault: Option<AccountInfo<'info>>,
 }
 
 /// Check subscription access for content
@@ -163,10 +298,14 @@ pub struct CheckSubscriptionAccess<'info> {
 /// - Level 2: Subscriber - creator sub OR NFT/Rental only (ecosystem sub NOT enough)
 /// - Level 3: NFT Only - ONLY NFT owners or renters (subscriptions don't grant access)
 ///
-/// Note: This instruction only checks SUBSCRIPTION access. NFT/Rental ownership
-/// is verified separately (typically off-chain) since it requires fetching NFT metadata.
-/// For Level 3 content, this instruction will always return SubscriptionRequired
-/// because subscriptions cannot grant access - caller must verify NFT/Rental separately.
+/// Creator subscriptions grant access up to their tier's max_visibility.
+/// Free trials count as subscriptions during their window, without a funded stream.
+/// Subscription vaults count as subscriptions until the end of their last prepaid period.
+///
+/// Note: This instruction only checks SUBSCRIPTION access. Use check_content_access
+/// to also verify NFT, rental and bundle ownership on-chain.
+/// For Level 3 content, this instruction will always return NftOrRentalRequired
+/// because subscriptions cannot grant access.
 pub fn handle_check_subscription_access(ctx: Context<CheckSubscriptionAccess>) -> Result<()> {
     let content = &ctx.accounts.content;
     let visibility = content.visibility_level;
@@ -185,65 +324,74 @@ pub fn handle_check_subscription_access(ctx: Context<CheckSubscriptionAccess>) -
         return Err(ContentRegistryError::NftOrRentalRequired.into());
     }
 
-    // Level 2: Creator subscription OR NFT/Rental
+    // Level 1-2: Creator subscription whose tier unlocks this level
     // Check creator subscription first (works for both Level 1 and Level 2)
-    if visibility <= 2 {
-        if let Some(patron_sub_info) = &ctx.accounts.patron_subscription {
-            // Verify PDA
-            let (expected_pda, _bump) = Pubkey::find_program_address(
-                &[
-                    CREATOR_PATRON_SUB_SEED,
-                    ctx.accounts.user.key().as_ref(),
-                    content.creator.as_ref(),
-                ],
-                &crate::id(),
-            );
-
-            if patron_sub_info.key() == expected_pda {
-                // Try to deserialize
-                let data = patron_sub_info.try_borrow_data()?;
-                if data.len() >= 8 + CreatorPatronSubscription::INIT_SPACE {
-                    let patron_sub: CreatorPatronSubscription = CreatorPatronSubscription::try_deserialize(
-                        &mut &data[..]
-                    )?;
-
-                    // Check if active subscription tier (NOT membership) and within 30 days
-                    // Membership is support-only, does NOT grant content access
-                    if patron_sub.is_active
-                        && patron_sub.tier == PatronTier::Subscription
-                        && now < patron_sub.started_at + SUBSCRIPTION_VALIDITY_PERIOD
-                    {
-                        msg!("Access granted: valid creator subscription");
-                        return Ok(());
-                    }
-                }
-            }
+    if let Some(patron_sub_info) = &ctx.accounts.patron_subscription {
+        let expiry = patron_subscription_expiry(
+            patron_sub_info,
+            ctx.accounts.patron_config.as_deref().map(|c| &**c),
+            ctx.accounts.patron_stream.as_ref(),
+            &ctx.accounts.user.key(),
+            &content.creator,
+            visibility,
+            now,
+        )?;
+        if expiry.is_some() {
+            msg!("Access granted: valid creator subscription");
+            return Ok(());
+        }
+    }
+
+    // Creator subscription paid from a program-held vault
+    if let Some(vault_info) = &ctx.accounts.patron_vault {
+        let expiry = patron_vault_expiry(
+            vault_info,
+            ctx.accounts.patron_config.as_deref().map(|c| &**c),
+            &ctx.accounts.user.key(),
+            &content.creator,
+            visibility,
+            now,
+        )?;
+        if expiry.is_some() {
+            msg!("Access granted: valid creator subscription vault");
+            return Ok(());
+        }
+    }
+
+    // Creator free trial within its window, at the trial tier
+    if let Some(trial_info) = &ctx.accounts.patron_trial {
+        let expiry = patron_trial_expiry(
+            trial_info,
+            ctx.accounts.patron_config.as_deref().map(|c| &**c),
+            &ctx.accounts.user.key(),
+            &content.creator,
+            visibility,
+            now,
+        )?;
+        if expiry.is_some() {
+            msg!("Access granted: creator free trial");
+            return Ok(());
         }
     }
 
     // Level 1: Ecosystem subscription also accepted (in addition to creator sub)
     if visibility == 1 {
         if let Some(eco_sub_info) = &ctx.accounts.ecosystem_subscription {
-            // Verify PDA
-            let (expected_pda, _bump) = Pubkey::find_program_address(
-                &[ECOSYSTEM_SUB_SEED, ctx.accounts.user.key().as_ref()],
-                &crate::id(),
-            );
-
-            if eco_sub_info.key() == expected_pda {
-                // Try to deserialize
-                let data = eco_sub_info.try_borrow_data()?;
-                if data.len() >= 8 + EcosystemSubscription::INIT_SPACE {
-                    let eco_sub: EcosystemSubscription = EcosystemSubscription::try_deserialize(
-                        &mut &data[..]
-                    )?;
-
-                    // Check if active and within 30 days
-                    if eco_sub.is_active && now < eco_sub.started_at + SUBSCRIPTION_VALIDITY_PERIOD {
-                        msg!("Access granted: valid ecosystem subscription");
-                        return Ok(());
-                    }
-                }
+            if ecosystem_subscription_expiry(eco_sub_info, ctx.accounts.ecosystem_stream.as_ref(), &ctx.accounts.user.key(), now)?.is_some() {
+                msg!("Access granted: valid ecosystem subscription");
+                return Ok(());
+            }
+        }
+        if let Some(vault_info) = &ctx.accounts.ecosystem_vault {
+            if ecosystem_vault_expiry(vault_info, &ctx.accounts.user.key(), now)?.is_some() {
+                msg!("Access granted: valid ecosystem subscription vault");
+                return Ok(());
+            }
+        }
+        if let Some(trial_info) = &ctx.accounts.ecosystem_trial {
+            if ecosystem_trial_expiry(trial_info, &ctx.accounts.user.key(), now)?.is_some() {
+                msg!("Access granted: ecosystem free trial");
+                return Ok(());
             }
         }
     }
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::errors::ContentRegistryError;
+use crate::events::{GiftEvent, GiftType};
+
+// ============================================================================
+// GIFT HELPERS - Shared by mint, rental and subscription flows
+// ============================================================================
+
+/// Validate the optional gift message CID (same 64-char limit as content CIDs)
+pub(crate) fn validate_gift_message(message_cid: &Option<String>) -> Result<()> {
+    require!(
+        message_cid.as_ref().is_none_or(|cid| cid.len() <= 64),
+        ContentRegistryError::CidTooLong
+    );
+    Ok(())
+}
+
+/// Emit a GiftEvent when the recipient is not the payer
+/// item: content, bundle or creator; Pubkey::default() for the ecosystem
+/// asset: NFT asset or subscription record created for the recipient
+pub(crate) fn emit_gift(
+    payer: Pubkey,
+    recipient: Pubkey,
+    gift_type: GiftType,
+    item: Pubkey,
+    asset: Pubkey,
+    message_cid: Option<String>,
+    timestamp: i64,
+) {
+    if payer == recipient {
+        return;
+    }
+
+    emit!(GiftEvent {
+        payer,
+        recipient,
+        gift_type,
+        item,
+        asset,
+        message_cid,
+        timestamp,
+    });
+
+    msg!("Gift from {} to {}", payer, recipient);
+}
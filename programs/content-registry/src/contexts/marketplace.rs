@@ -0,0 +1,869 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use mpl_core::instructions::TransferV1CpiBuilder;
+
+use crate::state::*;
+use crate::errors::ContentRegistryError;
+use crate::events::SecondarySaleEvent;
+use crate::contexts::mint_request::pay_from_escrow;
+use crate::contexts::usdc_payment::{require_usdc_account, require_usdc_vault, transfer_usdc};
+use crate::MPL_CORE_ID;
+
+// ============================================================================
+// NATIVE SECONDARY MARKETPLACE - Listings and collection-wide offers
+// ============================================================================
+// Sales settle inside the program, so secondary fees are paid exactly as
+// EcosystemConfig::calculate_secondary_split computes instead of being inferred
+// from the reward pool lamport surplus by sync_secondary_royalties.
+//
+// Settlement also settles the seller's accrued holder rewards (content/bundle
+// pool in SOL and USDC, plus already-distributed patron/global rewards) and
+// resets the NFT's debts so the buyer starts from zero. USDC rewards need the
+// pool's USDC ATA and the seller's USDC account - only when something is pending.
+// Undistributed lazy-pool balances stay with the NFT.
+
+// ============================================================================
+// SHARED SETTLEMENT
+// ============================================================================
+
+/// Immediate holder pool for the NFT's content or bundle
+pub(crate) enum HolderPool<'a, 'info> {
+    Content(&'a mut Account<'info, ContentRewardPool>),
+    Bundle(&'a mut Account<'info, RewardPool>),
+}
+
+impl<'a, 'info> HolderPool<'a, 'info> {
+    /// Pick the pool matching the NFT type from the optional pool accounts
+    pub(crate) fn select(
+        is_bundle: bool,
+        content_pool: Option<&'a mut Account<'info, ContentRewardPool>>,
+        bundle_pool: Option<&'a mut Account<'info, RewardPool>>,
+    ) -> Result<Self> {
+        if is_bundle {
+            bundle_pool.map(HolderPool::Bundle).ok_or(ContentRegistryError::InvalidRewardPool.into())
+        } else {
+            content_pool.map(HolderPool::Content).ok_or(ContentRegistryError::InvalidRewardPool.into())
+        }
+    }
+
+    fn info(&self) -> AccountInfo<'info> {
+        match self {
+            HolderPool::Content(pool) => pool.to_account_info(),
+            HolderPool::Bundle(pool) => pool.to_account_info(),
+        }
+    }
+
+    fn reward_per_share(&self) -> u128 {
+        match self {
+            HolderPool::Content(pool) => pool.reward_per_share,
+            HolderPool::Bundle(pool) => pool.reward_per_share,
+        }
+    }
+
+    fn usdc_reward_per_share(&self) -> u128 {
+        match self {
+            HolderPool::Content(pool) => pool.usdc_reward_per_share,
+            HolderPool::Bundle(pool) => pool.usdc_reward_per_share,
+        }
+    }
+
+    /// PDA seed prefix and the content/bundle the pool is seeded by
+    fn seeds(&self) -> (&'static [u8], Pubkey) {
+        match self {
+            HolderPool::Content(pool) => (CONTENT_REWARD_POOL_SEED, pool.content),
+            HolderPool::Bundle(pool) => (REWARD_POOL_SEED, pool.item),
+        }
+    }
+
+    fn add_rewards(&mut self, amount: u64) {
+        match self {
+            HolderPool::Content(pool) => pool.add_rewards(amount),
+            HolderPool::Bundle(pool) => pool.add_rewards(amount),
+        }
+    }
+
+    fn record_claim(&mut self, amount: u64) {
+        match self {
+            HolderPool::Content(pool) => pool.total_claimed += amount,
+            HolderPool::Bundle(pool) => pool.total_claimed += amount,
+        }
+    }
+
+    fn record_usdc_claim(&mut self, amount: u64) {
+        match self {
+            HolderPool::Content(pool) => pool.usdc_total_claimed += amount,
+            HolderPool::Bundle(pool) => pool.usdc_total_claimed += amount,
+        }
+    }
+}
+
+/// USDC accounts for settling the seller's USDC holder rewards
+/// Only required when the NFT has pending USDC rewards
+pub(crate) struct UsdcRewardSettlement<'a, 'info> {
+    pub usdc_mint: Pubkey,
+    pub holder_pool_usdc: &'a Option<Box<Account<'info, TokenAccount>>>,
+    pub seller_usdc: &'a Option<Box<Account<'info, TokenAccount>>>,
+    pub token_program: Option<AccountInfo<'info>>,
+}
+
+/// Accounts a secondary sale pays into
+/// `escrow` is a program-owned PDA already holding `price` lamports
+pub(crate) struct SecondarySettlement<'a, 'info> {
+    pub escrow: AccountInfo<'info>,
+    pub nft_state: &'a mut UnifiedNftRewardState,
+    pub holder_pool: HolderPool<'a, 'info>,
+    pub patron_pool: &'a mut Account<'info, CreatorPatronPool>,
+    pub global_pool: &'a mut Account<'info, GlobalHolderPool>,
+    pub creator: AccountInfo<'info>,
+    pub platform: AccountInfo<'info>,
+    pub treasury: AccountInfo<'info>,
+    pub seller: AccountInfo<'info>,
+    pub usdc: UsdcRewardSettlement<'a, 'info>,
+}
+
+/// Result of a settled secondary sale (for events)
+pub(crate) struct SecondarySplit {
+    pub creator_royalty: u64,
+    pub platform_fee: u64,
+    pub ecosystem_fee: u64,
+    pub holder_reward: u64,
+    pub seller_amount: u64,
+    pub seller_rewards: u64,
+    pub seller_usdc_rewards: u64,
+}
+
+/// Pay out a secondary sale with the exact secondary split and hand the NFT's
+/// reward position from seller to buyer
+pub(crate) fn settle_secondary_sale(
+    s: SecondarySettlement,
+    price: u64,
+    creator_royalty_bps: u16,
+) -> Result<SecondarySplit> {
+    let SecondarySettlement {
+        escrow,
+        nft_state,
+        mut holder_pool,
+        patron_pool,
+        global_pool,
+        creator,
+        platform,
+        treasury,
+        seller,
+        usdc,
+    } = s;
+
+    let (creator_royalty, platform_fee, ecosystem_fee, holder_reward, seller_amount) =
+        EcosystemConfig::calculate_secondary_split(price, creator_royalty_bps);
+
+    // Holder share goes to the item's pool (the NFT being sold still holds weight,
+    // so its slice is settled to the seller below rather than stranded)
+    if holder_reward > 0 {
+        pay_from_escrow(&escrow, &holder_pool.info(), holder_reward)?;
+        holder_pool.add_rewards(holder_reward);
+    }
+    pay_from_escrow(&escrow, &creator, creator_royalty)?;
+    pay_from_escrow(&escrow, &platform, platform_fee)?;
+    pay_from_escrow(&escrow, &treasury, ecosystem_fee)?;
+    pay_from_escrow(&escrow, &seller, seller_amount)?;
+
+    // Settle the seller's accrued rewards, then reset debts for the buyer
+    let weight = nft_state.weight as u128;
+    let mut seller_rewards = 0u64;
+
+    let rps = holder_pool.reward_per_share();
+    let pending = nft_state.pending_content_or_bundle_reward(rps);
+    if pending > 0 {
+        pay_from_escrow(&holder_pool.info(), &seller, pending)?;
+        holder_pool.record_claim(pending);
+        seller_rewards += pending;
+    }
+    nft_state.content_or_bundle_debt = weight * rps;
+
+    // USDC holder rewards (the buyer must not inherit the seller's usdc_debt)
+    let usdc_rps = holder_pool.usdc_reward_per_share();
+    let seller_usdc_rewards = nft_state.pending_usdc_reward(usdc_rps);
+    if seller_usdc_rewards > 0 {
+        let pool_info = holder_pool.info();
+        let pool_usdc = require_usdc_vault(usdc.holder_pool_usdc, &usdc.usdc_mint, &pool_info.key())?;
+        let seller_usdc = require_usdc_account(usdc.seller_usdc, &usdc.usdc_mint, &seller.key())?;
+        let token_program = usdc.token_program.ok_or(ContentRegistryError::UsdcAccountsRequired)?;
+
+        let (seed, item) = holder_pool.seeds();
+        let (_, bump) = Pubkey::find_program_address(&[seed, item.as_ref()], &crate::ID);
+        transfer_usdc(
+            &token_program,
+            &pool_usdc.to_account_info(),
+            &seller_usdc.to_account_info(),
+            &pool_info,
+            seller_usdc_rewards,
+            &[&[seed, item.as_ref(), &[bump]]],
+        )?;
+        holder_pool.record_usdc_claim(seller_usdc_rewards);
+    }
+    nft_state.update_usdc_debt(usdc_rps);
+
+    // Lazy pools: only update debt when something was paid (preserves virtual RPS protection)
+    let pending = nft_state.pending_patron_reward(patron_pool.reward_per_share);
+    if pending > 0 {
+        pay_from_escrow(&patron_pool.to_account_info(), &seller, pending)?;
+        patron_pool.total_claimed += pending;
+        nft_state.patron_debt = weight * patron_pool.reward_per_share;
+        seller_rewards += pending;
+    }
+
+    let pending = nft_state.pending_global_reward(global_pool.reward_per_share);
+    if pending > 0 {
+        pay_from_escrow(&global_pool.to_account_info(), &seller, pending)?;
+        global_pool.total_claimed += pending;
+        nft_state.global_debt = weight * global_pool.reward_per_share;
+        seller_rewards += pending;
+    }
+
+    Ok(SecondarySplit {
+        creator_royalty,
+        platform_fee,
+        ecosystem_fee,
+        holder_reward,
+        seller_amount,
+        seller_rewards,
+        seller_usdc_rewards,
+    })
+}
+
+// ============================================================================
+// LIST NFT
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ListNft<'info> {
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + Listing::INIT_SPACE,
+        seeds = [LISTING_SEED, nft_asset.key().as_ref()],
+        bump
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    /// CHECK: Metaplex Core asset, ownership verified in handler
+    #[account(mut)]
+    pub nft_asset: AccountInfo<'info>,
+
+    /// CHECK: Collection of the NFT, verified against the asset in handler
+    #[account(mut)]
+    pub collection_asset: AccountInfo<'info>,
+
+    /// Only NFTs minted by this program (rentals have no reward state)
+    #[account(
+        seeds = [UNIFIED_NFT_REWARD_STATE_SEED, nft_asset.key().as_ref()],
+        bump
+    )]
+    pub nft_reward_state: Box<Account<'info, UnifiedNftRewardState>>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// CHECK: MPL Core program
+    #[account(address = MPL_CORE_ID)]
+    pub mpl_core_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_list_nft(ctx: Context<ListNft>, price: u64) -> Result<()> {
+    require!(
+        MintConfig::validate_price(price, PaymentCurrency::Sol),
+        ContentRegistryError::PriceTooLow
+    );
+    require!(
+        crate::verify_core_nft_ownership(
+            &ctx.accounts.nft_asset,
+            &ctx.accounts.seller.key(),
+            &ctx.accounts.collection_asset.key(),
+        )?,
+        ContentRegistryError::NotNftOwner
+    );
+
+    // Move the NFT into the listing PDA's custody
+    TransferV1CpiBuilder::new(&ctx.accounts.mpl_core_program)
+        .asset(&ctx.accounts.nft_asset)
+        .collection(Some(&ctx.accounts.collection_asset))
+        .payer(&ctx.accounts.seller.to_account_info())
+        .authority(Some(&ctx.accounts.seller.to_account_info()))
+        .new_owner(&ctx.accounts.listing.to_account_info())
+        .system_program(Some(&ctx.accounts.system_program.to_account_info()))
+        .invoke()?;
+
+    let listing = &mut ctx.accounts.listing;
+    listing.seller = ctx.accounts.seller.key();
+    listing.nft_asset = ctx.accounts.nft_asset.key();
+    listing.collection_asset = ctx.accounts.collection_asset.key();
+    listing.content_or_bundle = ctx.accounts.nft_reward_state.content_or_bundle;
+    listing.price = price;
+    listing.created_at = Clock::get()?.unix_timestamp;
+
+    msg!("NFT listed: {} for {} lamports", listing.nft_asset, price);
+
+    Ok(())
+}
+
+// ============================================================================
+// CANCEL LISTING
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CancelListing<'info> {
+    #[account(
+        mut,
+        has_one = seller,
+        has_one = nft_asset,
+        has_one = collection_asset,
+        seeds = [LISTING_SEED, nft_asset.key().as_ref()],
+        bump,
+        close = seller
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    /// CHECK: Verified by listing.nft_asset
+    #[account(mut)]
+    pub nft_asset: AccountInfo<'info>,
+
+    /// CHECK: Verified by listing.collection_asset
+    #[account(mut)]
+    pub collection_asset: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// CHECK: MPL Core program
+    #[account(address = MPL_CORE_ID)]
+    pub mpl_core_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
+    let nft_asset_key = ctx.accounts.nft_asset.key();
+    let listing_seeds = &[
+        LISTING_SEED,
+        nft_asset_key.as_ref(),
+        &[ctx.bumps.listing],
+    ];
+
+    // Return the NFT to the seller
+    TransferV1CpiBuilder::new(&ctx.accounts.mpl_core_program)
+        .asset(&ctx.accounts.nft_asset)
+        .collection(Some(&ctx.accounts.collection_asset))
+        .payer(&ctx.accounts.seller.to_account_info())
+        .authority(Some(&ctx.accounts.listing.to_account_info()))
+        .new_owner(&ctx.accounts.seller.to_account_info())
+        .system_program(Some(&ctx.accounts.system_program.to_account_info()))
+        .invoke_signed(&[listing_seeds])?;
+
+    msg!("Listing cancelled: {}", nft_asset_key);
+
+    Ok(())
+}
+
+// ============================================================================
+// BUY LISTED NFT
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct BuyListedNft<'info> {
+    #[account(
+        mut,
+        seeds = [ECOSYSTEM_CONFIG_SEED],
+        bump
+    )]
+    pub ecosystem_config: Box<Account<'info, EcosystemConfig>>,
+
+    /// Listing - escrow for the payment, rent returns to seller
+    #[account(
+        mut,
+        has_one = seller,
+        has_one = nft_asset,
+        has_one = collection_asset,
+        seeds = [LISTING_SEED, nft_asset.key().as_ref()],
+        bump,
+        close = seller
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    /// CHECK: Verified by listing.nft_asset
+    #[account(mut)]
+    pub nft_asset: AccountInfo<'info>,
+
+    /// CHECK: Verified by listing.collection_asset
+    #[account(mut)]
+    pub collection_asset: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [UNIFIED_NFT_REWARD_STATE_SEED, nft_asset.key().as_ref()],
+        bump
+    )]
+    pub nft_reward_state: Box<Account<'info, UnifiedNftRewardState>>,
+
+    /// Mint config of the NFT's content or bundle (creator royalty rate)
+    #[account(
+        seeds = [MINT_CONFIG_SEED, nft_reward_state.content_or_bundle.as_ref()],
+        bump
+    )]
+    pub mint_config: Box<Account<'info, MintConfig>>,
+
+    /// Content reward pool (content NFTs)
+    #[account(
+        mut,
+        seeds = [CONTENT_REWARD_POOL_SEED, nft_reward_state.content_or_bundle.as_ref()],
+        bump
+    )]
+    pub content_reward_pool: Option<Box<Account<'info, ContentRewardPool>>>,
+
+    /// Bundle reward pool (bundle NFTs)
+    #[account(
+        mut,
+        seeds = [REWARD_POOL_SEED, nft_reward_state.content_or_bundle.as_ref()],
+        bump
+    )]
+    pub bundle_reward_pool: Option<Box<Account<'info, RewardPool>>>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_PATRON_POOL_SEED, nft_reward_state.creator.as_ref()],
+        bump
+    )]
+    pub creator_patron_pool: Box<Account<'info, CreatorPatronPool>>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_HOLDER_POOL_SEED],
+        bump
+    )]
+    pub global_holder_pool: Box<Account<'info, GlobalHolderPool>>,
+
+    /// CHECK: Creator receiving the royalty
+    #[account(mut, constraint = nft_reward_state.creator == creator.key() @ ContentRegistryError::Unauthorized)]
+    pub creator: AccountInfo<'info>,
+
+    /// CHECK: Ecosystem treasury
+    #[account(mut, constraint = ecosystem_config.treasury == treasury.key())]
+    pub treasury: AccountInfo<'info>,
+
+    /// CHECK: Optional platform wallet for commission (defaults to treasury)
+    #[account(mut)]
+    pub platform: Option<AccountInfo<'info>>,
+
+    /// CHECK: Seller recorded in the listing
+    #[account(mut)]
+    pub seller: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: MPL Core program
+    #[account(address = MPL_CORE_ID)]
+    pub mpl_core_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Holder pool's USDC ATA - required when the NFT has pending USDC rewards
+    #[account(mut)]
+    pub holder_pool_usdc: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Seller's USDC token account - receives pending USDC rewards
+    #[account(mut)]
+    pub seller_usdc: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn handle_buy_listed_nft(ctx: Context<BuyListedNft>, max_price: u64) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let price = ctx.accounts.listing.price;
+    require!(price <= max_price, ContentRegistryError::PriceExceedsMax);
+    require!(
+        ctx.accounts.buyer.key() != ctx.accounts.seller.key(),
+        ContentRegistryError::Unauthorized
+    );
+
+    // Buyer pays into the listing escrow, which then pays out the split
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.listing.to_account_info(),
+            },
+        ),
+        price,
+    )?;
+
+    let platform_info = ctx.accounts.platform.as_ref()
+        .map(|p| p.to_account_info())
+        .unwrap_or_else(|| ctx.accounts.treasury.to_account_info());
+
+    let accounts = &mut *ctx.accounts;
+    let is_bundle = accounts.nft_reward_state.is_bundle;
+    let split = settle_secondary_sale(
+        SecondarySettlement {
+            escrow: accounts.listing.to_account_info(),
+            nft_state: &mut accounts.nft_reward_state,
+            holder_pool: HolderPool::select(
+                is_bundle,
+                accounts.content_reward_pool.as_deref_mut(),
+                accounts.bundle_reward_pool.as_deref_mut(),
+            )?,
+            patron_pool: &mut accounts.creator_patron_pool,
+            global_pool: &mut accounts.global_holder_pool,
+            creator: accounts.creator.to_account_info(),
+            platform: platform_info,
+            treasury: accounts.treasury.to_account_info(),
+            seller: accounts.seller.to_account_info(),
+            usdc: UsdcRewardSettlement {
+                usdc_mint: accounts.ecosystem_config.usdc_mint,
+                holder_pool_usdc: &accounts.holder_pool_usdc,
+                seller_usdc: &accounts.seller_usdc,
+                token_program: accounts.token_program.as_ref().map(|p| p.to_account_info()),
+            },
+        },
+        price,
+        accounts.mint_config.creator_royalty_bps,
+    )?;
+    accounts.ecosystem_config.total_fees_sol += split.ecosystem_fee;
+
+    // Deliver the NFT from escrow to the buyer
+    let nft_asset_key = accounts.nft_asset.key();
+    let listing_seeds = &[
+        LISTING_SEED,
+        nft_asset_key.as_ref(),
+        &[ctx.bumps.listing],
+    ];
+
+    TransferV1CpiBuilder::new(&accounts.mpl_core_program)
+        .asset(&accounts.nft_asset)
+        .collection(Some(&accounts.collection_asset))
+        .payer(&accounts.buyer.to_account_info())
+        .authority(Some(&accounts.listing.to_account_info()))
+        .new_owner(&accounts.buyer.to_account_info())
+        .system_program(Some(&accounts.system_program.to_account_info()))
+        .invoke_signed(&[listing_seeds])?;
+
+    emit!(SecondarySaleEvent {
+        nft_asset: nft_asset_key,
+        content_or_bundle: accounts.listing.content_or_bundle,
+        seller: accounts.seller.key(),
+        buyer: accounts.buyer.key(),
+        price,
+        creator_royalty: split.creator_royalty,
+        platform_fee: split.platform_fee,
+        ecosystem_fee: split.ecosystem_fee,
+        holder_reward: split.holder_reward,
+        seller_amount: split.seller_amount,
+        seller_rewards_settled: split.seller_rewards,
+        seller_usdc_rewards_settled: split.seller_usdc_rewards,
+        timestamp,
+    });
+
+    msg!("NFT sold: {} for {} lamports", nft_asset_key, price);
+
+    Ok(())
+}
+
+// ============================================================================
+// MAKE OFFER
+// ============================================================================
+
+/// Escrow an offer for any edition of a content or bundle
+/// Pass exactly one of content / bundle
+#[derive(Accounts)]
+pub struct MakeOffer<'info> {
+    pub content: Option<Box<Account<'info, ContentEntry>>>,
+
+    pub bundle: Option<Box<Account<'info, Bundle>>>,
+
+    /// Offer PDA - seeded by the target content or bundle
+    #[account(
+        init,
+        payer = buyer,
+        space = 8 + Offer::INIT_SPACE,
+        seeds = [
+            OFFER_SEED,
+            content.as_ref().map(|c| c.key()).or(bundle.as_ref().map(|b| b.key())).unwrap_or_default().as_ref(),
+            buyer.key().as_ref(),
+        ],
+        bump
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_make_offer(ctx: Context<MakeOffer>, price: u64, min_rarity: Option<Rarity>) -> Result<()> {
+    require!(
+        MintConfig::validate_price(price, PaymentCurrency::Sol),
+        ContentRegistryError::PriceTooLow
+    );
+
+    let (content_or_bundle, is_bundle, collection_asset) =
+        match (&ctx.accounts.content, &ctx.accounts.bundle) {
+            (Some(content), None) => (content.key(), false, content.collection_asset),
+            (None, Some(bundle)) => (bundle.key(), true, bundle.collection_asset),
+            _ => return Err(ContentRegistryError::InvalidOfferTarget.into()),
+        };
+
+    // Escrow the offer
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.offer.to_account_info(),
+            },
+        ),
+        price,
+    )?;
+
+    let offer = &mut ctx.accounts.offer;
+    offer.buyer = ctx.accounts.buyer.key();
+    offer.content_or_bundle = content_or_bundle;
+    offer.is_bundle = is_bundle;
+    offer.collection_asset = collection_asset;
+    offer.price = price;
+    offer.min_rarity = min_rarity;
+    offer.created_at = Clock::get()?.unix_timestamp;
+
+    msg!("Offer made: {} lamports for any edition of {}", price, content_or_bundle);
+
+    Ok(())
+}
+
+// ============================================================================
+// CANCEL OFFER
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CancelOffer<'info> {
+    /// Closing returns the escrowed offer and rent to the buyer
+    #[account(
+        mut,
+        has_one = buyer,
+        seeds = [OFFER_SEED, offer.content_or_bundle.as_ref(), buyer.key().as_ref()],
+        bump,
+        close = buyer
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+}
+
+pub fn handle_cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
+    msg!("Offer cancelled, {} lamports refunded", ctx.accounts.offer.price);
+    Ok(())
+}
+
+// ============================================================================
+// ACCEPT OFFER
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct AcceptOffer<'info> {
+    #[account(
+        mut,
+        seeds = [ECOSYSTEM_CONFIG_SEED],
+        bump
+    )]
+    pub ecosystem_config: Box<Account<'info, EcosystemConfig>>,
+
+    /// Offer - escrow is paid out, rent returns to buyer
+    #[account(
+        mut,
+        has_one = buyer,
+        has_one = collection_asset,
+        seeds = [OFFER_SEED, offer.content_or_bundle.as_ref(), buyer.key().as_ref()],
+        bump,
+        close = buyer
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+
+    /// CHECK: Metaplex Core asset, ownership and collection verified in handler
+    #[account(mut)]
+    pub nft_asset: AccountInfo<'info>,
+
+    /// CHECK: Verified by offer.collection_asset
+    #[account(mut)]
+    pub collection_asset: AccountInfo<'info>,
+
+    /// Reward state binds the NFT to the offer's content/bundle and records its rarity
+    #[account(
+        mut,
+        seeds = [UNIFIED_NFT_REWARD_STATE_SEED, nft_asset.key().as_ref()],
+        bump,
+        constraint = nft_reward_state.content_or_bundle == offer.content_or_bundle @ ContentRegistryError::ContentMismatch,
+        constraint = nft_reward_state.is_bundle == offer.is_bundle @ ContentRegistryError::InvalidNftType
+    )]
+    pub nft_reward_state: Box<Account<'info, UnifiedNftRewardState>>,
+
+    /// Mint config of the offer's content or bundle (creator royalty rate)
+    #[account(
+        seeds = [MINT_CONFIG_SEED, offer.content_or_bundle.as_ref()],
+        bump
+    )]
+    pub mint_config: Box<Account<'info, MintConfig>>,
+
+    /// Content reward pool (content offers)
+    #[account(
+        mut,
+        seeds = [CONTENT_REWARD_POOL_SEED, offer.content_or_bundle.as_ref()],
+        bump
+    )]
+    pub content_reward_pool: Option<Box<Account<'info, ContentRewardPool>>>,
+
+    /// Bundle reward pool (bundle offers)
+    #[account(
+        mut,
+        seeds = [REWARD_POOL_SEED, offer.content_or_bundle.as_ref()],
+        bump
+    )]
+    pub bundle_reward_pool: Option<Box<Account<'info, RewardPool>>>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_PATRON_POOL_SEED, nft_reward_state.creator.as_ref()],
+        bump
+    )]
+    pub creator_patron_pool: Box<Account<'info, CreatorPatronPool>>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_HOLDER_POOL_SEED],
+        bump
+    )]
+    pub global_holder_pool: Box<Account<'info, GlobalHolderPool>>,
+
+    /// CHECK: Creator receiving the royalty
+    #[account(mut, constraint = nft_reward_state.creator == creator.key() @ ContentRegistryError::Unauthorized)]
+    pub creator: AccountInfo<'info>,
+
+    /// CHECK: Ecosystem treasury
+    #[account(mut, constraint = ecosystem_config.treasury == treasury.key())]
+    pub treasury: AccountInfo<'info>,
+
+    /// CHECK: Optional platform wallet for commission (defaults to treasury)
+    #[account(mut)]
+    pub platform: Option<AccountInfo<'info>>,
+
+    /// CHECK: Buyer recorded in the offer (receives the NFT and offer rent)
+    #[account(mut)]
+    pub buyer: AccountInfo<'info>,
+
+    /// Current holder of a matching NFT
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// CHECK: MPL Core program
+    #[account(address = MPL_CORE_ID)]
+    pub mpl_core_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Holder pool's USDC ATA - required when the NFT has pending USDC rewards
+    #[account(mut)]
+    pub holder_pool_usdc: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Seller's USDC token account - receives pending USDC rewards
+    #[account(mut)]
+    pub seller_usdc: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn handle_accept_offer(ctx: Context<AcceptOffer>, min_price: u64) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let price = ctx.accounts.offer.price;
+    require!(price >= min_price, ContentRegistryError::InsufficientPayment);
+    require!(
+        ctx.accounts.seller.key() != ctx.accounts.buyer.key(),
+        ContentRegistryError::Unauthorized
+    );
+    require!(
+        ctx.accounts.offer.accepts_rarity(ctx.accounts.nft_reward_state.rarity),
+        ContentRegistryError::RarityBelowMinimum
+    );
+    require!(
+        crate::verify_core_nft_ownership(
+            &ctx.accounts.nft_asset,
+            &ctx.accounts.seller.key(),
+            &ctx.accounts.collection_asset.key(),
+        )?,
+        ContentRegistryError::NotNftOwner
+    );
+
+    let platform_info = ctx.accounts.platform.as_ref()
+        .map(|p| p.to_account_info())
+        .unwrap_or_else(|| ctx.accounts.treasury.to_account_info());
+
+    let accounts = &mut *ctx.accounts;
+    let is_bundle = accounts.nft_reward_state.is_bundle;
+    let split = settle_secondary_sale(
+        SecondarySettlement {
+            escrow: accounts.offer.to_account_info(),
+            nft_state: &mut accounts.nft_reward_state,
+            holder_pool: HolderPool::select(
+                is_bundle,
+                accounts.content_reward_pool.as_deref_mut(),
+                accounts.bundle_reward_pool.as_deref_mut(),
+            )?,
+            patron_pool: &mut accounts.creator_patron_pool,
+            global_pool: &mut accounts.global_holder_pool,
+            creator: accounts.creator.to_account_info(),
+            platform: platform_info,
+            treasury: accounts.treasury.to_account_info(),
+            seller: accounts.seller.to_account_info(),
+            usdc: UsdcRewardSettlement {
+                usdc_mint: accounts.ecosystem_config.usdc_mint,
+                holder_pool_usdc: &accounts.holder_pool_usdc,
+                seller_usdc: &accounts.seller_usdc,
+                token_program: accounts.token_program.as_ref().map(|p| p.to_account_info()),
+            },
+        },
+        price,
+        accounts.mint_config.creator_royalty_bps,
+    )?;
+    accounts.ecosystem_config.total_fees_sol += split.ecosystem_fee;
+
+    // Deliver the NFT to the buyer
+    TransferV1CpiBuilder::new(&accounts.mpl_core_program)
+        .asset(&accounts.nft_asset)
+        .collection(Some(&accounts.collection_asset))
+        .payer(&accounts.seller.to_account_info())
+        .authority(Some(&accounts.seller.to_account_info()))
+        .new_owner(&accounts.buyer)
+        .system_program(Some(&accounts.system_program.to_account_info()))
+        .invoke()?;
+
+    let nft_asset_key = accounts.nft_asset.key();
+    emit!(SecondarySaleEvent {
+        nft_asset: nft_asset_key,
+        content_or_bundle: accounts.offer.content_or_bundle,
+        seller: accounts.seller.key(),
+        buyer: accounts.buyer.key(),
+        price,
+        creator_royalty: split.creator_royalty,
+        platform_fee: split.platform_fee,
+        ecosystem_fee: split.ecosystem_fee,
+        holder_reward: split.holder_reward,
+        seller_amount: split.seller_amount,
+        seller_rewards_settled: split.seller_rewards,
+        seller_usdc_rewards_settled: split.seller_usdc_rewards,
+        timestamp,
+    });
+
+    msg!("Offer accepted: {} sold for {} lamports", nft_asset_key, price);
+
+    Ok(())
+}
@@ -0,0 +1,574 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::state::*;
+use crate::errors::ContentRegistryError;
+
+// ============================================================================
+// ACCOUNT MIGRATIONS - grow accounts created before fields were appended
+// ============================================================================
+// Accounts created by an earlier program version are shorter than the current
+// layout and fail to deserialize until migrated. Migration reallocates them to
+// the current size (the payer funds the extra rent). Appended bytes are zeroed,
+// which is the correct initial value for every appended field listed below -
+// except CreatorPatronConfig, MintConfig and RentConfig, whose legacy layouts
+// are rewritten.
+
+/// Verify a program-owned account carries the discriminator of `T`
+fn require_account_type<T: Discriminator>(account: &AccountInfo) -> Result<()> {
+    require!(account.owner == &crate::ID, ContentRegistryError::InvalidAccountData);
+    require!(
+        account.try_borrow_data()?.starts_with(T::DISCRIMINATOR),
+        ContentRegistryError::InvalidAccountData
+    );
+    Ok(())
+}
+
+/// Grow a program-owned account of type `T` to its current size
+/// Rejects accounts of another type and accounts that are already current
+fn grow_legacy_account<'info, T: Discriminator + Space>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    require_account_type::<T>(account)?;
+    let new_len = 8 + T::INIT_SPACE;
+    require!(account.data_len() < new_len, ContentRegistryError::AlreadyMigrated);
+    resize_account(account, payer, system_program, new_len)
+}
+
+/// Reallocate an account to `new_len`, topping up rent from `payer`
+fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let rent_due = Rent::get()?.minimum_balance(new_len).saturating_sub(account.lamports());
+    if rent_due > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+
+    account.resize(new_len)?;
+    Ok(())
+}
+
+// ============================================================================
+// MIGRATE REWARD ACCOUNT (USDC fields)
+// ============================================================================
+
+/// Grow a ContentRewardPool, RewardPool or UnifiedNftRewardState to the USDC layout
+/// Appended fields: usdc_reward_per_share, usdc_total_deposited, usdc_total_claimed
+/// on the pools and usdc_debt on the NFT state - all start at zero
+/// Permissionless - the caller pays the extra rent
+#[derive(Accounts)]
+pub struct MigrateRewardAccount<'info> {
+    /// CHECK: Owner and discriminator verified in handler
+    #[account(mut)]
+    pub account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_reward_account(ctx: Context<MigrateRewardAccount>) -> Result<()> {
+    let account = ctx.accounts.account.to_account_info();
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    let discriminator: [u8; 8] = account
+        .try_borrow_data()?
+        .get(..8)
+        .and_then(|d| d.try_into().ok())
+        .ok_or(ContentRegistryError::InvalidAccountData)?;
+
+    if discriminator == ContentRewardPool::DISCRIMINATOR {
+        grow_legacy_account::<ContentRewardPool>(&account, &payer, &system_program)?;
+    } else if discriminator == RewardPool::DISCRIMINATOR {
+        grow_legacy_account::<RewardPool>(&account, &payer, &system_program)?;
+    } else if discriminator == UnifiedNftRewardState::DISCRIMINATOR {
+        grow_legacy_account::<UnifiedNftRewardState>(&account, &payer, &system_program)?;
+    } else {
+        return Err(ContentRegistryError::InvalidAccountData.into());
+    }
+
+    msg!("Migrated reward account {} to {} bytes", account.key(), account.data_len());
+
+    Ok(())
+}
+
+// ============================================================================
+// MIGRATE ECOSYSTEM SUB CONFIG (trial_duration)
+// ============================================================================
+
+/// Grow the EcosystemSubConfig to the layout with trial_duration (starts as None)
+/// Admin only - the admin pays the extra rent
+#[derive(Accounts)]
+pub struct MigrateEcosystemSubConfig<'info> {
+    /// CHECK: Legacy layout - owner and discriminator verified in handler
+    #[account(
+        mut,
+        seeds = [ECOSYSTEM_SUB_CONFIG_SEED],
+        bump
+    )]
+    pub ecosystem_sub_config: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [ECOSYSTEM_CONFIG_SEED],
+        bump,
+        has_one = admin @ ContentRegistryError::Unauthorized
+    )]
+    pub ecosystem_config: Box<Account<'info, EcosystemConfig>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_ecosystem_sub_config(ctx: Context<MigrateEcosystemSubConfig>) -> Result<()> {
+    let account = ctx.accounts.ecosystem_sub_config.to_account_info();
+    grow_legacy_account::<EcosystemSubConfig>(
+        &account,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    msg!("Migrated ecosystem sub config to {} bytes", account.data_len());
+
+    Ok(())
+}
+
+// ============================================================================
+// MIGRATE CREATOR PATRON CONFIG (tier ladder + trial_duration)
+// ============================================================================
+
+/// CreatorPatronConfig before tiers: fixed membership and subscription prices
+#[derive(AnchorDeserialize)]
+struct LegacyCreatorPatronConfig {
+    creator: Pubkey,
+    membership_price: u64,
+    subscription_price: u64,
+    is_active: bool,
+    created_at: i64,
+    updated_at: i64,
+}
+
+/// Discriminator + creator + two prices + is_active + two timestamps
+const LEGACY_CREATOR_PATRON_CONFIG_LEN: usize = 8 + 32 + 8 + 8 + 1 + 8 + 8;
+
+impl LegacyCreatorPatronConfig {
+    /// Tier ladder matching the old PatronTier enum, so existing subscriptions
+    /// keep their tier: 0 = Membership (support only), 1 = Subscription (Level 2)
+    /// A disabled (0) price becomes a retired tier
+    fn into_current(self) -> CreatorPatronConfig {
+        CreatorPatronConfig {
+            creator: self.creator,
+            tiers: vec![
+                PatronTierConfig {
+                    name: "Membership".to_string(),
+                    price: self.membership_price,
+                    max_visibility: 0,
+                    holder_share_bps: None,
+                },
+                PatronTierConfig {
+                    name: "Subscription".to_string(),
+                    price: self.subscription_price,
+                    max_visibility: 2,
+                    holder_share_bps: None,
+                },
+            ],
+            is_active: self.is_active,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            trial_duration: None,
+        }
+    }
+}
+
+/// Rewrite a legacy CreatorPatronConfig as a two-tier ladder
+/// Admin only - the admin pays the extra rent
+#[derive(Accounts)]
+pub struct MigrateCreatorPatronConfig<'info> {
+    /// CHECK: Legacy layout - owner, discriminator and length verified in handler
+    #[account(
+        mut,
+        seeds = [CREATOR_PATRON_CONFIG_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub patron_config: UncheckedAccount<'info>,
+
+    /// CHECK: Only used to derive the patron config PDA
+    pub creator: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [ECOSYSTEM_CONFIG_SEED],
+        bump,
+        has_one = admin @ ContentRegistryError::Unauthorized
+    )]
+    pub ecosystem_config: Box<Account<'info, EcosystemConfig>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_creator_patron_config(ctx: Context<MigrateCreatorPatronConfig>) -> Result<()> {
+    let account = ctx.accounts.patron_config.to_account_info();
+    require_account_type::<CreatorPatronConfig>(&account)?;
+    require!(
+        account.data_len() == LEGACY_CREATOR_PATRON_CONFIG_LEN,
+        ContentRegistryError::AlreadyMigrated
+    );
+
+    let legacy = LegacyCreatorPatronConfig::deserialize(&mut &account.try_borrow_data()?[8..])?;
+    let config = legacy.into_current();
+
+    resize_account(
+        &account,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + CreatorPatronConfig::INIT_SPACE,
+    )?;
+    config.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    msg!("Migrated patron config for creator {} to {} tiers", config.creator, config.tiers.len());
+
+    Ok(())
+}
+
+// ============================================================================
+// MIGRATE MINT CONFIG (rarity, phases, pricing, rent-to-own)
+// ============================================================================
+
+/// MintConfig before rarity tables, mint phases, pricing modes and rent-to-own
+#[derive(AnchorDeserialize)]
+struct LegacyMintConfig {
+    item_type: ItemType,
+    item: Pubkey,
+    creator: Pubkey,
+    price: u64,
+    currency: PaymentCurrency,
+    max_supply: Option<u64>,
+    creator_royalty_bps: u16,
+    is_active: bool,
+    created_at: i64,
+    updated_at: i64,
+}
+
+/// Discriminator + item_type + item + creator + price + currency + max_supply
+/// (allocated at its Some size) + royalty + is_active + two timestamps
+const LEGACY_MINT_CONFIG_LEN: usize = 8 + 1 + 32 + 32 + 8 + 1 + 9 + 2 + 1 + 8 + 8;
+
+impl LegacyMintConfig {
+    /// Appended fields take the values a newly created config starts with:
+    /// standard rarity odds, no quota or phases, fixed pricing, no rent-to-own and
+    /// no slot-hash rarity opt-in
+    fn into_current(self) -> MintConfig {
+        MintConfig {
+            item_type: self.item_type,
+            item: self.item,
+            creator: self.creator,
+            price: self.price,
+            currency: self.currency,
+            max_supply: self.max_supply,
+            creator_royalty_bps: self.creator_royalty_bps,
+            is_active: self.is_active,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            rarity_table: RarityTable::default(),
+            rarity_quota: None,
+            allowlist_phase: None,
+            public_start_at: 0,
+            public_end_at: None,
+            max_per_wallet: None,
+            pricing_mode: PricingMode::Fixed,
+            rent_to_own_bps: None,
+            instant_rarity: false,
+        }
+    }
+}
+
+/// Rewrite a legacy content or bundle MintConfig in the current layout
+/// Admin only - the admin pays the extra rent
+#[derive(Accounts)]
+pub struct MigrateMintConfig<'info> {
+    /// CHECK: Legacy layout - owner, discriminator and length verified in handler
+    #[account(
+        mut,
+        seeds = [MINT_CONFIG_SEED, item.key().as_ref()],
+        bump
+    )]
+    pub mint_config: UncheckedAccount<'info>,
+
+    /// CHECK: Content or bundle - only used to derive the mint config PDA
+    pub item: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [ECOSYSTEM_CONFIG_SEED],
+        bump,
+        has_one = admin @ ContentRegistryError::Unauthorized
+    )]
+    pub ecosystem_config: Box<Account<'info, EcosystemConfig>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_mint_config(ctx: Context<MigrateMintConfig>) -> Result<()> {
+    let account = ctx.accounts.mint_config.to_account_info();
+    require_account_type::<MintConfig>(&account)?;
+    require!(
+        account.data_len() == LEGACY_MINT_CONFIG_LEN,
+        ContentRegistryError::AlreadyMigrated
+    );
+
+    let legacy = LegacyMintConfig::deserialize(&mut &account.try_borrow_data()?[8..])?;
+    let config = legacy.into_current();
+
+    resize_account(
+        &account,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + MintConfig::INIT_SPACE,
+    )?;
+    config.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    msg!("Migrated mint config for item {} to {} bytes", config.item, account.data_len());
+
+    Ok(())
+}
+
+// ============================================================================
+// MIGRATE RENT CONFIG (rent options + concurrency cap)
+// ============================================================================
+
+/// RentConfig before rent options: fixed 6-hour, 1-day and 7-day fees
+#[derive(AnchorDeserialize)]
+struct LegacyRentConfig {
+    item_type: ItemType,
+    item: Pubkey,
+    creator: Pubkey,
+    rent_fee_6h: u64,
+    rent_fee_1d: u64,
+    rent_fee_7d: u64,
+    is_active: bool,
+    total_rentals: u64,
+    total_fees_collected: u64,
+    created_at: i64,
+    updated_at: i64,
+}
+
+/// Discriminator + item_type + item + creator + three fees + is_active
+/// + two totals + two timestamps
+const LEGACY_RENT_CONFIG_LEN: usize = 8 + 1 + 32 + 32 + 8 + 8 + 8 + 1 + 8 + 8 + 8 + 8;
+
+impl LegacyRentConfig {
+    /// Option list matching the old RentTier indices, so existing clients keep
+    /// renting the same period: 0 = 6 hours, 1 = 1 day, 2 = 7 days
+    /// No concurrency cap, as before
+    fn into_current(self) -> RentConfig {
+        RentConfig {
+            item_type: self.item_type,
+            item: self.item,
+            creator: self.creator,
+            rent_options: vec![
+                RentOption { duration_seconds: 6 * 3600, fee: self.rent_fee_6h },
+                RentOption { duration_seconds: 24 * 3600, fee: self.rent_fee_1d },
+                RentOption { duration_seconds: 7 * 24 * 3600, fee: self.rent_fee_7d },
+            ],
+            is_active: self.is_active,
+            total_rentals: self.total_rentals,
+            total_fees_collected: self.total_fees_collected,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            max_concurrent_rentals: None,
+            active_rental_count: 0,
+        }
+    }
+}
+
+/// Rewrite a legacy content or bundle RentConfig with its three tiers as rent options
+/// Admin only - the admin pays the extra rent
+#[derive(Accounts)]
+pub struct MigrateRentConfig<'info> {
+    /// CHECK: Legacy layout - owner, discriminator and length verified in handler
+    #[account(
+        mut,
+        seeds = [RENT_CONFIG_SEED, item.key().as_ref()],
+        bump
+    )]
+    pub rent_config: UncheckedAccount<'info>,
+
+    /// CHECK: Content or bundle - only used to derive the rent config PDA
+    pub item: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [ECOSYSTEM_CONFIG_SEED],
+        bump,
+        has_one = admin @ ContentRegistryError::Unauthorized
+    )]
+    pub ecosystem_config: Box<Account<'info, EcosystemConfig>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_rent_config(ctx: Context<MigrateRentConfig>) -> Result<()> {
+    let account = ctx.accounts.rent_config.to_account_info();
+    require_account_type::<RentConfig>(&account)?;
+    require!(
+        account.data_len() == LEGACY_RENT_CONFIG_LEN,
+        ContentRegistryError::AlreadyMigrated
+    );
+
+    let legacy = LegacyRentConfig::deserialize(&mut &account.try_borrow_data()?[8..])?;
+    let config = legacy.into_current();
+
+    resize_account(
+        &account,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + RentConfig::INIT_SPACE,
+    )?;
+    config.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    msg!("Migrated rent config for item {} to {} options", config.item, config.rent_options.len());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_patron_config_keeps_tier_indices() {
+        let creator = Pubkey::new_unique();
+        let mut data = CreatorPatronConfig::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(creator.as_ref());
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.extend_from_slice(&50_000_000u64.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(&10i64.to_le_bytes());
+        data.extend_from_slice(&20i64.to_le_bytes());
+        assert_eq!(data.len(), LEGACY_CREATOR_PATRON_CONFIG_LEN);
+
+        let config = LegacyCreatorPatronConfig::deserialize(&mut &data[8..]).unwrap().into_current();
+        assert_eq!(config.creator, creator);
+        assert!(config.is_active);
+        assert_eq!((config.created_at, config.updated_at), (10, 20));
+        assert_eq!(config.trial_duration, None);
+
+        // Disabled membership is retired, subscription keeps Level 2 access
+        assert_eq!(config.tiers[0].price, 0);
+        assert!(!config.tiers[0].grants_visibility(1));
+        assert_eq!(config.get_tier(1).unwrap().price, 50_000_000);
+        assert!(config.tiers[1].grants_visibility(2));
+
+        // Rewritten layout round-trips and fits the allocated space
+        let mut buf = Vec::new();
+        config.try_serialize(&mut buf).unwrap();
+        assert!(buf.len() <= 8 + CreatorPatronConfig::INIT_SPACE);
+        let parsed = CreatorPatronConfig::try_deserialize(&mut &buf[..]).unwrap();
+        assert_eq!(parsed.tiers, config.tiers);
+    }
+
+    #[test]
+    fn legacy_mint_config_gets_creation_defaults() {
+        let item = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let mut data = MintConfig::DISCRIMINATOR.to_vec();
+        data.push(1); // ItemType::Bundle
+        data.extend_from_slice(item.as_ref());
+        data.extend_from_slice(creator.as_ref());
+        data.extend_from_slice(&25_000_000u64.to_le_bytes());
+        data.push(0); // PaymentCurrency::Sol
+        data.push(1);
+        data.extend_from_slice(&100u64.to_le_bytes());
+        data.extend_from_slice(&500u16.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(&10i64.to_le_bytes());
+        data.extend_from_slice(&20i64.to_le_bytes());
+        assert_eq!(data.len(), LEGACY_MINT_CONFIG_LEN);
+
+        let config = LegacyMintConfig::deserialize(&mut &data[8..]).unwrap().into_current();
+        assert_eq!(config.item_type, ItemType::Bundle);
+        assert_eq!((config.item, config.creator), (item, creator));
+        assert_eq!(config.price, 25_000_000);
+        assert!(config.currency == PaymentCurrency::Sol);
+        assert_eq!(config.max_supply, Some(100));
+        assert_eq!(config.creator_royalty_bps, 500);
+        assert!(config.is_active);
+        assert_eq!((config.created_at, config.updated_at), (10, 20));
+        assert!(config.rarity_table.is_valid());
+        assert_eq!(config.pricing_mode, PricingMode::Fixed);
+        assert_eq!(config.rent_to_own_bps, None);
+        assert!(!config.instant_rarity);
+
+        // Rewritten layout round-trips and fits the allocated space
+        let mut buf = Vec::new();
+        config.try_serialize(&mut buf).unwrap();
+        assert!(buf.len() <= 8 + MintConfig::INIT_SPACE);
+        let parsed = MintConfig::try_deserialize(&mut &buf[..]).unwrap();
+        assert_eq!(parsed.rarity_table, config.rarity_table);
+        assert_eq!(parsed.max_supply, Some(100));
+    }
+
+    #[test]
+    fn legacy_rent_config_keeps_tier_indices() {
+        let item = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let mut data = RentConfig::DISCRIMINATOR.to_vec();
+        data.push(0); // ItemType::Content
+        data.extend_from_slice(item.as_ref());
+        data.extend_from_slice(creator.as_ref());
+        data.extend_from_slice(&2_000_000u64.to_le_bytes());
+        data.extend_from_slice(&5_000_000u64.to_le_bytes());
+        data.extend_from_slice(&20_000_000u64.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(&3u64.to_le_bytes());
+        data.extend_from_slice(&27_000_000u64.to_le_bytes());
+        data.extend_from_slice(&10i64.to_le_bytes());
+        data.extend_from_slice(&20i64.to_le_bytes());
+        assert_eq!(data.len(), LEGACY_RENT_CONFIG_LEN);
+
+        let config = LegacyRentConfig::deserialize(&mut &data[8..]).unwrap().into_current();
+        assert_eq!(config.item_type, ItemType::Content);
+        assert_eq!((config.item, config.creator), (item, creator));
+        assert!(config.is_active);
+        assert_eq!((config.total_rentals, config.total_fees_collected), (3, 27_000_000));
+        assert_eq!((config.created_at, config.updated_at), (10, 20));
+        assert_eq!(config.max_concurrent_rentals, None);
+        assert_eq!(config.active_rental_count, 0);
+
+        // Old tier indices select the same period and fee
+        assert_eq!(config.get_option(0).unwrap(), RentOption { duration_seconds: 21_600, fee: 2_000_000 });
+        assert_eq!(config.get_option(1).unwrap(), RentOption { duration_seconds: 86_400, fee: 5_000_000 });
+        assert_eq!(config.get_option(2).unwrap(), RentOption { duration_seconds: 604_800, fee: 20_000_000 });
+        assert!(RentConfig::validate_options(&config.rent_options).is_ok());
+
+        // Rewritten layout round-trips and fits the allocated space
+        let mut buf = Vec::new();
+        config.try_serialize(&mut buf).unwrap();
+        assert!(buf.len() <= 8 + RentConfig::INIT_SPACE);
+        let parsed = RentConfig::try_deserialize(&mut &buf[..]).unwrap();
+        assert_eq!(parsed.rent_options, config.rent_options);
+    }
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::ContentRegistryError;
+use crate::utils::verify_allowlist_proof;
+
+// ============================================================================
+// MINT PHASES - Allowlist (presale) window followed by the public window
+// ============================================================================
+
+/// Resolve the active phase for a content mint and return the price to charge
+/// During the allowlist phase the wallet must prove membership and stay under
+/// the per-wallet cap; its WalletMintRecord is lazily initialized and incremented.
+/// The public phase charges the pricing mode's current price.
+pub(crate) fn apply_mint_phase(
+    mint_config: &MintConfig,
+    wallet_mint_record: Option<&mut WalletMintRecord>,
+    wallet: Pubkey,
+    item: Pubkey,
+    allowlist_proof: Option<Vec<[u8; 32]>>,
+    sold_count: u64,
+    now: i64,
+) -> Result<u64> {
+    let phase = mint_config
+        .active_phase(now)
+        .ok_or(ContentRegistryError::MintPhaseNotActive)?;
+
+    match (phase, mint_config.allowlist_phase) {
+        (MintPhase::Allowlist, Some(allowlist)) => {
+            let proof = allowlist_proof.ok_or(ContentRegistryError::InvalidAllowlistProof)?;
+            require!(
+                verify_allowlist_proof(&proof, &allowlist.merkle_root, &wallet.to_bytes()),
+                ContentRegistryError::InvalidAllowlistProof
+            );
+
+            let record = wallet_mint_record.ok_or(ContentRegistryError::WalletMintRecordRequired)?;
+            record.init_if_new(wallet, item);
+            require!(
+                record.allowlist_minted < allowlist.max_per_wallet,
+                ContentRegistryError::AllowlistLimitReached
+            );
+            record.allowlist_minted += 1;
+
+            Ok(allowlist.price)
+        }
+        _ => Ok(mint_config.current_price(sold_count, now)),
+    }
+}
+
+// ============================================================================
+// CONFIGURE MINT PHASES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ConfigureMintPhases<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        has_one = creator
+    )]
+    pub content: Account<'info, ContentEntry>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        seeds = [MINT_CONFIG_SEED, content.key().as_ref()],
+        bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+}
+
+pub fn handle_configure_mint_phases(
+    ctx: Context<ConfigureMintPhases>,
+    allowlist_phase: Option<AllowlistPhase>,
+    public_start_at: i64,
+    public_end_at: Option<i64>,
+) -> Result<()> {
+    let mint_config = &mut ctx.accounts.mint_config;
+
+    if let Some(allowlist) = &allowlist_phase {
+        require!(allowlist.start_at < allowlist.end_at, ContentRegistryError::InvalidMintPhase);
+        require!(allowlist.max_per_wallet > 0, ContentRegistryError::InvalidMintPhase);
+        // Public phase follows the allowlist phase
+        require!(public_start_at >= allowlist.end_at, ContentRegistryError::InvalidMintPhase);
+        require!(
+            MintConfig::validate_price(allowlist.price, mint_config.currency),
+            ContentRegistryError::PriceTooLow
+        );
+    }
+    if let Some(end) = public_end_at {
+        require!(end > public_start_at, ContentRegistryError::InvalidMintPhase);
+    }
+
+    mint_config.allowlist_phase = allowlist_phase;
+    mint_config.public_start_at = public_start_at;
+    mint_config.public_end_at = public_end_at;
+    mint_config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Mint phases configured: allowlist={} public_start={}",
+        allowlist_phase.is_some(),
+        public_start_at
+    );
+
+    Ok(())
+}
@@ -0,0 +1,529 @@
+use anchor_lang::prelude::*;
+use switchboard_on_demand::{RandomnessAccountData, ON_DEMAND_DEVNET_PID, ON_DEMAND_MAINNET_PID};
+
+use crate::state::*;
+use crate::errors::ContentRegistryError;
+use crate::events::NftMintEvent;
+use crate::contexts::simple_mint::determine_rarity_from_bytes;
+use crate::contexts::content_edition::{ContentEditionPools, EditionNftAccounts};
+use crate::contexts::mint_phase::apply_mint_phase;
+use crate::MPL_CORE_ID;
+
+// ============================================================================
+// COMMIT-REVEAL MINT - Content NFT with Switchboard On-Demand randomness
+// ============================================================================
+// Two-phase mint so rarity can't be predicted or ground by simulating the tx:
+// 1. request_mint: buyer commits to a fresh Switchboard randomness account,
+//    escrows the price in the MintRequest PDA and reserves a pending slot
+// 2. reveal_mint: in the oracle's reveal slot (bundled with the Switchboard reveal
+//    instruction), rarity is derived from the oracle value and the NFT asset fixed
+//    at request time, the NFT is minted and the escrow is paid out 80/5/3/12
+// 3. If the oracle value can't be read by MINT_REQUEST_TIMEOUT (reveal slot missed,
+//    oracle never answered, or the buyer withheld the reveal), anyone can complete
+//    the request with reveal_mint using the forfeited roll (lowest available tier,
+//    same as settle_auction). There is no refund, so a buyer who reads an
+//    unfavourable value off-chain gains nothing by withholding the reveal.
+//
+// Escrow is SOL only; USDC-priced content uses simple_mint.
+
+/// Read the Switchboard randomness account, verifying its owner
+pub(crate) fn load_randomness(randomness_account: &AccountInfo) -> Result<RandomnessAccountData> {
+    let owner = randomness_account.owner.to_bytes();
+    require!(
+        owner == ON_DEMAND_MAINNET_PID.to_bytes() || owner == ON_DEMAND_DEVNET_PID.to_bytes(),
+        ContentRegistryError::InvalidRandomnessAccount
+    );
+    let randomness = RandomnessAccountData::parse(randomness_account.data.borrow())
+        .map_err(|_| ContentRegistryError::InvalidRandomnessAccount)?;
+    Ok(*randomness)
+}
+
+/// Read the oracle value for a commitment made at `seed_slot`
+/// Only readable in the reveal slot, so the reveal must share a transaction with the oracle reveal
+pub(crate) fn read_revealed_randomness(
+    randomness_account: &AccountInfo,
+    seed_slot: u64,
+    clock_slot: u64,
+) -> Result<[u8; 32]> {
+    let randomness = load_randomness(randomness_account)?;
+    require!(randomness.seed_slot == seed_slot, ContentRegistryError::InvalidRandomnessAccount);
+    randomness
+        .get_value(clock_slot)
+        .map_err(|_| ContentRegistryError::RandomnessNotResolved.into())
+}
+
+/// Move lamports out of a program-owned escrow
+pub(crate) fn pay_from_escrow(escrow: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    **escrow.try_borrow_mut_lamports()? -= amount;
+    **to.try_borrow_mut_lamports()? += amount;
+    Ok(())
+}
+
+// ============================================================================
+// REQUEST MINT
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct RequestMint<'info> {
+    #[account(
+        seeds = [ECOSYSTEM_CONFIG_SEED],
+        bump
+    )]
+    pub ecosystem_config: Box<Account<'info, EcosystemConfig>>,
+
+    #[account(mut)]
+    pub content: Box<Account<'info, ContentEntry>>,
+
+    #[account(
+        seeds = [MINT_CONFIG_SEED, content.key().as_ref()],
+        bump
+    )]
+    pub mint_config: Box<Account<'info, MintConfig>>,
+
+    /// Pending request - escrows the mint price
+    #[account(
+        init,
+        payer = buyer,
+        space = 8 + MintRequest::INIT_SPACE,
+        seeds = [MINT_REQUEST_SEED, buyer.key().as_ref(), content.key().as_ref()],
+        bump
+    )]
+    pub mint_request: Box<Account<'info, MintRequest>>,
+
+    /// Switchboard randomness account committed in the previous slot
+    /// CHECK: Owner and layout verified in handler
+    pub randomness_account: AccountInfo<'info>,
+
+    /// CHECK: Optional platform wallet for commission (recorded for reveal)
+    pub platform: Option<AccountInfo<'info>>,
+
+    /// Per-(wallet, content) mint counter (required during the allowlist phase or with max_per_wallet)
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + WalletMintRecord::INIT_SPACE,
+        seeds = [WALLET_MINT_RECORD_SEED, content.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub wallet_mint_record: Option<Box<Account<'info, WalletMintRecord>>>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_request_mint(
+    ctx: Context<RequestMint>,
+    allowlist_proof: Option<Vec<[u8; 32]>>,
+    max_price: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(ctx.accounts.mint_config.is_active, ContentRegistryError::MintingNotActive);
+    require!(!ctx.accounts.ecosystem_config.is_paused, ContentRegistryError::EcosystemPaused);
+    require!(
+        ctx.accounts.mint_config.currency == PaymentCurrency::Sol,
+        ContentRegistryError::InvalidCurrency
+    );
+
+    // Pending requests reserve supply until revealed or cancelled
+    let content = &ctx.accounts.content;
+    if let Some(max_supply) = ctx.accounts.mint_config.max_supply {
+        require!(
+            content.minted_count + content.pending_count < max_supply,
+            ContentRegistryError::MaxSupplyReached
+        );
+    }
+
+    // Randomness must have been committed in the previous slot and not yet revealed
+    let randomness = load_randomness(&ctx.accounts.randomness_account)?;
+    require!(
+        randomness.seed_slot == clock.slot.saturating_sub(1) && randomness.reveal_slot == 0,
+        ContentRegistryError::RandomnessAlreadyRevealed
+    );
+
+    // Allowlist phase overrides the price and enforces the allowlist cap
+    let mint_price = apply_mint_phase(
+        &ctx.accounts.mint_config,
+        ctx.accounts.wallet_mint_record.as_deref_mut().map(|r| &mut **r),
+        ctx.accounts.buyer.key(),
+        ctx.accounts.content.key(),
+        allowlist_proof,
+        ctx.accounts.content.minted_count + ctx.accounts.content.pending_count,
+        clock.unix_timestamp,
+    )?;
+    MintConfig::require_max_price(mint_price, max_price)?;
+
+    // Per-wallet cap across all phases (counted at request time)
+    ctx.accounts.content.record_wallet_mint(
+        ctx.accounts.wallet_mint_record.as_deref_mut().map(|r| &mut **r),
+        ctx.accounts.content.key(),
+        ctx.accounts.buyer.key(),
+        ctx.accounts.mint_config.max_per_wallet,
+    )?;
+
+    // Escrow the price in the request PDA
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.mint_request.to_account_info(),
+            },
+        ),
+        mint_price,
+    )?;
+
+    // The asset address is fixed now so the reveal can't re-bind the oracle value
+    let buyer_key = ctx.accounts.buyer.key();
+    let content_key = ctx.accounts.content.key();
+    let (nft_asset, _) = Pubkey::find_program_address(
+        &[REQUEST_NFT_SEED, buyer_key.as_ref(), content_key.as_ref(), &randomness.seed_slot.to_le_bytes()],
+        &crate::ID,
+    );
+
+    let request = &mut ctx.accounts.mint_request;
+    request.buyer = buyer_key;
+    request.content = content_key;
+    request.platform = ctx.accounts.platform.as_ref().map(|p| p.key());
+    request.randomness_account = ctx.accounts.randomness_account.key();
+    request.seed_slot = randomness.seed_slot;
+    request.amount_paid = mint_price;
+    request.created_at = clock.unix_timestamp;
+    request.nft_asset = nft_asset;
+
+    ctx.accounts.content.increment_pending();
+
+    msg!("Mint requested: {} lamports escrowed, randomness slot {}", mint_price, randomness.seed_slot);
+
+    Ok(())
+}
+
+// ============================================================================
+// REVEAL MINT
+// ============================================================================
+
+/// Complete a pending mint once the oracle has revealed, or with the forfeited
+/// roll once the request has timed out
+/// Permissionless - the caller pays rent for the new NFT accounts
+#[derive(Accounts)]
+pub struct RevealMint<'info> {
+    // =========================================================================
+    // Core accounts
+    // =========================================================================
+
+    #[account(
+        seeds = [ECOSYSTEM_CONFIG_SEED],
+        bump
+    )]
+    pub ecosystem_config: Box<Account<'info, EcosystemConfig>>,
+
+    #[account(mut)]
+    pub content: Box<Account<'info, ContentEntry>>,
+
+    /// MintConfig PDA - authority for collection operations (signs NFT creation)
+    #[account(
+        mut,
+        seeds = [MINT_CONFIG_SEED, content.key().as_ref()],
+        bump
+    )]
+    pub mint_config: Box<Account<'info, MintConfig>>,
+
+    /// Pending request - escrow is paid out, remaining rent returns to buyer
+    #[account(
+        mut,
+        seeds = [MINT_REQUEST_SEED, buyer.key().as_ref(), content.key().as_ref()],
+        bump,
+        close = buyer
+    )]
+    pub mint_request: Box<Account<'info, MintRequest>>,
+
+    /// Switchboard randomness account committed at request time
+    /// CHECK: Must match mint_request.randomness_account, layout verified in handler
+    #[account(
+        constraint = randomness_account.key() == mint_request.randomness_account @ ContentRegistryError::InvalidRandomnessAccount
+    )]
+    pub randomness_account: AccountInfo<'info>,
+
+    /// Content-specific reward pool (IMMEDIATE distribution)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ContentRewardPool::INIT_SPACE,
+        seeds = [CONTENT_REWARD_POOL_SEED, content.key().as_ref()],
+        bump
+    )]
+    pub content_reward_pool: Box<Account<'info, ContentRewardPool>>,
+
+    /// The Metaplex Core Collection asset
+    /// CHECK: Verified via content.collection_asset
+    #[account(
+        mut,
+        constraint = collection_asset.key() == content.collection_asset @ ContentRegistryError::InvalidCollection
+    )]
+    pub collection_asset: AccountInfo<'info>,
+
+    /// CHECK: Creator to receive payment
+    #[account(mut, constraint = content.creator == creator.key())]
+    pub creator: AccountInfo<'info>,
+
+    /// CHECK: Ecosystem treasury
+    #[account(mut, constraint = ecosystem_config.treasury == treasury.key())]
+    pub treasury: AccountInfo<'info>,
+
+    /// CHECK: Platform wallet recorded at request time (must match)
+    #[account(mut)]
+    pub platform: Option<AccountInfo<'info>>,
+
+    // =========================================================================
+    // NFT accounts
+    // =========================================================================
+
+    /// CHECK: NFT asset - PDA fixed at request time from buyer, content, and seed slot
+    #[account(
+        mut,
+        seeds = [REQUEST_NFT_SEED, buyer.key().as_ref(), content.key().as_ref(), &mint_request.seed_slot.to_le_bytes()],
+        bump,
+        constraint = nft_asset.key() == mint_request.nft_asset @ ContentRegistryError::InvalidNftAsset
+    )]
+    pub nft_asset: AccountInfo<'info>,
+
+    /// Unified NFT reward state - tracks debts for all pools + rarity
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + UnifiedNftRewardState::INIT_SPACE,
+        seeds = [UNIFIED_NFT_REWARD_STATE_SEED, nft_asset.key().as_ref()],
+        bump
+    )]
+    pub unified_nft_state: Box<Account<'info, UnifiedNftRewardState>>,
+
+    // =========================================================================
+    // Subscription pool accounts (LAZY distribution pools)
+    // =========================================================================
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_HOLDER_POOL_SEED],
+        bump
+    )]
+    pub global_holder_pool: Box<Account<'info, GlobalHolderPool>>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_DIST_POOL_SEED],
+        bump
+    )]
+    pub creator_dist_pool: Box<Account<'info, CreatorDistPool>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CreatorPatronPool::INIT_SPACE,
+        seeds = [CREATOR_PATRON_POOL_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_patron_pool: Box<Account<'info, CreatorPatronPool>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CreatorWeight::INIT_SPACE,
+        seeds = [CREATOR_WEIGHT_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_weight: Box<Account<'info, CreatorWeight>>,
+
+    /// CHECK: PDA verified by seeds
+    #[account(
+        mut,
+        seeds = [CREATOR_PATRON_TREASURY_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_patron_treasury: AccountInfo<'info>,
+
+    /// CHECK: PDA verified by seeds
+    #[account(
+        mut,
+        seeds = [ECOSYSTEM_STREAMING_TREASURY_SEED],
+        bump
+    )]
+    pub ecosystem_streaming_treasury: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [ECOSYSTEM_EPOCH_STATE_SEED],
+        bump
+    )]
+    pub ecosystem_epoch_state: Box<Account<'info, EcosystemEpochState>>,
+
+    // =========================================================================
+    // Buyer, payer and system accounts
+    // =========================================================================
+
+    /// CHECK: Buyer recorded in mint_request (receives NFT and escrow rent)
+    #[account(mut)]
+    pub buyer: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: MPL Core program
+    #[account(address = MPL_CORE_ID)]
+    pub mpl_core_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RevealMint<'info> {
+    pub fn handler(ctx: Context<RevealMint>, content_name: String) -> Result<()> {
+        require!(
+            !content_name.is_empty() && content_name.len() <= 32,
+            ContentRegistryError::InvalidContentName
+        );
+
+        let clock = Clock::get()?;
+        let timestamp = clock.unix_timestamp;
+
+        let buyer_key = ctx.accounts.buyer.key();
+        let content_key = ctx.accounts.content.key();
+        let creator_key = ctx.accounts.creator.key();
+        let nft_asset_key = ctx.accounts.nft_asset.key();
+
+        // =====================================================================
+        // STEP 0: Validation
+        // =====================================================================
+
+        require!(
+            ctx.accounts.platform.as_ref().map(|p| p.key()) == ctx.accounts.mint_request.platform,
+            ContentRegistryError::Unauthorized
+        );
+
+        let mint_price = ctx.accounts.mint_request.amount_paid;
+
+        // =====================================================================
+        // STEP 1: Read oracle randomness
+        // =====================================================================
+
+        let revealed = read_revealed_randomness(
+            &ctx.accounts.randomness_account,
+            ctx.accounts.mint_request.seed_slot,
+            clock.slot,
+        );
+        let random_bytes = match revealed {
+            // Bind the oracle value to this NFT so one reveal can't be replayed elsewhere
+            Ok(value) => solana_sha256_hasher::hashv(&[
+                &value,
+                nft_asset_key.as_ref(),
+                buyer_key.as_ref(),
+            ]).to_bytes(),
+            // Forfeited roll: zero bytes always draw the lowest available tier,
+            // so missing or withholding the reveal never pays off
+            Err(_) if ctx.accounts.mint_request.is_expired(timestamp) => [0u8; 32],
+            Err(err) => return Err(err),
+        };
+
+        let (rarity, weight) = determine_rarity_from_bytes(random_bytes, &mut ctx.accounts.mint_config)?;
+
+        // =====================================================================
+        // STEP 2: Initialize pools (lazy) and trigger epoch distribution if needed
+        // =====================================================================
+
+        let platform_treasury_info = ctx.accounts.platform.as_ref()
+            .map(|p| p.to_account_info())
+            .unwrap_or_else(|| ctx.accounts.treasury.to_account_info());
+        let system_program_info = ctx.accounts.system_program.to_account_info();
+
+        let mut pools = ContentEditionPools {
+            content_reward_pool: &mut ctx.accounts.content_reward_pool,
+            creator_patron_pool: &mut ctx.accounts.creator_patron_pool,
+            global_holder_pool: &mut ctx.accounts.global_holder_pool,
+            creator_dist_pool: &mut ctx.accounts.creator_dist_pool,
+            creator_weight: &mut ctx.accounts.creator_weight,
+            ecosystem_epoch_state: &mut ctx.accounts.ecosystem_epoch_state,
+            creator_patron_treasury: &ctx.accounts.creator_patron_treasury,
+            ecosystem_streaming_treasury: &ctx.accounts.ecosystem_streaming_treasury,
+        };
+        pools.prepare(
+            content_key,
+            &ctx.accounts.creator,
+            &platform_treasury_info,
+            &ctx.accounts.treasury,
+            &system_program_info,
+            timestamp,
+        )?;
+
+        // =====================================================================
+        // STEP 3: Create NFT via Metaplex Core
+        // =====================================================================
+
+        let edition = ctx.accounts.content.minted_count + 1;
+        let seed_slot_bytes = ctx.accounts.mint_request.seed_slot.to_le_bytes();
+
+        let mint_config_seeds = &[
+            MINT_CONFIG_SEED,
+            content_key.as_ref(),
+            &[ctx.bumps.mint_config],
+        ];
+
+        let nft_seeds = &[
+            REQUEST_NFT_SEED,
+            buyer_key.as_ref(),
+            content_key.as_ref(),
+            &seed_slot_bytes,
+            &[ctx.bumps.nft_asset],
+        ];
+
+        EditionNftAccounts {
+            mpl_core_program: &ctx.accounts.mpl_core_program,
+            nft_asset: &ctx.accounts.nft_asset,
+            collection_asset: &ctx.accounts.collection_asset,
+            mint_config: &ctx.accounts.mint_config.to_account_info(),
+            payer: &ctx.accounts.payer.to_account_info(),
+            owner: &ctx.accounts.buyer,
+            system_program: &system_program_info,
+        }
+        .create(&content_name, &content_key, rarity, edition, &[mint_config_seeds, nft_seeds])?;
+
+        // Pending slot becomes a minted edition (locks content on first mint)
+        ctx.accounts.content.decrement_pending();
+        ctx.accounts.content.increment_minted();
+
+        // =====================================================================
+        // STEP 4: Pay out escrow (80/5/3/12 split)
+        // =====================================================================
+
+        pools.pay_primary_sale_from_escrow(
+            &ctx.accounts.mint_request.to_account_info(),
+            &ctx.accounts.creator,
+            &platform_treasury_info,
+            &ctx.accounts.treasury,
+            mint_price,
+        )?;
+
+        // =====================================================================
+        // STEP 5: Set reward debts, then add NFT weight to ALL pools
+        // =====================================================================
+
+        pools.register_edition(&mut ctx.accounts.unified_nft_state, nft_asset_key, rarity, weight, timestamp);
+
+        emit!(NftMintEvent {
+            content: content_key,
+            buyer: buyer_key,
+            creator: creator_key,
+            edition_number: edition,
+            price: mint_price,
+            timestamp,
+            nft_asset: nft_asset_key,
+        });
+
+        msg!("Reveal mint completed!");
+        msg!("NFT: {} | Rarity: {:?} | Weight: {}", nft_asset_key, rarity, weight);
+
+        Ok(())
+    }
+}
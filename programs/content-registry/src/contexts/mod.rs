@@ -21,6 +21,18 @@ pub mod streamflow_membership;
 pub mod profile;
 pub mod treasury_unwrap;
 pub mod update_metadata;
+pub mod usdc_payment;
+pub mod mint_request;
+pub mod mint_phase;
+pub mod auction;
+pub mod marketplace;
+pub mod access;
+pub mod subscription_expiry;
+pub mod trial;
+pub mod gift;
+pub mod subscription_vault;
+pub mod migrate;
+pub mod content_edition;
 
 pub use ecosystem::*;
 pub use content::*;
@@ -45,3 +57,13 @@ pub use streamflow_membership::*;
 pub use profile::*;
 pub use treasury_unwrap::*;
 pub use update_metadata::*;
+pub use usdc_payment::*;
+pub use mint_request::*;
+pub use mint_phase::*;
+pub use auction::*;
+pub use marketplace::*;
+pub use access::*;
+pub use subscription_expiry::*;
+pub use trial::*;
+pub use subscription_vault::*;
+pub use migrate::*;
@@ -1,13 +1,14 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::ContentRegistryError;
+use crate::contexts::streamflow_membership::validate_subscription_stream;
 
 // ============================================================================
 // INITIALIZE PATRON CONFIG (Creator sets up subscription tiers)
 // ============================================================================
 
 /// Initialize patron configuration for a creator
-/// Allows creators to set membership (support-only) and subscription (support + access) prices
+/// Allows creators to define a ladder of named tiers, each with a price and the visibility it unlocks
 #[derive(Accounts)]
 pub struct InitPatronConfig<'info> {
     /// Creator's patron configuration (to be created)
@@ -37,28 +38,23 @@ pub struct InitPatronConfig<'info> {
     pub system_program: Program<'info, System>,
 }
 
-/// Initialize patron config with membership and/or subscription tiers
+/// Initialize patron config with the creator's tier ladder
 pub fn handle_init_patron_config(
     ctx: Context<InitPatronConfig>,
-    membership_price: u64,
-    subscription_price: u64,
+    tiers: Vec<PatronTierConfig>,
 ) -> Result<()> {
     let timestamp = Clock::get()?.unix_timestamp;
 
-    // At least one tier must be enabled
-    require!(
-        membership_price > 0 || subscription_price > 0,
-        ContentRegistryError::InvalidPatronConfig
-    );
+    CreatorPatronConfig::validate_tiers(&tiers)?;
 
     // Initialize patron config
     let config = &mut ctx.accounts.patron_config;
     config.creator = ctx.accounts.creator.key();
-    config.membership_price = membership_price;
-    config.subscription_price = subscription_price;
+    config.tiers = tiers;
     config.is_active = true;
     config.created_at = timestamp;
     config.updated_at = timestamp;
+    config.trial_duration = None;
 
     // Initialize patron pool if this is the first time
     let pool = &mut ctx.accounts.creator_patron_pool;
@@ -74,8 +70,9 @@ pub fn handle_init_patron_config(
     }
 
     msg!("Patron config initialized for creator: {}", ctx.accounts.creator.key());
-    msg!("  Membership price: {} lamports", membership_price);
-    msg!("  Subscription price: {} lamports", subscription_price);
+    for (index, tier) in ctx.accounts.patron_config.tiers.iter().enumerate() {
+        msg!("  Tier {} ({}): {} lamports, visibility <= {}", index, tier.name, tier.price, tier.max_visibility);
+    }
 
     Ok(())
 }
@@ -100,34 +97,31 @@ pub struct UpdatePatronConfig<'info> {
     pub creator: Signer<'info>,
 }
 
-/// Update patron config prices or active status
+/// Update patron config tiers, active status or free trial length
+/// Subscriptions reference tiers by index: existing tiers may only change price
+/// (0 retires a tier), new tiers are appended
+/// trial_duration: Some(None) stops offering trials
 pub fn handle_update_patron_config(
     ctx: Context<UpdatePatronConfig>,
-    membership_price: Option<u64>,
-    subscription_price: Option<u64>,
+    tiers: Option<Vec<PatronTierConfig>>,
     is_active: Option<bool>,
+    trial_duration: Option<Option<i64>>,
 ) -> Result<()> {
     let timestamp = Clock::get()?.unix_timestamp;
     let config = &mut ctx.accounts.patron_config;
 
-    if let Some(price) = membership_price {
-        config.membership_price = price;
-    }
-
-    if let Some(price) = subscription_price {
-        config.subscription_price = price;
+    if let Some(tiers) = tiers {
+        config.validate_tier_update(&tiers)?;
+        config.tiers = tiers;
     }
 
     if let Some(active) = is_active {
         config.is_active = active;
     }
 
-    // Validate at least one tier is enabled if active
-    if config.is_active {
-        require!(
-            config.membership_price > 0 || config.subscription_price > 0,
-            ContentRegistryError::InvalidPatronConfig
-        );
+    if let Some(trial_duration) = trial_duration {
+        validate_trial_duration(trial_duration)?;
+        config.trial_duration = trial_duration;
     }
 
     config.updated_at = timestamp;
@@ -144,6 +138,7 @@ pub fn handle_update_patron_config(
 /// Subscribe to a creator (Streamflow handles payment)
 /// Creates subscription record - actual payment is via Streamflow stream to treasury
 #[derive(Accounts)]
+#[instruction(tier_index: u8, stream_id: Pubkey)]
 pub struct SubscribePatron<'info> {
     /// Creator's patron configuration
     #[account(
@@ -170,6 +165,10 @@ pub struct SubscribePatron<'info> {
     )]
     pub creator: AccountInfo<'info>,
 
+    /// CHECK: Streamflow stream paying for the subscription - terms verified in handler
+    #[account(address = stream_id @ ContentRegistryError::InvalidStreamId)]
+    pub stream_metadata: AccountInfo<'info>,
+
     /// The subscriber paying for the subscription
     #[account(mut)]
     pub subscriber: Signer<'info>,
@@ -182,27 +181,30 @@ pub struct SubscribePatron<'info> {
 /// stream_id: The Streamflow stream ID for this subscription's payment
 pub fn handle_subscribe_patron(
     ctx: Context<SubscribePatron>,
-    tier: PatronTier,
+    tier_index: u8,
     stream_id: Pubkey,
 ) -> Result<()> {
     let timestamp = Clock::get()?.unix_timestamp;
-    let config = &ctx.accounts.patron_config;
-
-    // Validate tier is available
-    match tier {
-        PatronTier::Membership => {
-            require!(config.membership_price > 0, ContentRegistryError::TierNotAvailable);
-        }
-        PatronTier::Subscription => {
-            require!(config.subscription_price > 0, ContentRegistryError::TierNotAvailable);
-        }
-    };
+    let price = ctx.accounts.patron_config.get_tier(tier_index)?.price;
+
+    // Stream must come from the subscriber and pay the creator's patron treasury at the tier price
+    let creator_key = ctx.accounts.creator.key();
+    let (treasury, _) = Pubkey::find_program_address(
+        &[CREATOR_PATRON_TREASURY_SEED, creator_key.as_ref()],
+        &crate::id(),
+    );
+    validate_subscription_stream(
+        &ctx.accounts.stream_metadata,
+        &ctx.accounts.subscriber.key(),
+        &treasury,
+        price,
+    )?;
 
     // Initialize subscription record (payment handled by Streamflow)
     let subscription = &mut ctx.accounts.patron_subscription;
     subscription.subscriber = ctx.accounts.subscriber.key();
     subscription.creator = ctx.accounts.creator.key();
-    subscription.tier = tier;
+    subscription.tier_index = tier_index;
     subscription.stream_id = stream_id;
     subscription.started_at = timestamp;
     subscription.is_active = true;
@@ -210,7 +212,7 @@ pub fn handle_subscribe_patron(
     msg!("Patron subscription created (Streamflow payment)");
     msg!("  Subscriber: {}", ctx.accounts.subscriber.key());
     msg!("  Creator: {}", ctx.accounts.creator.key());
-    msg!("  Tier: {:?}", tier);
+    msg!("  Tier: {}", tier_index);
     msg!("  Stream ID: {}", stream_id);
 
     Ok(())
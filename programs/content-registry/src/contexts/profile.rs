@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::{UserProfile, USER_PROFILE_SEED, MAX_USERNAME_LENGTH};
+use crate::state::{UserProfile, USER_PROFILE_SEED};
 use crate::errors::ContentRegistryError;
 
 // ============================================================================
@@ -1,8 +1,326 @@
 use anchor_lang::prelude::*;
+use mpl_core::accounts::{BaseAssetV1, BaseCollectionV1};
+use mpl_core::fetch_plugin;
+use mpl_core::instructions::{BurnV1CpiBuilder, UpdatePluginV1CpiBuilder};
+use mpl_core::types::{Attribute, Attributes, Plugin, PluginAuthority, PluginType, UpdateAuthority};
 use crate::state::*;
 use crate::errors::ContentRegistryError;
+use crate::events::ContentRentedEvent;
 use crate::MPL_CORE_ID;
 
+/// Read the Attributes plugin of a rental NFT
+/// Returns the plugin authority and the attributes
+pub(crate) fn fetch_rental_attributes(nft_asset: &AccountInfo) -> Result<(PluginAuthority, Attributes)> {
+    let (authority, attributes, _) = fetch_plugin::<BaseAssetV1, Attributes>(
+        nft_asset,
+        PluginType::Attributes,
+    ).map_err(|_| ContentRegistryError::RentalNotFound)?;
+    Ok((authority, attributes))
+}
+
+/// Parse the expires_at attribute of a rental NFT
+pub(crate) fn rental_expires_at(attributes: &Attributes) -> Result<i64> {
+    let expires_at = attributes
+        .attribute_list
+        .iter()
+        .find(|a| a.key == "expires_at")
+        .ok_or(ContentRegistryError::RentalNotFound)?
+        .value
+        .parse::<i64>()
+        .map_err(|_| ContentRegistryError::RentalNotFound)?;
+    Ok(expires_at)
+}
+
+/// Attribute marking a rental NFT that holds a max_concurrent_rentals slot
+const RENTAL_SLOT_ATTRIBUTE: &str = "holds_slot";
+
+/// Marker attribute for a new rental that occupied a slot
+pub(crate) fn rental_slot_attribute() -> Attribute {
+    Attribute {
+        key: RENTAL_SLOT_ATTRIBUTE.to_string(),
+        value: "true".to_string(),
+    }
+}
+
+/// Whether a rental NFT holds a slot (freed when it is returned or reaped)
+pub(crate) fn rental_holds_slot(attributes: &Attributes) -> bool {
+    attributes.attribute_list.iter().any(|a| a.key == RENTAL_SLOT_ATTRIBUTE && a.value == "true")
+}
+
+/// Whether a rental NFT's slot was freed by a later rental after it expired
+/// (marker kept as "false" so the asset can't free it twice)
+fn rental_slot_released(attributes: &Attributes) -> bool {
+    attributes.attribute_list.iter().any(|a| a.key == RENTAL_SLOT_ATTRIBUTE && a.value != "true")
+}
+
+/// Distribute a rent fee using the primary sale split
+/// holder_pool is Some only when the item has existing holders - otherwise
+/// the holder share goes to the creator
+/// Returns the holder reward deposited (caller updates reward_per_share)
+pub(crate) fn distribute_rent_fee<'info>(
+    renter: &AccountInfo<'info>,
+    creator: &AccountInfo<'info>,
+    platform: &AccountInfo<'info>,
+    treasury: &AccountInfo<'info>,
+    holder_pool: Option<&AccountInfo<'info>>,
+    system_program: &AccountInfo<'info>,
+    rent_fee: u64,
+) -> Result<u64> {
+    if rent_fee == 0 {
+        return Ok(0);
+    }
+
+    let (creator_amount, platform_amount, ecosystem_amount, holder_reward_amount) =
+        EcosystemConfig::calculate_primary_split(rent_fee);
+
+    let (final_creator_amount, pool_amount) = match holder_pool {
+        Some(_) => (creator_amount, holder_reward_amount),
+        None => (creator_amount + holder_reward_amount, 0),
+    };
+
+    let pay = |to: &AccountInfo<'info>, amount: u64| -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: renter.clone(),
+                    to: to.clone(),
+                },
+            ),
+            amount,
+        )
+    };
+
+    pay(creator, final_creator_amount)?;
+    if let Some(pool) = holder_pool {
+        pay(pool, pool_amount)?;
+    }
+    pay(platform, platform_amount)?;
+    pay(treasury, ecosystem_amount)?;
+
+    Ok(pool_amount)
+}
+
+/// Rent-to-own: add a paid rent fee to the rental holder's credit (no-op while disabled)
+/// Gifted rentals credit the recipient, who holds the rental NFT
+pub(crate) fn record_rental_credit(
+    mint_config: &MintConfig,
+    rental_credit: Option<&mut RentalCredit>,
+    holder: Pubkey,
+    item: Pubkey,
+    fee: u64,
+    now: i64,
+) -> Result<()> {
+    if !mint_config.rent_to_own_enabled() {
+        return Ok(());
+    }
+    let credit = rental_credit.ok_or(ContentRegistryError::RentalCreditRequired)?;
+    credit.record(holder, item, fee, now);
+    Ok(())
+}
+
+/// Verify a collection's update authority is the given mint_config PDA
+pub(crate) fn verify_collection_authority(collection_asset: &AccountInfo, mint_config: &Pubkey) -> Result<()> {
+    let collection_data = collection_asset.try_borrow_data()?;
+    let collection = BaseCollectionV1::from_bytes(&collection_data)
+        .map_err(|_| ContentRegistryError::InvalidCollection)?;
+    require!(
+        collection.update_authority == *mint_config,
+        ContentRegistryError::InvalidCollection
+    );
+    Ok(())
+}
+
+/// Verify `nft_asset` is an NFT of `collection` and return its owner
+fn collection_asset_owner(nft_asset: &AccountInfo, collection: &Pubkey) -> Result<Pubkey> {
+    let asset_data = nft_asset.try_borrow_data()?;
+    let asset = BaseAssetV1::from_bytes(&asset_data)
+        .map_err(|_| ContentRegistryError::InvalidNftAsset)?;
+    require!(
+        asset.update_authority == UpdateAuthority::Collection(*collection),
+        ContentRegistryError::InvalidCollection
+    );
+    Ok(asset.owner)
+}
+
+/// Verify `nft_asset` is a rental NFT of `collection` held by `renter`
+/// Returns its expires_at and whether it holds a rental slot
+pub(crate) fn verify_rental_asset(nft_asset: &AccountInfo, collection: &Pubkey, renter: &Pubkey) -> Result<(i64, bool)> {
+    require!(
+        collection_asset_owner(nft_asset, collection)? == *renter,
+        ContentRegistryError::NotNftOwner
+    );
+
+    let (_, attributes) = fetch_rental_attributes(nft_asset)?;
+    Ok((rental_expires_at(&attributes)?, rental_holds_slot(&attributes)))
+}
+
+/// Rewrite the Attributes plugin of a rental NFT
+/// The collection update authority (mint_config PDA) is the plugin authority
+#[allow(clippy::too_many_arguments)]
+fn update_rental_attributes<'info>(
+    mpl_core_program: &AccountInfo<'info>,
+    nft_asset: &AccountInfo<'info>,
+    collection_asset: &AccountInfo<'info>,
+    mint_config: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    attribute_list: Vec<Attribute>,
+) -> Result<()> {
+    UpdatePluginV1CpiBuilder::new(mpl_core_program)
+        .asset(nft_asset)
+        .collection(Some(collection_asset))
+        .payer(payer)
+        .authority(Some(mint_config))
+        .system_program(system_program)
+        .plugin(Plugin::Attributes(Attributes { attribute_list }))
+        .invoke_signed(signer_seeds)?;
+    Ok(())
+}
+
+/// Lazy expiry: free the slots still held by expired rentals before a new rental
+/// Each asset must be an expired, slot-holding rental NFT of the collection
+/// Its marker is set to "false" rather than burned, so mint_config stays read-only
+/// and the asset rent still goes back to its holder when it is reaped
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn release_expired_rental_slots<'info>(
+    expired_rentals: &[AccountInfo<'info>],
+    rent_config: &mut RentConfig,
+    mpl_core_program: &AccountInfo<'info>,
+    collection_asset: &AccountInfo<'info>,
+    mint_config: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    now: i64,
+) -> Result<()> {
+    require!(expired_rentals.len() <= MAX_REAP_BATCH, ContentRegistryError::ReapBatchTooLarge);
+
+    for nft_asset in expired_rentals {
+        require!(nft_asset.owner == &MPL_CORE_ID, ContentRegistryError::InvalidNftAsset);
+        collection_asset_owner(nft_asset, &collection_asset.key())?;
+
+        let (_, attributes) = fetch_rental_attributes(nft_asset)?;
+        require!(rental_expires_at(&attributes)? < now, ContentRegistryError::RentalNotExpired);
+        require!(rental_holds_slot(&attributes), ContentRegistryError::RentalSlotNotHeld);
+
+        let attribute_list = attributes
+            .attribute_list
+            .into_iter()
+            .map(|a| match a.key.as_str() {
+                RENTAL_SLOT_ATTRIBUTE => Attribute { key: a.key, value: "false".to_string() },
+                _ => a,
+            })
+            .collect();
+        update_rental_attributes(
+            mpl_core_program,
+            nft_asset,
+            collection_asset,
+            mint_config,
+            payer,
+            system_program,
+            signer_seeds,
+            attribute_list,
+        )?;
+        rent_config.release_rental_slot();
+    }
+
+    Ok(())
+}
+
+/// Burn a rental NFT through its PermanentBurnDelegate (mint_config PDA)
+/// mint_config signs as both delegate and payer, so the asset's lamports
+/// land in mint_config - returns the amount for the caller to forward
+pub(crate) fn burn_rental<'info>(
+    mpl_core_program: &AccountInfo<'info>,
+    nft_asset: &AccountInfo<'info>,
+    collection_asset: &AccountInfo<'info>,
+    mint_config: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<u64> {
+    let lamports_before = mint_config.lamports();
+    BurnV1CpiBuilder::new(mpl_core_program)
+        .asset(nft_asset)
+        .collection(Some(collection_asset))
+        .payer(mint_config)
+        .authority(Some(mint_config))
+        .system_program(Some(system_program))
+        .invoke_signed(signer_seeds)?;
+    Ok(mint_config.lamports().saturating_sub(lamports_before))
+}
+
+/// Push a rental NFT's expiry forward
+/// Verifies the renter holds a program-updatable rental NFT of the collection,
+/// rewrites expires_at/duration and returns the new expiry
+/// Extensions stack on unexpired time: new expiry = max(expires_at, now) + period
+/// A rental whose slot was freed after expiry has to occupy a slot again
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn extend_rental_expiry<'info>(
+    mpl_core_program: &AccountInfo<'info>,
+    nft_asset: &AccountInfo<'info>,
+    collection_asset: &AccountInfo<'info>,
+    mint_config: &AccountInfo<'info>,
+    renter: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    rent_config: &mut RentConfig,
+    duration_seconds: i64,
+    now: i64,
+) -> Result<i64> {
+    require!(
+        crate::verify_core_nft_ownership(nft_asset, &renter.key(), &collection_asset.key())?,
+        ContentRegistryError::NotNftOwner
+    );
+
+    let (authority, attributes) = fetch_rental_attributes(nft_asset)?;
+    // Rentals created before extensions existed have immutable attributes
+    require!(
+        authority == PluginAuthority::UpdateAuthority,
+        ContentRegistryError::RentalNotExtendable
+    );
+
+    let expires_at = rental_expires_at(&attributes)?.max(now) + duration_seconds;
+    let holds_slot = if rental_slot_released(&attributes) {
+        rent_config.occupy_rental_slot()?
+    } else {
+        rental_holds_slot(&attributes)
+    };
+
+    // Record the purchased duration (replaces the legacy "tier" attribute)
+    let mut attribute_list: Vec<Attribute> = attributes
+        .attribute_list
+        .into_iter()
+        .filter(|a| a.key != "tier" && a.key != "duration")
+        .map(|a| match a.key.as_str() {
+            "expires_at" => Attribute { key: a.key, value: expires_at.to_string() },
+            RENTAL_SLOT_ATTRIBUTE => Attribute { key: a.key, value: holds_slot.to_string() },
+            _ => a,
+        })
+        .collect();
+    attribute_list.push(Attribute {
+        key: "duration".to_string(),
+        value: duration_seconds.to_string(),
+    });
+
+    update_rental_attributes(
+        mpl_core_program,
+        nft_asset,
+        collection_asset,
+        mint_config,
+        renter,
+        system_program,
+        signer_seeds,
+        attribute_list,
+    )?;
+
+    Ok(expires_at)
+}
+
 /// Configure rental settings for content
 /// Only the content creator can configure rent
 #[derive(Accounts)]
@@ -43,6 +361,35 @@ pub struct UpdateRentConfig<'info> {
     pub creator: Signer<'info>,
 }
 
+/// Enable or disable rent-to-own for a content or bundle (creator only)
+#[derive(Accounts)]
+pub struct ConfigureRentToOwn<'info> {
+    #[account(
+        mut,
+        seeds = [MINT_CONFIG_SEED, mint_config.item.as_ref()],
+        bump,
+        has_one = creator @ ContentRegistryError::Unauthorized
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn handle_configure_rent_to_own(ctx: Context<ConfigureRentToOwn>, rent_to_own_bps: Option<u16>) -> Result<()> {
+    require!(
+        rent_to_own_bps.is_none_or(|bps| (1..=10000).contains(&bps)),
+        ContentRegistryError::InvalidRentToOwnBps
+    );
+
+    let mint_config = &mut ctx.accounts.mint_config;
+    mint_config.rent_to_own_bps = rent_to_own_bps;
+    mint_config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Rent-to-own: {:?} bps of rental spend credited", rent_to_own_bps);
+
+    Ok(())
+}
+
 /// Rent content with SOL payment
 /// Creates a frozen (non-transferable) NFT with expiry in Attributes plugin
 #[derive(Accounts)]
@@ -110,10 +457,23 @@ pub struct RentContentSol<'info> {
     #[account(mut)]
     pub treasury: AccountInfo<'info>,
 
+    /// Rent-to-own spend record of the rental holder (required while mint_config.rent_to_own_bps is set)
+    #[account(
+        init_if_needed,
+        payer = renter,
+        space = 8 + RentalCredit::INIT_SPACE,
+        seeds = [RENTAL_CREDIT_SEED, content.key().as_ref(), recipient.key().as_ref()],
+        bump
+    )]
+    pub rental_credit: Option<Box<Account<'info, RentalCredit>>>,
+
     /// Renter paying for rental
     #[account(mut)]
     pub renter: Signer<'info>,
 
+    /// CHECK: Receives the rental NFT - the renter for a regular rental, another wallet for a gift
+    pub recipient: AccountInfo<'info>,
+
     /// CHECK: Metaplex Core program
     #[account(address = MPL_CORE_ID)]
     pub mpl_core_program: AccountInfo<'info>,
@@ -128,3 +488,362 @@ pub struct CheckRentExpiry<'info> {
     /// CHECK: The NFT asset to check - expiry stored in Attributes plugin
     pub nft_asset: AccountInfo<'info>,
 }
+
+/// Extend an existing content rental instead of minting a new rental NFT
+/// Pays for another rent option and pushes expires_at forward on the same asset
+#[derive(Accounts)]
+pub struct ExtendRental<'info> {
+    #[account(
+        seeds = [ECOSYSTEM_CONFIG_SEED],
+        bump
+    )]
+    pub ecosystem_config: Box<Account<'info, EcosystemConfig>>,
+
+    pub content: Box<Account<'info, ContentEntry>>,
+
+    #[account(
+        mut,
+        seeds = [RENT_CONFIG_SEED, content.key().as_ref()],
+        bump,
+        constraint = rent_config.is_active @ ContentRegistryError::RentingNotActive
+    )]
+    pub rent_config: Box<Account<'info, RentConfig>>,
+
+    /// MintConfig PDA - collection update authority (signs the attribute update)
+    #[account(
+        seeds = [MINT_CONFIG_SEED, content.key().as_ref()],
+        bump
+    )]
+    pub mint_config: Box<Account<'info, MintConfig>>,
+
+    /// CHECK: Verified via content.collection_asset
+    #[account(
+        mut,
+        constraint = collection_asset.key() == content.collection_asset @ ContentRegistryError::InvalidCollection
+    )]
+    pub collection_asset: AccountInfo<'info>,
+
+    /// Content reward pool (created by the original rental)
+    #[account(
+        mut,
+        seeds = [CONTENT_REWARD_POOL_SEED, content.key().as_ref()],
+        bump
+    )]
+    pub content_reward_pool: Box<Account<'info, ContentRewardPool>>,
+
+    /// CHECK: Rental NFT owned by renter - verified in handler
+    #[account(mut)]
+    pub nft_asset: AccountInfo<'info>,
+
+    /// CHECK: Verified via content.creator
+    #[account(mut, constraint = content.creator == creator.key() @ ContentRegistryError::Unauthorized)]
+    pub creator: AccountInfo<'info>,
+
+    /// CHECK: Platform wallet
+    #[account(mut)]
+    pub platform: Option<AccountInfo<'info>>,
+
+    /// CHECK: Verified via ecosystem_config.treasury
+    #[account(mut, constraint = ecosystem_config.treasury == treasury.key())]
+    pub treasury: AccountInfo<'info>,
+
+    /// Rent-to-own spend record (required while mint_config.rent_to_own_bps is set)
+    /// Extensions are paid by the rental holder, so the credit is theirs
+    #[account(
+        init_if_needed,
+        payer = renter,
+        space = 8 + RentalCredit::INIT_SPACE,
+        seeds = [RENTAL_CREDIT_SEED, content.key().as_ref(), renter.key().as_ref()],
+        bump
+    )]
+    pub rental_credit: Option<Box<Account<'info, RentalCredit>>>,
+
+    #[account(mut)]
+    pub renter: Signer<'info>,
+
+    /// CHECK: Metaplex Core program
+    #[account(address = MPL_CORE_ID)]
+    pub mpl_core_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_extend_rental(ctx: Context<ExtendRental>, option_index: u8) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    require!(!ctx.accounts.ecosystem_config.is_paused, ContentRegistryError::EcosystemPaused);
+
+    let option = ctx.accounts.rent_config.get_option(option_index)?;
+    let content_key = ctx.accounts.content.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        MINT_CONFIG_SEED,
+        content_key.as_ref(),
+        &[ctx.bumps.mint_config],
+    ]];
+
+    let expires_at = extend_rental_expiry(
+        &ctx.accounts.mpl_core_program,
+        &ctx.accounts.nft_asset,
+        &ctx.accounts.collection_asset,
+        &ctx.accounts.mint_config.to_account_info(),
+        &ctx.accounts.renter.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        signer_seeds,
+        &mut ctx.accounts.rent_config,
+        option.duration_seconds,
+        timestamp,
+    )?;
+
+    // Same primary split as a new rental
+    let rent_fee = option.fee;
+    let pool_info = ctx.accounts.content_reward_pool.to_account_info();
+    let holder_pool = (ctx.accounts.content_reward_pool.total_nfts > 0).then_some(&pool_info);
+    let platform_info = ctx.accounts.platform.as_ref()
+        .map(|p| p.to_account_info())
+        .unwrap_or_else(|| ctx.accounts.treasury.to_account_info());
+    let holder_reward = distribute_rent_fee(
+        &ctx.accounts.renter.to_account_info(),
+        &ctx.accounts.creator,
+        &platform_info,
+        &ctx.accounts.treasury,
+        holder_pool,
+        &ctx.accounts.system_program.to_account_info(),
+        rent_fee,
+    )?;
+    if holder_reward > 0 {
+        ctx.accounts.content_reward_pool.add_rewards(holder_reward);
+    }
+
+    record_rental_credit(
+        &ctx.accounts.mint_config,
+        ctx.accounts.rental_credit.as_deref_mut().map(|c| &mut **c),
+        ctx.accounts.renter.key(),
+        content_key,
+        rent_fee,
+        timestamp,
+    )?;
+
+    let rent_config = &mut ctx.accounts.rent_config;
+    rent_config.total_fees_collected += rent_fee;
+    rent_config.updated_at = timestamp;
+
+    emit!(ContentRentedEvent {
+        content: content_key,
+        renter: ctx.accounts.renter.key(),
+        creator: ctx.accounts.creator.key(),
+        nft_asset: ctx.accounts.nft_asset.key(),
+        fee_paid: rent_fee,
+        rented_at: timestamp,
+        expires_at,
+    });
+
+    msg!("Rental extended: duration={}, fee={}, expires_at={}", option.duration_seconds, rent_fee, expires_at);
+
+    Ok(())
+}
+
+/// Burn expired rental NFTs of one collection (permissionless crank)
+/// Pass pairs of (nft_asset, renter) as remaining_accounts:
+/// [nft_asset_1, renter_1, nft_asset_2, renter_2, ...]
+/// Reaping a rental that holds a slot frees it (requires rent_config)
+#[derive(Accounts)]
+pub struct ReapExpiredRentals<'info> {
+    /// MintConfig PDA - PermanentBurnDelegate of the rental NFTs
+    /// Receives the reclaimed asset rent during the burn, then forwards it
+    #[account(
+        mut,
+        seeds = [MINT_CONFIG_SEED, mint_config.item.as_ref()],
+        bump
+    )]
+    pub mint_config: Box<Account<'info, MintConfig>>,
+
+    /// CHECK: Collection whose update authority is mint_config - verified in handler
+    #[account(mut, owner = MPL_CORE_ID)]
+    pub collection_asset: AccountInfo<'info>,
+
+    /// Anyone can reap - receives RENTAL_REAP_REWARD_BPS of reclaimed rent
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// CHECK: Metaplex Core program
+    #[account(address = MPL_CORE_ID)]
+    pub mpl_core_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Rent config of the item - required when a reaped rental holds a slot
+    #[account(
+        mut,
+        seeds = [RENT_CONFIG_SEED, mint_config.item.as_ref()],
+        bump
+    )]
+    pub rent_config: Option<Box<Account<'info, RentConfig>>>,
+}
+
+pub fn handle_reap_expired_rentals<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReapExpiredRentals<'info>>,
+) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let mint_config_info = ctx.accounts.mint_config.to_account_info();
+    let collection_key = ctx.accounts.collection_asset.key();
+    verify_collection_authority(&ctx.accounts.collection_asset, &mint_config_info.key())?;
+
+    let remaining = ctx.remaining_accounts;
+    require!(remaining.len().is_multiple_of(2), ContentRegistryError::InvalidAccountPairs);
+    require!(remaining.len() / 2 <= MAX_REAP_BATCH, ContentRegistryError::ReapBatchTooLarge);
+
+    let item_key = ctx.accounts.mint_config.item;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        MINT_CONFIG_SEED,
+        item_key.as_ref(),
+        &[ctx.bumps.mint_config],
+    ]];
+
+    let mut reaped: u32 = 0;
+    let mut total_caller_reward: u64 = 0;
+
+    for pair in remaining.chunks(2) {
+        let nft_asset = &pair[0];
+        let renter = &pair[1];
+
+        // Skip assets already burned (e.g. by a concurrent reaper)
+        if nft_asset.owner != &MPL_CORE_ID || nft_asset.data_len() <= 1 {
+            continue;
+        }
+
+        // Reclaimed rent goes back to the renter holding the asset
+        let (expires_at, holds_slot) = verify_rental_asset(nft_asset, &collection_key, &renter.key())?;
+        require!(expires_at < timestamp, ContentRegistryError::RentalNotExpired);
+        if holds_slot {
+            ctx.accounts.rent_config.as_mut()
+                .ok_or(ContentRegistryError::RentConfigRequired)?
+                .release_rental_slot();
+        }
+
+        let reclaimed = burn_rental(
+            &ctx.accounts.mpl_core_program,
+            nft_asset,
+            &ctx.accounts.collection_asset,
+            &mint_config_info,
+            &ctx.accounts.system_program.to_account_info(),
+            signer_seeds,
+        )?;
+
+        let caller_reward = reclaimed * RENTAL_REAP_REWARD_BPS / 10000;
+        **mint_config_info.try_borrow_mut_lamports()? -= reclaimed;
+        **renter.try_borrow_mut_lamports()? += reclaimed - caller_reward;
+        **ctx.accounts.caller.to_account_info().try_borrow_mut_lamports()? += caller_reward;
+
+        reaped += 1;
+        total_caller_reward += caller_reward;
+    }
+
+    msg!("Reaped {} expired rentals, caller reward {} lamports", reaped, total_caller_reward);
+
+    Ok(())
+}
+
+/// Return a rental early (content or bundle)
+/// Burns the rental NFT, frees its concurrency slot and refunds the asset rent
+/// The rent fee itself is not refunded
+#[derive(Accounts)]
+pub struct ReturnRental<'info> {
+    /// MintConfig PDA - PermanentBurnDelegate of the rental NFT
+    #[account(
+        mut,
+        seeds = [MINT_CONFIG_SEED, mint_config.item.as_ref()],
+        bump
+    )]
+    pub mint_config: Box<Account<'info, MintConfig>>,
+
+    #[account(
+        mut,
+        seeds = [RENT_CONFIG_SEED, mint_config.item.as_ref()],
+        bump
+    )]
+    pub rent_config: Box<Account<'info, RentConfig>>,
+
+    /// CHECK: Collection whose update authority is mint_config - verified in handler
+    #[account(mut, owner = MPL_CORE_ID)]
+    pub collection_asset: AccountInfo<'info>,
+
+    /// CHECK: Rental NFT owned by renter - verified in handler
+    #[account(mut)]
+    pub nft_asset: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub renter: Signer<'info>,
+
+    /// CHECK: Metaplex Core program
+    #[account(address = MPL_CORE_ID)]
+    pub mpl_core_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_return_rental(ctx: Context<ReturnRental>) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let mint_config_info = ctx.accounts.mint_config.to_account_info();
+    verify_collection_authority(&ctx.accounts.collection_asset, &mint_config_info.key())?;
+    let (_, holds_slot) = verify_rental_asset(
+        &ctx.accounts.nft_asset,
+        &ctx.accounts.collection_asset.key(),
+        &ctx.accounts.renter.key(),
+    )?;
+
+    let item_key = ctx.accounts.mint_config.item;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        MINT_CONFIG_SEED,
+        item_key.as_ref(),
+        &[ctx.bumps.mint_config],
+    ]];
+
+    let reclaimed = burn_rental(
+        &ctx.accounts.mpl_core_program,
+        &ctx.accounts.nft_asset,
+        &ctx.accounts.collection_asset,
+        &mint_config_info,
+        &ctx.accounts.system_program.to_account_info(),
+        signer_seeds,
+    )?;
+    **mint_config_info.try_borrow_mut_lamports()? -= reclaimed;
+    **ctx.accounts.renter.to_account_info().try_borrow_mut_lamports()? += reclaimed;
+
+    let rent_config = &mut ctx.accounts.rent_config;
+    if holds_slot {
+        rent_config.release_rental_slot();
+    }
+    rent_config.updated_at = timestamp;
+
+    msg!("Rental returned: {}, {} active rentals", ctx.accounts.nft_asset.key(), rent_config.active_rental_count);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attributes(slot_marker: Option<&str>) -> Attributes {
+        let mut attribute_list = vec![Attribute { key: "expires_at".to_string(), value: "100".to_string() }];
+        if let Some(value) = slot_marker {
+            attribute_list.push(Attribute { key: RENTAL_SLOT_ATTRIBUTE.to_string(), value: value.to_string() });
+        }
+        Attributes { attribute_list }
+    }
+
+    #[test]
+    fn released_slot_marker_is_not_freed_twice() {
+        // Rentals created without a cap never held a slot
+        assert!(!rental_holds_slot(&attributes(None)));
+        assert!(!rental_slot_released(&attributes(None)));
+
+        let held = attributes(Some(&rental_slot_attribute().value));
+        assert!(rental_holds_slot(&held));
+        assert!(!rental_slot_released(&held));
+
+        // Freed by a later rental: reap/return skip it, extending re-occupies a slot
+        let released = attributes(Some("false"));
+        assert!(!rental_holds_slot(&released));
+        assert!(rental_slot_released(&released));
+    }
+}
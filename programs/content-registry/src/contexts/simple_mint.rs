@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
 use mpl_core::instructions::CreateV2CpiBuilder;
 use mpl_core::types::{DataState, Plugin, PluginAuthority, PluginAuthorityPair, PermanentBurnDelegate};
 
@@ -9,6 +10,16 @@ use crate::contexts::subscription_mint::{
     maybe_distribute_patron_pool,
     maybe_distribute_ecosystem_pools,
 };
+use crate::contexts::content_edition::{ContentEditionPools, EditionNftAccounts};
+use crate::contexts::usdc_payment::{
+    require_usdc_account,
+    require_usdc_vault,
+    transfer_usdc,
+    distribute_usdc_to_content_pools,
+};
+use crate::contexts::mint_phase::apply_mint_phase;
+use crate::contexts::gift::{emit_gift, validate_gift_message};
+use crate::events::GiftType;
 use crate::MPL_CORE_ID;
 
 /// Seed for simple mint NFT assets
@@ -20,6 +31,8 @@ pub const SIMPLE_NFT_SEED: &[u8] = b"simple_nft";
 
 /// Simple mint NFT with slot hash randomness and full subscription pool integration
 /// Single transaction - no VRF dependency, immediate mint, tracks all reward pools
+/// Slot hashes are predictable, so a random rarity needs MintConfig.instant_rarity;
+/// otherwise such content mints through request_mint / reveal_mint
 #[derive(Accounts)]
 pub struct SimpleMint<'info> {
     // =========================================================================
@@ -38,6 +51,7 @@ pub struct SimpleMint<'info> {
 
     /// MintConfig PDA - authority for collection operations (signs NFT creation)
     #[account(
+        mut,
         seeds = [MINT_CONFIG_SEED, content.key().as_ref()],
         bump
     )]
@@ -170,6 +184,54 @@ pub struct SimpleMint<'info> {
     // NOTE: platform_treasury removed - use platform (variable host operator) for 5% fee
     // NOTE: ecosystem_treasury removed - use treasury (ecosystem_config.treasury) for 3% fee
 
+    // =========================================================================
+    // USDC payment accounts (required when mint_config.currency == Usdc)
+    // =========================================================================
+
+    /// Payer's USDC token account
+    #[account(mut)]
+    pub payer_usdc: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Creator's USDC token account (80%)
+    #[account(mut)]
+    pub creator_usdc: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Platform's USDC token account (5%) - owned by platform, or treasury if no platform
+    #[account(mut)]
+    pub platform_usdc: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Ecosystem treasury's USDC token account (3%)
+    #[account(mut)]
+    pub treasury_usdc: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Content reward pool's USDC ATA (12% holder rewards)
+    #[account(mut)]
+    pub content_reward_pool_usdc: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    // =========================================================================
+    // Mint phase accounts (required during the allowlist phase or with max_per_wallet)
+    // =========================================================================
+
+    /// Per-(wallet, content) mint counter
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + WalletMintRecord::INIT_SPACE,
+        seeds = [WALLET_MINT_RECORD_SEED, content.key().as_ref(), payer.key().as_ref()],
+        bump
+    )]
+    pub wallet_mint_record: Option<Box<Account<'info, WalletMintRecord>>>,
+
+    /// Rent-to-own spend record - credited toward the price and closed on purchase
+    #[account(
+        mut,
+        seeds = [RENTAL_CREDIT_SEED, content.key().as_ref(), payer.key().as_ref()],
+        bump
+    )]
+    pub rental_credit: Option<Box<Account<'info, RentalCredit>>>,
+
     // =========================================================================
     // Payer and system accounts
     // =========================================================================
@@ -177,6 +239,9 @@ pub struct SimpleMint<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    /// CHECK: Receives the NFT - the payer for a regular purchase, another wallet for a gift
+    pub recipient: AccountInfo<'info>,
+
     /// CHECK: Slot hashes sysvar for randomness
     #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::ID)]
     pub slot_hashes: AccountInfo<'info>,
@@ -189,12 +254,31 @@ pub struct SimpleMint<'info> {
 }
 
 impl<'info> SimpleMint<'info> {
-    pub fn handler(ctx: Context<SimpleMint>, content_name: String) -> Result<()> {
+    /// Rent-to-own: discount the price by prior rental spend and close the credit record
+    fn consume_rental_credit(&self, price: u64) -> Result<u64> {
+        match &self.rental_credit {
+            Some(credit) if self.mint_config.rent_to_own_enabled() => {
+                let discounted = self.mint_config.rent_to_own_price(price, credit.total_spent);
+                credit.close(self.payer.to_account_info())?;
+                Ok(discounted)
+            }
+            _ => Ok(price),
+        }
+    }
+
+    pub fn handler(
+        ctx: Context<SimpleMint>,
+        content_name: String,
+        allowlist_proof: Option<Vec<[u8; 32]>>,
+        max_price: u64,
+        gift_message_cid: Option<String>,
+    ) -> Result<()> {
         // Validate content name (1-32 chars for Metaplex Core)
         require!(
             !content_name.is_empty() && content_name.len() <= 32,
             ContentRegistryError::InvalidContentName
         );
+        validate_gift_message(&gift_message_cid)?;
 
         let clock = Clock::get()?;
         let timestamp = clock.unix_timestamp;
@@ -213,16 +297,42 @@ impl<'info> SimpleMint<'info> {
         require!(!ctx.accounts.ecosystem_config.is_paused, ContentRegistryError::EcosystemPaused);
 
         if let Some(max_supply) = ctx.accounts.mint_config.max_supply {
-            require!(ctx.accounts.content.minted_count < max_supply, ContentRegistryError::MaxSupplyReached);
+            // Pending commit-reveal requests reserve supply
+            require!(
+                ctx.accounts.content.minted_count + ctx.accounts.content.pending_count < max_supply,
+                ContentRegistryError::MaxSupplyReached
+            );
         }
 
-        let mint_price = ctx.accounts.mint_config.price;
+        // Allowlist phase overrides the price and enforces the allowlist cap
+        let mint_price = apply_mint_phase(
+            &ctx.accounts.mint_config,
+            ctx.accounts.wallet_mint_record.as_deref_mut().map(|r| &mut **r),
+            payer_key,
+            content_key,
+            allowlist_proof,
+            ctx.accounts.content.minted_count + ctx.accounts.content.pending_count,
+            timestamp,
+        )?;
+        let mint_price = ctx.accounts.consume_rental_credit(mint_price)?;
+        MintConfig::require_max_price(mint_price, max_price)?;
+
+        // Per-wallet cap across all phases
+        ctx.accounts.content.record_wallet_mint(
+            ctx.accounts.wallet_mint_record.as_deref_mut().map(|r| &mut **r),
+            content_key,
+            payer_key,
+            ctx.accounts.mint_config.max_per_wallet,
+        )?;
         let had_existing_nfts = ctx.accounts.content_reward_pool.total_weight > 0;
 
         // =====================================================================
         // STEP 1: Generate randomness from slot hashes
         // =====================================================================
 
+        // Predictable, so only used when rarity is fixed or the creator opted in
+        ctx.accounts.mint_config.require_instant_rarity()?;
+
         let slot_hashes_data = ctx.accounts.slot_hashes.try_borrow_data()?;
         let randomness_seed = solana_sha256_hasher::hashv(&[
             &slot_hashes_data[..std::cmp::min(64, slot_hashes_data.len())],
@@ -232,73 +342,35 @@ impl<'info> SimpleMint<'info> {
             &clock.slot.to_le_bytes(),
         ]);
 
-        let (rarity, weight) = determine_rarity_from_bytes(randomness_seed.to_bytes());
+        let (rarity, weight) = determine_rarity_from_bytes(randomness_seed.to_bytes(), &mut ctx.accounts.mint_config)?;
 
         // =====================================================================
-        // STEP 2: Initialize pool accounts if needed (lazy init)
-        // =====================================================================
-
-        // Initialize content reward pool if first mint
-        if ctx.accounts.content_reward_pool.content == Pubkey::default() {
-            ctx.accounts.content_reward_pool.content = content_key;
-            ctx.accounts.content_reward_pool.total_deposited = 0;
-            ctx.accounts.content_reward_pool.total_claimed = 0;
-            ctx.accounts.content_reward_pool.created_at = timestamp;
-        }
-
-        // Initialize creator patron pool if first mint for this creator
-        if ctx.accounts.creator_patron_pool.creator == Pubkey::default() {
-            ctx.accounts.creator_patron_pool.creator = creator_key;
-            ctx.accounts.creator_patron_pool.reward_per_share = 0;
-            ctx.accounts.creator_patron_pool.total_weight = 0;
-            ctx.accounts.creator_patron_pool.total_deposited = 0;
-            ctx.accounts.creator_patron_pool.total_claimed = 0;
-            ctx.accounts.creator_patron_pool.last_distribution_at = timestamp;
-            ctx.accounts.creator_patron_pool.epoch_duration = DEFAULT_EPOCH_DURATION;
-            ctx.accounts.creator_patron_pool.created_at = timestamp;
-        }
-
-        // Initialize creator weight if first mint for this creator
-        if ctx.accounts.creator_weight.creator == Pubkey::default() {
-            ctx.accounts.creator_weight.creator = creator_key;
-            ctx.accounts.creator_weight.total_weight = 0;
-            ctx.accounts.creator_weight.reward_debt = 0;
-            ctx.accounts.creator_weight.total_claimed = 0;
-            ctx.accounts.creator_weight.created_at = timestamp;
-        }
-
-        // =====================================================================
-        // STEP 2.5: Trigger epoch distribution if needed (Option B - call first)
+        // STEP 2: Initialize pools (lazy) and trigger epoch distribution if needed
         // =====================================================================
 
         // Get platform treasury (use treasury as fallback if platform not provided)
         let platform_treasury_info = ctx.accounts.platform.as_ref()
             .map(|p| p.to_account_info())
             .unwrap_or_else(|| ctx.accounts.treasury.to_account_info());
-
-        // Patron pool distribution (drains creator_patron_treasury at epoch end)
-        let creator_key = ctx.accounts.creator.key();
-        maybe_distribute_patron_pool(
-            &mut ctx.accounts.creator_patron_pool,
-            &ctx.accounts.creator_patron_treasury,
+        let system_program_info = ctx.accounts.system_program.to_account_info();
+
+        let mut pools = ContentEditionPools {
+            content_reward_pool: &mut ctx.accounts.content_reward_pool,
+            creator_patron_pool: &mut ctx.accounts.creator_patron_pool,
+            global_holder_pool: &mut ctx.accounts.global_holder_pool,
+            creator_dist_pool: &mut ctx.accounts.creator_dist_pool,
+            creator_weight: &mut ctx.accounts.creator_weight,
+            ecosystem_epoch_state: &mut ctx.accounts.ecosystem_epoch_state,
+            creator_patron_treasury: &ctx.accounts.creator_patron_treasury,
+            ecosystem_streaming_treasury: &ctx.accounts.ecosystem_streaming_treasury,
+        };
+        pools.prepare(
+            content_key,
             &ctx.accounts.creator,
             &platform_treasury_info,
             &ctx.accounts.treasury,
+            &system_program_info,
             timestamp,
-            &ctx.accounts.system_program.to_account_info(),
-            &creator_key,
-        )?;
-
-        // Ecosystem pools distribution (drains ecosystem_streaming_treasury at epoch end)
-        maybe_distribute_ecosystem_pools(
-            &mut ctx.accounts.global_holder_pool,
-            &mut ctx.accounts.creator_dist_pool,
-            &mut ctx.accounts.ecosystem_epoch_state,
-            &ctx.accounts.ecosystem_streaming_treasury,
-            &platform_treasury_info,
-            &ctx.accounts.treasury,
-            timestamp,
-            &ctx.accounts.system_program.to_account_info(),
         )?;
 
         // =====================================================================
@@ -306,10 +378,6 @@ impl<'info> SimpleMint<'info> {
         // =====================================================================
 
         let edition = ctx.accounts.content.minted_count + 1;
-        // NFT name format: "<ContentName> (<R> #XXXXXX)" where R is single-letter rarity code
-        let nft_name = format!("{} ({} #{:06})", content_name, rarity.code(), edition);
-        // NFT metadata served from API (not stored on-chain)
-        let nft_uri = format!("https://handcraft.art/api/content/{}/metadata", content_key);
 
         let mint_config_seeds = &[
             MINT_CONFIG_SEED,
@@ -325,27 +393,16 @@ impl<'info> SimpleMint<'info> {
             &[ctx.bumps.nft_asset],
         ];
 
-        // Create PermanentBurnDelegate plugin with mint_config PDA as authority
-        // This allows our program to burn NFTs via burn_nft_with_subscription instruction
-        let burn_delegate_plugin = PluginAuthorityPair {
-            plugin: Plugin::PermanentBurnDelegate(PermanentBurnDelegate {}),
-            authority: Some(PluginAuthority::Address {
-                address: ctx.accounts.mint_config.key()
-            }),
-        };
-
-        CreateV2CpiBuilder::new(&ctx.accounts.mpl_core_program)
-            .asset(&ctx.accounts.nft_asset)
-            .collection(Some(&ctx.accounts.collection_asset))
-            .authority(Some(&ctx.accounts.mint_config.to_account_info()))
-            .payer(&ctx.accounts.payer.to_account_info())
-            .owner(Some(&ctx.accounts.payer.to_account_info()))
-            .system_program(&ctx.accounts.system_program.to_account_info())
-            .name(nft_name)
-            .uri(nft_uri)
-            .data_state(DataState::AccountState)
-            .plugins(vec![burn_delegate_plugin])
-            .invoke_signed(&[mint_config_seeds, nft_seeds])?;
+        EditionNftAccounts {
+            mpl_core_program: &ctx.accounts.mpl_core_program,
+            nft_asset: &ctx.accounts.nft_asset,
+            collection_asset: &ctx.accounts.collection_asset,
+            mint_config: &ctx.accounts.mint_config.to_account_info(),
+            payer: &ctx.accounts.payer.to_account_info(),
+            owner: &ctx.accounts.recipient,
+            system_program: &system_program_info,
+        }
+        .create(&content_name, &content_key, rarity, edition, &[mint_config_seeds, nft_seeds])?;
 
         // Update content minted count and lock content after first mint
         ctx.accounts.content.minted_count = edition;
@@ -357,7 +414,49 @@ impl<'info> SimpleMint<'info> {
         // STEP 4: Distribute payment (80/5/3/12 split)
         // =====================================================================
 
-        if mint_price > 0 {
+        if mint_price > 0 && ctx.accounts.mint_config.currency == PaymentCurrency::Usdc {
+            let usdc_mint = ctx.accounts.ecosystem_config.usdc_mint;
+            let token_program = ctx.accounts.token_program.as_ref()
+                .ok_or(ContentRegistryError::UsdcAccountsRequired)?
+                .to_account_info();
+            let payer_usdc = require_usdc_account(&ctx.accounts.payer_usdc, &usdc_mint, &payer_key)?
+                .to_account_info();
+            let creator_usdc = require_usdc_account(&ctx.accounts.creator_usdc, &usdc_mint, &creator_key)?
+                .to_account_info();
+            let platform_usdc = require_usdc_account(&ctx.accounts.platform_usdc, &usdc_mint, platform_treasury_info.key)?
+                .to_account_info();
+            let treasury_usdc = require_usdc_account(&ctx.accounts.treasury_usdc, &usdc_mint, &ctx.accounts.ecosystem_config.treasury)?
+                .to_account_info();
+            let pool_usdc = require_usdc_vault(&ctx.accounts.content_reward_pool_usdc, &usdc_mint, &pools.content_reward_pool.key())?
+                .to_account_info();
+            let payer_ai = ctx.accounts.payer.to_account_info();
+
+            let (creator_amount, platform_amount, ecosystem_amount, holder_reward_amount) =
+                EcosystemConfig::calculate_primary_split(mint_price);
+
+            // For first NFT, holder reward goes to creator (no holders yet)
+            let final_creator_amount = if !had_existing_nfts {
+                creator_amount + holder_reward_amount
+            } else {
+                creator_amount
+            };
+
+            transfer_usdc(&token_program, &payer_usdc, &creator_usdc, &payer_ai, final_creator_amount, &[])?;
+            transfer_usdc(&token_program, &payer_usdc, &platform_usdc, &payer_ai, platform_amount, &[])?;
+            transfer_usdc(&token_program, &payer_usdc, &treasury_usdc, &payer_ai, ecosystem_amount, &[])?;
+
+            // Holder reward to the pool's USDC vault (12%) and update USDC RPS
+            if had_existing_nfts && holder_reward_amount > 0 {
+                transfer_usdc(&token_program, &payer_usdc, &pool_usdc, &payer_ai, holder_reward_amount, &[])?;
+                pools.content_reward_pool.add_usdc_rewards(holder_reward_amount);
+            }
+
+            ctx.accounts.ecosystem_config.total_fees_usdc += ecosystem_amount;
+
+            msg!("USDC payment: creator={}, platform={}, ecosystem={}, holder_pool={}",
+                final_creator_amount, platform_amount, ecosystem_amount,
+                if had_existing_nfts { holder_reward_amount } else { 0 });
+        } else if mint_price > 0 {
             let (creator_amount, platform_amount, ecosystem_amount, holder_reward_amount) =
                 EcosystemConfig::calculate_primary_split(mint_price);
 
@@ -420,13 +519,13 @@ impl<'info> SimpleMint<'info> {
                         ctx.accounts.system_program.to_account_info(),
                         anchor_lang::system_program::Transfer {
                             from: ctx.accounts.payer.to_account_info(),
-                            to: ctx.accounts.content_reward_pool.to_account_info(),
+                            to: pools.content_reward_pool.to_account_info(),
                         },
                     ),
                     holder_reward_amount,
                 )?;
                 // Update reward_per_share BEFORE adding new NFT weight
-                ctx.accounts.content_reward_pool.add_rewards(holder_reward_amount);
+                pools.content_reward_pool.add_rewards(holder_reward_amount);
             }
 
             msg!("Payment: creator={}, platform={}, ecosystem={}, holder_pool={}",
@@ -435,96 +534,28 @@ impl<'info> SimpleMint<'info> {
         }
 
         // =====================================================================
-        // STEP 5: Set reward debts BEFORE adding NFT weight to pools
+        // STEP 5: Set reward debts, then add NFT weight to ALL pools
         // =====================================================================
 
-        // Content pool debt (IMMEDIATE pool - use actual RPS)
-        let content_debt = (weight as u128) * ctx.accounts.content_reward_pool.reward_per_share;
+        pools.register_edition(&mut ctx.accounts.unified_nft_state, nft_asset_key, rarity, weight, timestamp);
 
-        // =====================================================================
-        // Calculate virtual RPS for LAZY pools (includes undistributed treasury)
-        // =====================================================================
-
-        // Get streaming treasury balances for virtual RPS calculation
-        let patron_treasury_balance = ctx.accounts.creator_patron_treasury.lamports();
-        let eco_treasury_balance = ctx.accounts.ecosystem_streaming_treasury.lamports();
-
-        // Patron pool virtual RPS (12% of streaming treasury goes to holder pool)
-        let virtual_patron_rps = calculate_virtual_rps(
-            ctx.accounts.creator_patron_pool.reward_per_share,
-            patron_treasury_balance,
-            12, // holder share percentage
-            ctx.accounts.creator_patron_pool.total_weight + weight as u64, // include new NFT weight
-        );
-
-        // Global holder pool virtual RPS (12% of ecosystem treasury)
-        let virtual_global_rps = calculate_virtual_rps(
-            ctx.accounts.global_holder_pool.reward_per_share,
-            eco_treasury_balance,
-            12, // holder share percentage
-            ctx.accounts.global_holder_pool.total_weight + weight as u64, // include new NFT weight
-        );
-
-        // Creator dist pool virtual RPS (80% of ecosystem treasury)
-        let virtual_creator_dist_rps = calculate_virtual_rps(
-            ctx.accounts.creator_dist_pool.reward_per_share,
-            eco_treasury_balance,
-            80, // creator share percentage
-            ctx.accounts.creator_dist_pool.total_weight + weight as u64, // include new NFT weight
+        emit_gift(
+            payer_key,
+            ctx.accounts.recipient.key(),
+            GiftType::ContentNft,
+            content_key,
+            nft_asset_key,
+            gift_message_cid,
+            timestamp,
         );
 
-        // Patron pool debt (LAZY pool - use VIRTUAL RPS)
-        let patron_debt = (weight as u128) * virtual_patron_rps;
-
-        // Global holder pool debt (LAZY pool - use VIRTUAL RPS)
-        let global_debt = (weight as u128) * virtual_global_rps;
-
-        // Creator dist pool debt (LAZY pool - use VIRTUAL RPS) - ADD to creator's accumulated debt
-        let creator_debt_increment = (weight as u128) * virtual_creator_dist_rps;
-
-        // =====================================================================
-        // STEP 6: Initialize NFT state account
-        // =====================================================================
-
-        // UnifiedNftRewardState (tracks rarity + all pool debts)
-        ctx.accounts.unified_nft_state.nft_asset = nft_asset_key;
-        ctx.accounts.unified_nft_state.creator = creator_key;
-        ctx.accounts.unified_nft_state.rarity = rarity.clone();
-        ctx.accounts.unified_nft_state.weight = weight;
-        ctx.accounts.unified_nft_state.is_bundle = false;
-        ctx.accounts.unified_nft_state.content_or_bundle = content_key;
-        ctx.accounts.unified_nft_state.content_or_bundle_debt = content_debt;
-        ctx.accounts.unified_nft_state.patron_debt = patron_debt;
-        ctx.accounts.unified_nft_state.global_debt = global_debt;
-        ctx.accounts.unified_nft_state.created_at = timestamp;
-
-        // =====================================================================
-        // STEP 7: Add NFT weight to ALL pools (AFTER setting debts)
-        // =====================================================================
-
-        // Content reward pool
-        ctx.accounts.content_reward_pool.add_nft(weight);
-
-        // Creator patron pool
-        ctx.accounts.creator_patron_pool.total_weight += weight as u64;
-
-        // Global holder pool
-        ctx.accounts.global_holder_pool.total_weight += weight as u64;
-
-        // Creator dist pool
-        ctx.accounts.creator_dist_pool.total_weight += weight as u64;
-
-        // Creator weight (ADD debt, not SET)
-        ctx.accounts.creator_weight.total_weight += weight as u64;
-        ctx.accounts.creator_weight.reward_debt += creator_debt_increment;
-
         msg!("Simple mint completed!");
         msg!("NFT: {} | Rarity: {:?} | Weight: {}", nft_asset_key, rarity, weight);
         msg!("Pools updated: content={}, patron={}, global={}, creator_dist={}",
-            ctx.accounts.content_reward_pool.total_weight,
-            ctx.accounts.creator_patron_pool.total_weight,
-            ctx.accounts.global_holder_pool.total_weight,
-            ctx.accounts.creator_dist_pool.total_weight);
+            pools.content_reward_pool.total_weight,
+            pools.creator_patron_pool.total_weight,
+            pools.global_holder_pool.total_weight,
+            pools.creator_dist_pool.total_weight);
 
         Ok(())
     }
@@ -553,6 +584,7 @@ pub struct SimpleMintBundle<'info> {
 
     /// MintConfig PDA - authority for collection operations (signs NFT creation)
     #[account(
+        mut,
         seeds = [MINT_CONFIG_SEED, bundle.key().as_ref()],
         bump
     )]
@@ -685,6 +717,54 @@ pub struct SimpleMintBundle<'info> {
     // NOTE: platform_treasury removed - use platform (variable host operator) for 5% fee
     // NOTE: ecosystem_treasury removed - use treasury (ecosystem_config.treasury) for 3% fee
 
+    // =========================================================================
+    // USDC payment accounts (required when mint_config.currency == Usdc)
+    // =========================================================================
+
+    /// Payer's USDC token account
+    #[account(mut)]
+    pub payer_usdc: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Creator's USDC token account (80%)
+    #[account(mut)]
+    pub creator_usdc: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Platform's USDC token account (5%) - owned by platform, or treasury if no platform
+    #[account(mut)]
+    pub platform_usdc: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Ecosystem treasury's USDC token account (3%)
+    #[account(mut)]
+    pub treasury_usdc: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Bundle reward pool's USDC ATA (bundle half of 12% holder rewards)
+    #[account(mut)]
+    pub reward_pool_usdc: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    // =========================================================================
+    // Per-wallet limit accounts (required when mint_config.max_per_wallet is set)
+    // =========================================================================
+
+    /// Per-(wallet, bundle) mint counter
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + WalletMintRecord::INIT_SPACE,
+        seeds = [WALLET_MINT_RECORD_SEED, bundle.key().as_ref(), payer.key().as_ref()],
+        bump
+    )]
+    pub wallet_mint_record: Option<Box<Account<'info, WalletMintRecord>>>,
+
+    /// Rent-to-own spend record - credited toward the price and closed on purchase
+    #[account(
+        mut,
+        seeds = [RENTAL_CREDIT_SEED, bundle.key().as_ref(), payer.key().as_ref()],
+        bump
+    )]
+    pub rental_credit: Option<Box<Account<'info, RentalCredit>>>,
+
     // =========================================================================
     // Payer and system accounts
     // =========================================================================
@@ -692,6 +772,9 @@ pub struct SimpleMintBundle<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    /// CHECK: Receives the NFT - the payer for a regular purchase, another wallet for a gift
+    pub recipient: AccountInfo<'info>,
+
     /// CHECK: Slot hashes sysvar for randomness
     #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::ID)]
     pub slot_hashes: AccountInfo<'info>,
@@ -704,12 +787,30 @@ pub struct SimpleMintBundle<'info> {
 }
 
 impl<'info> SimpleMintBundle<'info> {
-    pub fn handler<'a>(ctx: Context<'_, '_, 'a, 'a, SimpleMintBundle<'a>>, bundle_name: String) -> Result<()> {
+    /// Rent-to-own: discount the price by prior rental spend and close the credit record
+    fn consume_rental_credit(&self, price: u64) -> Result<u64> {
+        match &self.rental_credit {
+            Some(credit) if self.mint_config.rent_to_own_enabled() => {
+                let discounted = self.mint_config.rent_to_own_price(price, credit.total_spent);
+                credit.close(self.payer.to_account_info())?;
+                Ok(discounted)
+            }
+            _ => Ok(price),
+        }
+    }
+
+    pub fn handler<'a>(
+        ctx: Context<'_, '_, 'a, 'a, SimpleMintBundle<'a>>,
+        bundle_name: String,
+        max_price: u64,
+        gift_message_cid: Option<String>,
+    ) -> Result<()> {
         // Validate bundle name (1-32 chars for Metaplex Core)
         require!(
             !bundle_name.is_empty() && bundle_name.len() <= 32,
             ContentRegistryError::InvalidContentName
         );
+        validate_gift_message(&gift_message_cid)?;
 
         let clock = Clock::get()?;
         let timestamp = clock.unix_timestamp;
@@ -728,7 +829,18 @@ impl<'info> SimpleMintBundle<'info> {
             require!(ctx.accounts.bundle.minted_count < max_supply, ContentRegistryError::MaxSupplyReached);
         }
 
-        let mint_price = ctx.accounts.mint_config.price;
+        // Per-wallet cap
+        ctx.accounts.bundle.record_wallet_mint(
+            ctx.accounts.wallet_mint_record.as_deref_mut().map(|r| &mut **r),
+            bundle_key,
+            payer_key,
+            ctx.accounts.mint_config.max_per_wallet,
+        )?;
+
+        // Effective price from the pricing mode, bounded by the buyer's slippage limit
+        let mint_price = ctx.accounts.mint_config.current_price(ctx.accounts.bundle.minted_count, timestamp);
+        let mint_price = ctx.accounts.consume_rental_credit(mint_price)?;
+        MintConfig::require_max_price(mint_price, max_price)?;
         let had_existing_nfts = ctx.accounts.reward_pool.total_weight > 0;
 
         // Generate randomness
@@ -741,7 +853,7 @@ impl<'info> SimpleMintBundle<'info> {
             &clock.slot.to_le_bytes(),
         ]);
 
-        let (rarity, weight) = determine_rarity_from_bytes(randomness_seed.to_bytes());
+        let (rarity, weight) = determine_rarity_from_bytes(randomness_seed.to_bytes(), &mut ctx.accounts.mint_config)?;
 
         // Initialize pools if needed
         if ctx.accounts.reward_pool.item == Pubkey::default() {
@@ -826,7 +938,7 @@ impl<'info> SimpleMintBundle<'info> {
             .collection(Some(&ctx.accounts.collection_asset))
             .authority(Some(&ctx.accounts.mint_config.to_account_info()))
             .payer(&ctx.accounts.payer.to_account_info())
-            .owner(Some(&ctx.accounts.payer.to_account_info()))
+            .owner(Some(&ctx.accounts.recipient.to_account_info()))
             .system_program(&ctx.accounts.system_program.to_account_info())
             .name(nft_name)
             .uri(nft_uri)
@@ -841,7 +953,64 @@ impl<'info> SimpleMintBundle<'info> {
         }
 
         // Distribute payment
-        if mint_price > 0 {
+        if mint_price > 0 && ctx.accounts.mint_config.currency == PaymentCurrency::Usdc {
+            let usdc_mint = ctx.accounts.ecosystem_config.usdc_mint;
+            let token_program = ctx.accounts.token_program.as_ref()
+                .ok_or(ContentRegistryError::UsdcAccountsRequired)?
+                .to_account_info();
+            let payer_usdc = require_usdc_account(&ctx.accounts.payer_usdc, &usdc_mint, &payer_key)?
+                .to_account_info();
+            let creator_usdc = require_usdc_account(&ctx.accounts.creator_usdc, &usdc_mint, &creator_key)?
+                .to_account_info();
+            let platform_usdc = require_usdc_account(&ctx.accounts.platform_usdc, &usdc_mint, platform_treasury_info.key)?
+                .to_account_info();
+            let treasury_usdc = require_usdc_account(&ctx.accounts.treasury_usdc, &usdc_mint, &ctx.accounts.ecosystem_config.treasury)?
+                .to_account_info();
+            let pool_usdc = require_usdc_vault(&ctx.accounts.reward_pool_usdc, &usdc_mint, &ctx.accounts.reward_pool.key())?
+                .to_account_info();
+            let payer_ai = ctx.accounts.payer.to_account_info();
+
+            let (creator_amount, platform_amount, ecosystem_amount, holder_reward_amount) =
+                EcosystemConfig::calculate_primary_split(mint_price);
+
+            let final_creator_amount = if !had_existing_nfts {
+                creator_amount + holder_reward_amount
+            } else {
+                creator_amount
+            };
+
+            transfer_usdc(&token_program, &payer_usdc, &creator_usdc, &payer_ai, final_creator_amount, &[])?;
+            transfer_usdc(&token_program, &payer_usdc, &platform_usdc, &payer_ai, platform_amount, &[])?;
+            transfer_usdc(&token_program, &payer_usdc, &treasury_usdc, &payer_ai, ecosystem_amount, &[])?;
+
+            // 50/50 holder reward split, same as SOL: bundle vault + content vaults
+            // remaining_accounts are (bundle_item, content_reward_pool, pool_usdc_ata) triples for USDC bundles
+            if had_existing_nfts && holder_reward_amount > 0 {
+                let bundle_share = holder_reward_amount / 2;
+                let content_share = holder_reward_amount - bundle_share;
+
+                transfer_usdc(&token_program, &payer_usdc, &pool_usdc, &payer_ai, bundle_share, &[])?;
+                ctx.accounts.reward_pool.add_usdc_rewards(bundle_share);
+
+                if content_share > 0 && !ctx.remaining_accounts.is_empty() {
+                    let distributed = distribute_usdc_to_content_pools(
+                        ctx.remaining_accounts,
+                        &bundle_key,
+                        &token_program,
+                        &payer_usdc,
+                        &payer_ai,
+                        &usdc_mint,
+                        content_share,
+                    )?;
+                    msg!("Distributed {} USDC base units by weight to content pools", distributed);
+                }
+            }
+
+            ctx.accounts.ecosystem_config.total_fees_usdc += ecosystem_amount;
+
+            msg!("USDC payment: creator={}, platform={}, ecosystem={}",
+                final_creator_amount, platform_amount, ecosystem_amount);
+        } else if mint_price > 0 {
             let (creator_amount, platform_amount, ecosystem_amount, holder_reward_amount) =
                 EcosystemConfig::calculate_primary_split(mint_price);
 
@@ -989,6 +1158,7 @@ impl<'info> SimpleMintBundle<'info> {
         // Calculate debts BEFORE adding weight
         // Bundle pool debt (IMMEDIATE pool - use actual RPS)
         let bundle_debt = (weight as u128) * ctx.accounts.reward_pool.reward_per_share;
+        let usdc_debt = (weight as u128) * ctx.accounts.reward_pool.usdc_reward_per_share;
 
         // Calculate virtual RPS for LAZY pools (includes undistributed treasury)
         let patron_treasury_balance = ctx.accounts.creator_patron_treasury.lamports();
@@ -1026,7 +1196,7 @@ impl<'info> SimpleMintBundle<'info> {
         // Initialize NFT state account (unified - tracks rarity + all pool debts)
         ctx.accounts.unified_nft_state.nft_asset = nft_asset_key;
         ctx.accounts.unified_nft_state.creator = creator_key;
-        ctx.accounts.unified_nft_state.rarity = rarity.clone();
+        ctx.accounts.unified_nft_state.rarity = rarity;
         ctx.accounts.unified_nft_state.weight = weight;
         ctx.accounts.unified_nft_state.is_bundle = true;
         ctx.accounts.unified_nft_state.content_or_bundle = bundle_key;
@@ -1034,6 +1204,7 @@ impl<'info> SimpleMintBundle<'info> {
         ctx.accounts.unified_nft_state.patron_debt = patron_debt;
         ctx.accounts.unified_nft_state.global_debt = global_debt;
         ctx.accounts.unified_nft_state.created_at = timestamp;
+        ctx.accounts.unified_nft_state.usdc_debt = usdc_debt;
 
         // Add weight to ALL pools
         ctx.accounts.reward_pool.add_nft(weight);
@@ -1043,6 +1214,16 @@ impl<'info> SimpleMintBundle<'info> {
         ctx.accounts.creator_weight.total_weight += weight as u64;
         ctx.accounts.creator_weight.reward_debt += creator_debt_increment;
 
+        emit_gift(
+            payer_key,
+            ctx.accounts.recipient.key(),
+            GiftType::BundleNft,
+            bundle_key,
+            nft_asset_key,
+            gift_message_cid,
+            timestamp,
+        );
+
         msg!("Simple bundle mint completed!");
         msg!("NFT: {} | Rarity: {:?} | Weight: {}", nft_asset_key, rarity, weight);
 
@@ -1054,9 +1235,13 @@ impl<'info> SimpleMintBundle<'info> {
 // Helper function
 // ============================================================================
 
-/// Determine rarity from random bytes (same logic as existing)
-fn determine_rarity_from_bytes(random_bytes: [u8; 32]) -> (Rarity, u16) {
-    let rarity = Rarity::from_random(random_bytes);
-    let weight = rarity.weight();
-    (rarity, weight)
+/// Determine rarity and pool weight from random bytes using the item's rarity table
+/// In quota mode this consumes one edition of the drawn tier
+pub(crate) fn determine_rarity_from_bytes(
+    random_bytes: [u8; 32],
+    mint_config: &mut MintConfig,
+) -> Result<(Rarity, u16)> {
+    mint_config
+        .draw_rarity(random_bytes)
+        .ok_or_else(|| ContentRegistryError::RarityQuotaExhausted.into())
 }
@@ -8,9 +8,12 @@ use streamflow_sdk::cpi::accounts::{
     Cancel as StreamflowCancel,
     Topup as StreamflowTopup,
 };
+use streamflow_sdk::state::Contract;
 
 use crate::state::*;
 use crate::errors::ContentRegistryError;
+use crate::events::{GiftType, PatronTierChangedEvent};
+use crate::contexts::gift::{emit_gift, validate_gift_message};
 
 // Use Streamflow SDK's program ID (configured via its devnet feature)
 pub use streamflow_sdk::ID as STREAMFLOW_PROGRAM_ID;
@@ -57,6 +60,9 @@ pub struct JoinEcosystemMembership<'info> {
     #[account(mut)]
     pub subscriber: Signer<'info>,
 
+    /// CHECK: Subscription holder - the subscriber for a regular join, another wallet for a gift
+    pub recipient: AccountInfo<'info>,
+
     /// Subscriber's WSOL token account (source of funds)
     #[account(
         mut,
@@ -74,12 +80,12 @@ pub struct JoinEcosystemMembership<'info> {
     )]
     pub ecosystem_config: Account<'info, EcosystemSubConfig>,
 
-    /// User's ecosystem subscription record (created or reused if cancelled)
+    /// User's ecosystem subscription record (created, or reused once its stream has run out)
     #[account(
         init_if_needed,
         payer = subscriber,
         space = 8 + EcosystemSubscription::INIT_SPACE,
-        seeds = [ECOSYSTEM_SUB_SEED, subscriber.key().as_ref()],
+        seeds = [ECOSYSTEM_SUB_SEED, recipient.key().as_ref()],
         bump
     )]
     pub ecosystem_subscription: Account<'info, EcosystemSubscription>,
@@ -165,17 +171,45 @@ pub struct JoinEcosystemMembership<'info> {
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+
+    // Accounts below were appended so existing clients keep working
+
+    /// Stream of the existing subscription record (required while that record is active)
+    /// CHECK: Matched against the record's stream_id and Streamflow ownership
+    pub previous_stream: Option<AccountInfo<'info>>,
 }
 
 /// Join ecosystem membership with specified duration
 /// duration_type: 0 = monthly (30 days), 1 = yearly (365 days)
+/// gift_message_cid: Optional gift message when `recipient` is not the subscriber
+/// An active record is only replaced once its stream has run out, so nobody can
+/// overwrite another wallet's live subscription. Gifted streams are not
+/// cancelable, so the payer can't claw a gift back
 pub fn handle_join_ecosystem_membership(
     ctx: Context<JoinEcosystemMembership>,
     duration_type: u8,
+    gift_message_cid: Option<String>,
 ) -> Result<()> {
+    validate_gift_message(&gift_message_cid)?;
     let config = &ctx.accounts.ecosystem_config;
     let timestamp = Clock::get()?.unix_timestamp as u64;
 
+    let existing = &ctx.accounts.ecosystem_subscription;
+    if existing.subscriber != Pubkey::default() && existing.is_active {
+        let previous_stream = ctx.accounts.previous_stream.as_ref()
+            .ok_or(ContentRegistryError::SubscriptionStillActive)?;
+        require!(
+            previous_stream.key() == existing.stream_id,
+            ContentRegistryError::InvalidStreamId
+        );
+        let paid_through = stream_paid_through(previous_stream, &existing.stream_id)?;
+        require!(
+            subscription_replaceable(existing.is_active, paid_through, timestamp as i64),
+            ContentRegistryError::SubscriptionStillActive
+        );
+    }
+    let is_gift = ctx.accounts.recipient.key() != ctx.accounts.subscriber.key();
+
     // Calculate amount and duration based on type
     let (amount, duration_seconds) = match duration_type {
         0 => (config.price, SECONDS_PER_MONTH), // Monthly
@@ -237,7 +271,7 @@ pub fn handle_join_ecosystem_membership(
         amount_per_period,
         cliff,
         cliff_amount,
-        true,  // cancelable_by_sender
+        !is_gift, // cancelable_by_sender - gifts can't be clawed back
         false, // cancelable_by_recipient
         true,  // automatic_withdrawal - Streamflow auto-withdraws to treasury
         false, // transferable_by_sender
@@ -251,13 +285,23 @@ pub fn handle_join_ecosystem_membership(
 
     // Store subscription record
     let subscription = &mut ctx.accounts.ecosystem_subscription;
-    subscription.subscriber = ctx.accounts.subscriber.key();
+    subscription.subscriber = ctx.accounts.recipient.key();
     subscription.stream_id = ctx.accounts.stream_metadata.key();
     subscription.started_at = timestamp as i64;
     subscription.is_active = true;
 
+    emit_gift(
+        ctx.accounts.subscriber.key(),
+        ctx.accounts.recipient.key(),
+        GiftType::EcosystemSubscription,
+        Pubkey::default(),
+        ctx.accounts.ecosystem_subscription.key(),
+        gift_message_cid,
+        timestamp as i64,
+    );
+
     msg!("Ecosystem membership created via Streamflow");
-    msg!("  Subscriber: {}", ctx.accounts.subscriber.key());
+    msg!("  Subscriber: {}", ctx.accounts.recipient.key());
     msg!("  Stream ID: {}", ctx.accounts.stream_metadata.key());
     msg!("  Treasury (enforced): {}", ctx.accounts.ecosystem_treasury.key());
     msg!("  Amount: {} lamports (adjusted from {})", adjusted_amount, amount);
@@ -277,6 +321,9 @@ pub struct JoinCreatorMembership<'info> {
     #[account(mut)]
     pub subscriber: Signer<'info>,
 
+    /// CHECK: Subscription holder - the subscriber for a regular join, another wallet for a gift
+    pub recipient: AccountInfo<'info>,
+
     /// Subscriber's WSOL token account
     #[account(
         mut,
@@ -303,7 +350,7 @@ pub struct JoinCreatorMembership<'info> {
         init,
         payer = subscriber,
         space = 8 + CreatorPatronSubscription::INIT_SPACE,
-        seeds = [CREATOR_PATRON_SUB_SEED, subscriber.key().as_ref(), creator.key().as_ref()],
+        seeds = [CREATOR_PATRON_SUB_SEED, recipient.key().as_ref(), creator.key().as_ref()],
         bump
     )]
     pub patron_subscription: Account<'info, CreatorPatronSubscription>,
@@ -381,28 +428,23 @@ pub struct JoinCreatorMembership<'info> {
 }
 
 /// Join creator membership
-/// tier: 0 = Membership (support only), 1 = Subscription (support + access)
+/// tier_index: Index into the creator's patron tiers
 /// duration_type: 0 = monthly, 1 = yearly
+/// gift_message_cid: Optional gift message when `recipient` is not the subscriber
+/// Gifted streams are not cancelable, so the payer can't claw a gift back
 pub fn handle_join_creator_membership(
     ctx: Context<JoinCreatorMembership>,
-    tier: u8,
+    tier_index: u8,
     duration_type: u8,
+    gift_message_cid: Option<String>,
 ) -> Result<()> {
+    validate_gift_message(&gift_message_cid)?;
     let config = &ctx.accounts.patron_config;
     let timestamp = Clock::get()?.unix_timestamp as u64;
+    let is_gift = ctx.accounts.recipient.key() != ctx.accounts.subscriber.key();
 
     // Get price based on tier
-    let base_price = match tier {
-        0 => {
-            require!(config.membership_price > 0, ContentRegistryError::TierNotAvailable);
-            config.membership_price
-        }
-        1 => {
-            require!(config.subscription_price > 0, ContentRegistryError::TierNotAvailable);
-            config.subscription_price
-        }
-        _ => return Err(ContentRegistryError::InvalidTier.into()),
-    };
+    let base_price = config.get_tier(tier_index)?.price;
 
     // Calculate amount and duration
     let (amount, duration_seconds) = match duration_type {
@@ -456,7 +498,7 @@ pub fn handle_join_creator_membership(
         amount_per_period,
         cliff,
         cliff_amount,
-        true,  // cancelable_by_sender
+        !is_gift, // cancelable_by_sender - gifts can't be clawed back
         false, // cancelable_by_recipient
         true,  // automatic_withdrawal - Streamflow auto-withdraws to treasury
         false, // transferable_by_sender
@@ -470,15 +512,25 @@ pub fn handle_join_creator_membership(
 
     // Store subscription record
     let subscription = &mut ctx.accounts.patron_subscription;
-    subscription.subscriber = ctx.accounts.subscriber.key();
+    subscription.subscriber = ctx.accounts.recipient.key();
     subscription.creator = ctx.accounts.creator.key();
-    subscription.tier = if tier == 0 { PatronTier::Membership } else { PatronTier::Subscription };
+    subscription.tier_index = tier_index;
     subscription.stream_id = ctx.accounts.stream_metadata.key();
     subscription.started_at = timestamp as i64;
     subscription.is_active = true;
 
+    emit_gift(
+        ctx.accounts.subscriber.key(),
+        ctx.accounts.recipient.key(),
+        GiftType::PatronSubscription,
+        ctx.accounts.creator.key(),
+        ctx.accounts.patron_subscription.key(),
+        gift_message_cid,
+        timestamp as i64,
+    );
+
     msg!("Creator membership created via Streamflow");
-    msg!("  Subscriber: {}", ctx.accounts.subscriber.key());
+    msg!("  Subscriber: {}", ctx.accounts.recipient.key());
     msg!("  Creator: {}", ctx.accounts.creator.key());
     msg!("  Stream ID: {}", ctx.accounts.stream_metadata.key());
     msg!("  Treasury (enforced): {}", ctx.accounts.creator_treasury.key());
@@ -572,9 +624,15 @@ pub struct CancelEcosystemMembershipStream<'info> {
     pub token_program: Program<'info, Token>,
 }
 
+/// Gifted streams are rejected - the recipient closes the record with
+/// cancel_ecosystem_subscription once the gift has run out
 pub fn handle_cancel_ecosystem_membership_stream(
     ctx: Context<CancelEcosystemMembershipStream>,
 ) -> Result<()> {
+    let contract = read_stream_contract(&ctx.accounts.stream_metadata)?
+        .ok_or(ContentRegistryError::InvalidStreamMetadata)?;
+    require_self_funded_stream(&contract, &ctx.accounts.subscriber.key())?;
+
     let cpi_accounts = StreamflowCancel {
         authority: ctx.accounts.subscriber.to_account_info(),
         sender: ctx.accounts.subscriber.to_account_info(),
@@ -686,6 +744,7 @@ pub struct TopupEcosystemMembership<'info> {
 
 /// Topup ecosystem membership
 /// duration_type: 0 = monthly, 1 = yearly
+/// Gift recipients can top up too - the stream keeps its original sender
 pub fn handle_topup_ecosystem_membership(
     ctx: Context<TopupEcosystemMembership>,
     duration_type: u8,
@@ -730,6 +789,260 @@ pub fn handle_topup_ecosystem_membership(
     Ok(())
 }
 
+// ============================================================================
+// CHANGE PATRON TIER (Cancel stream, credit balance into a new-tier stream)
+// ============================================================================
+
+/// Move a patron subscription to another tier without losing continuity
+/// The old stream is cancelled and its unstreamed balance funds a replacement
+/// stream to the creator's treasury at the new tier's rate
+#[derive(Accounts)]
+pub struct ChangePatronTier<'info> {
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+
+    /// Subscriber's WSOL token account - receives the refund and funds the new stream
+    #[account(
+        mut,
+        associated_token::mint = wsol_mint,
+        associated_token::authority = subscriber,
+    )]
+    pub subscriber_wsol: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Creator wallet address
+    pub creator: AccountInfo<'info>,
+
+    /// Creator's patron config (must be active)
+    #[account(
+        seeds = [CREATOR_PATRON_CONFIG_SEED, creator.key().as_ref()],
+        bump,
+        constraint = patron_config.is_active @ ContentRegistryError::PatronConfigInactive,
+        constraint = patron_config.creator == creator.key() @ ContentRegistryError::Unauthorized
+    )]
+    pub patron_config: Box<Account<'info, CreatorPatronConfig>>,
+
+    /// Subscription being moved to the new tier
+    #[account(
+        mut,
+        seeds = [CREATOR_PATRON_SUB_SEED, subscriber.key().as_ref(), creator.key().as_ref()],
+        bump,
+        constraint = patron_subscription.subscriber == subscriber.key() @ ContentRegistryError::Unauthorized
+    )]
+    pub patron_subscription: Box<Account<'info, CreatorPatronSubscription>>,
+
+    /// Creator's streaming treasury PDA - ENFORCED recipient
+    /// CHECK: PDA verified by seeds
+    #[account(
+        mut,
+        seeds = [CREATOR_PATRON_TREASURY_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_treasury: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = wsol_mint,
+        associated_token::authority = creator_treasury,
+    )]
+    pub creator_treasury_wsol: Box<Account<'info, TokenAccount>>,
+
+    // === Current stream (cancelled) ===
+    /// CHECK: Current stream metadata - verified against subscription record
+    #[account(
+        mut,
+        constraint = old_stream_metadata.key() == patron_subscription.stream_id @ ContentRegistryError::InvalidStreamId
+    )]
+    pub old_stream_metadata: AccountInfo<'info>,
+
+    /// CHECK: Escrow tokens PDA of the current stream
+    #[account(mut)]
+    pub old_escrow_tokens: AccountInfo<'info>,
+
+    // === Replacement stream (created) ===
+    /// CHECK: Will be initialized by Streamflow
+    #[account(mut)]
+    pub stream_metadata: Signer<'info>,
+
+    /// CHECK: PDA derived by Streamflow
+    #[account(mut)]
+    pub escrow_tokens: AccountInfo<'info>,
+
+    /// CHECK: Known Streamflow account
+    #[account(mut, address = STREAMFLOW_TREASURY)]
+    pub streamflow_treasury: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = wsol_mint,
+        associated_token::authority = streamflow_treasury,
+    )]
+    pub streamflow_treasury_wsol: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Known Streamflow account
+    #[account(mut, address = STREAMFLOW_WITHDRAWOR)]
+    pub streamflow_withdrawor: AccountInfo<'info>,
+
+    /// CHECK: Partner for fees
+    #[account(mut)]
+    pub partner: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = wsol_mint,
+        associated_token::authority = partner,
+    )]
+    pub partner_wsol: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = WSOL_MINT)]
+    pub wsol_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Known Streamflow account
+    #[account(address = STREAMFLOW_FEE_ORACLE)]
+    pub fee_oracle: AccountInfo<'info>,
+
+    /// CHECK: Verified by address
+    #[account(address = STREAMFLOW_PROGRAM_ID)]
+    pub streamflow_program: AccountInfo<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Change patron tier
+/// new_tier_index: Index into the creator's patron tiers
+/// duration_type: 0 = monthly, 1 = yearly - selects the new tier's streaming rate
+/// The refunded balance is re-streamed at the new rate, so upgrades shorten and
+/// downgrades lengthen the paid-through window; started_at is kept
+pub fn handle_change_patron_tier(
+    ctx: Context<ChangePatronTier>,
+    new_tier_index: u8,
+    duration_type: u8,
+) -> Result<()> {
+    let old_tier_index = ctx.accounts.patron_subscription.tier_index;
+    require!(new_tier_index != old_tier_index, ContentRegistryError::TierUnchanged);
+
+    // Only the stream's sender gets the refund, so gifted streams keep their tier
+    let old_contract = read_stream_contract(&ctx.accounts.old_stream_metadata)?
+        .ok_or(ContentRegistryError::InvalidStreamMetadata)?;
+    require_self_funded_stream(&old_contract, &ctx.accounts.subscriber.key())?;
+
+    let base_price = ctx.accounts.patron_config.get_tier(new_tier_index)?.price;
+    let (amount, duration_seconds) = match duration_type {
+        0 => (base_price, SECONDS_PER_MONTH),
+        1 => (base_price * 10, SECONDS_PER_YEAR),
+        _ => return Err(ContentRegistryError::InvalidDurationType.into()),
+    };
+    let period: u64 = 1;
+    let amount_per_period = amount / duration_seconds;
+
+    // Step 1: Cancel the current stream - unstreamed funds return to subscriber_wsol
+    let balance_before = ctx.accounts.subscriber_wsol.amount;
+
+    let cancel_accounts = StreamflowCancel {
+        authority: ctx.accounts.subscriber.to_account_info(),
+        sender: ctx.accounts.subscriber.to_account_info(),
+        sender_tokens: ctx.accounts.subscriber_wsol.to_account_info(),
+        recipient: ctx.accounts.creator_treasury.to_account_info(),
+        recipient_tokens: ctx.accounts.creator_treasury_wsol.to_account_info(),
+        metadata: ctx.accounts.old_stream_metadata.to_account_info(),
+        escrow_tokens: ctx.accounts.old_escrow_tokens.to_account_info(),
+        streamflow_treasury: ctx.accounts.streamflow_treasury.to_account_info(),
+        streamflow_treasury_tokens: ctx.accounts.streamflow_treasury_wsol.to_account_info(),
+        partner: ctx.accounts.partner.to_account_info(),
+        partner_tokens: ctx.accounts.partner_wsol.to_account_info(),
+        mint: ctx.accounts.wsol_mint.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    };
+    streamflow_sdk::cpi::cancel(CpiContext::new(
+        ctx.accounts.streamflow_program.to_account_info(),
+        cancel_accounts,
+    ))?;
+
+    ctx.accounts.subscriber_wsol.reload()?;
+    let credit = ctx.accounts.subscriber_wsol.amount.saturating_sub(balance_before);
+
+    // Step 2: Re-stream the credit at the new tier's rate
+    require!(
+        amount_per_period > 0 && credit >= amount_per_period,
+        ContentRegistryError::InsufficientTierCredit
+    );
+    let adjusted_amount = credit / amount_per_period * amount_per_period;
+    let timestamp = Clock::get()?.unix_timestamp as u64;
+    let start_time = timestamp + 60;
+    let cliff = start_time;
+    let cliff_amount: u64 = 0;
+
+    let stream_name = create_stream_name(b"CreatorMembership");
+
+    let create_accounts = StreamflowCreate {
+        sender: ctx.accounts.subscriber.to_account_info(),
+        sender_tokens: ctx.accounts.subscriber_wsol.to_account_info(),
+        recipient: ctx.accounts.creator_treasury.to_account_info(), // ENFORCED by seeds
+        recipient_tokens: ctx.accounts.creator_treasury_wsol.to_account_info(),
+        metadata: ctx.accounts.stream_metadata.to_account_info(),
+        escrow_tokens: ctx.accounts.escrow_tokens.to_account_info(),
+        streamflow_treasury: ctx.accounts.streamflow_treasury.to_account_info(),
+        streamflow_treasury_tokens: ctx.accounts.streamflow_treasury_wsol.to_account_info(),
+        withdrawor: ctx.accounts.streamflow_withdrawor.to_account_info(),
+        partner: ctx.accounts.partner.to_account_info(),
+        partner_tokens: ctx.accounts.partner_wsol.to_account_info(),
+        mint: ctx.accounts.wsol_mint.to_account_info(),
+        fee_oracle: ctx.accounts.fee_oracle.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+        timelock_program: ctx.accounts.streamflow_program.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+
+    streamflow_sdk::cpi::create(
+        CpiContext::new(ctx.accounts.streamflow_program.to_account_info(), create_accounts),
+        start_time,
+        adjusted_amount,
+        period,
+        amount_per_period,
+        cliff,
+        cliff_amount,
+        true,  // cancelable_by_sender
+        false, // cancelable_by_recipient
+        true,  // automatic_withdrawal
+        false, // transferable_by_sender
+        false, // transferable_by_recipient
+        true,  // can_topup
+        stream_name,
+        86400, // withdraw_frequency: daily (matches epoch duration)
+        None,
+        None,
+    )?;
+
+    // Step 3: Point the subscription at the new tier and stream
+    let old_stream_id = ctx.accounts.patron_subscription.stream_id;
+    let subscription = &mut ctx.accounts.patron_subscription;
+    subscription.tier_index = new_tier_index;
+    subscription.stream_id = ctx.accounts.stream_metadata.key();
+    subscription.is_active = true;
+
+    emit!(PatronTierChangedEvent {
+        subscriber: ctx.accounts.subscriber.key(),
+        creator: ctx.accounts.creator.key(),
+        old_tier_index,
+        new_tier_index,
+        old_stream_id,
+        new_stream_id: ctx.accounts.stream_metadata.key(),
+        credited_amount: adjusted_amount,
+        timestamp: timestamp as i64,
+    });
+
+    msg!("Patron tier changed");
+    msg!("  Subscriber: {}", ctx.accounts.subscriber.key());
+    msg!("  Tier: {} -> {}", old_tier_index, new_tier_index);
+    msg!("  Credited: {} lamports", adjusted_amount);
+
+    Ok(())
+}
+
 // ============================================================================
 // HELPER FUNCTIONS
 // ============================================================================
@@ -741,3 +1054,309 @@ fn create_stream_name(prefix: &[u8]) -> [u8; 64] {
     name[..len].copy_from_slice(&prefix[..len]);
     name
 }
+
+/// Timestamp a subscription's Streamflow stream is paid through, if it is that stream
+pub(crate) fn stream_paid_through(stream_info: &AccountInfo, stream_id: &Pubkey) -> Result<Option<i64>> {
+    if stream_info.key() != *stream_id {
+        return Ok(None);
+    }
+    Ok(read_stream_contract(stream_info)?.and_then(|contract| contract_paid_through(&contract)))
+}
+
+/// Timestamp a Streamflow contract is paid through
+/// Deposits (including topups) not yet unlocked extend the window at the stream's
+/// rate, so monthly, yearly and topped-up streams all resolve from the same data.
+/// Completed pauses push the window out; a paused stream pays nothing, so it
+/// stops at the pause start until resumed. A cancelled stream stops at cancellation
+pub(crate) fn contract_paid_through(contract: &Contract) -> Option<i64> {
+    let ix = &contract.ix;
+    if ix.period == 0 || ix.amount_per_period == 0 {
+        return None;
+    }
+
+    // Everything deposited beyond the cliff and what unlocked before the last
+    // rate change streams out at the current rate from the effective start.
+    // Withdrawals only move already-unlocked funds, so they don't shorten it
+    let streamable = ix.net_amount_deposited
+        .saturating_sub(ix.cliff_amount)
+        .saturating_sub(contract.funds_unlocked_at_last_rate_change);
+    let funded_seconds = (streamable / ix.amount_per_period).saturating_mul(ix.period);
+    let mut paid_through = contract.effective_start_time()
+        .saturating_add(contract.pause_cumulative)
+        .saturating_add(funded_seconds);
+
+    if contract.current_pause_start > 0 {
+        paid_through = paid_through.min(contract.current_pause_start);
+    }
+    if contract.canceled_at > 0 {
+        paid_through = paid_through.min(contract.canceled_at);
+    }
+
+    Some(paid_through.min(i64::MAX as u64) as i64)
+}
+
+/// Deserialize a Streamflow contract, if the account is owned by Streamflow
+pub(crate) fn read_stream_contract(stream_info: &AccountInfo) -> Result<Option<Contract>> {
+    if stream_info.owner != &STREAMFLOW_PROGRAM_ID {
+        return Ok(None);
+    }
+
+    // Stream metadata is padded, so deserialize without requiring the whole slice
+    let data = stream_info.try_borrow_data()?;
+    Ok(Contract::deserialize(&mut &data[..]).ok())
+}
+
+/// Verify an externally created stream pays for a subscription on our terms:
+/// sent by the subscriber to the expected treasury PDA in WSOL, still live,
+/// and streaming at the monthly or yearly rate for `price`
+/// (same rates join_ecosystem_membership / join_creator_membership create)
+pub(crate) fn validate_subscription_stream(
+    stream_info: &AccountInfo,
+    subscriber: &Pubkey,
+    treasury: &Pubkey,
+    price: u64,
+) -> Result<()> {
+    let contract = read_stream_contract(stream_info)?
+        .ok_or(ContentRegistryError::InvalidStreamMetadata)?;
+    validate_subscription_contract(&contract, subscriber, treasury, price)
+}
+
+/// Contract-level checks of validate_subscription_stream
+pub(crate) fn validate_subscription_contract(
+    contract: &Contract,
+    subscriber: &Pubkey,
+    treasury: &Pubkey,
+    price: u64,
+) -> Result<()> {
+    require!(contract.sender == *subscriber, ContentRegistryError::StreamSenderMismatch);
+    require!(contract.recipient == *treasury, ContentRegistryError::StreamRecipientMismatch);
+    require!(contract.mint == WSOL_MINT, ContentRegistryError::StreamMintMismatch);
+    require!(
+        contract.canceled_at == 0 && !contract.closed && contract.ix.net_amount_deposited > 0,
+        ContentRegistryError::StreamNotActive
+    );
+
+    let ix = &contract.ix;
+    let rate_matches = ix.period > 0
+        && [(price, SECONDS_PER_MONTH), (price.saturating_mul(10), SECONDS_PER_YEAR)]
+            .iter()
+            .any(|&(amount, duration)| {
+                let expected = amount as u128 * ix.period as u128 / duration as u128;
+                expected > 0 && ix.amount_per_period as u128 == expected
+            });
+    require!(rate_matches, ContentRegistryError::StreamRateMismatch);
+
+    Ok(())
+}
+
+/// Require the subscriber to be the stream's sender
+/// Gifted streams are sent by the payer, who can't cancel them, so the recipient
+/// can't cancel or re-tier them either
+pub(crate) fn require_self_funded_stream(contract: &Contract, subscriber: &Pubkey) -> Result<()> {
+    require!(contract.sender == *subscriber, ContentRegistryError::GiftedSubscriptionLocked);
+    Ok(())
+}
+
+/// Whether a subscription record may be overwritten by a new join:
+/// inactive, or its stream is gone or no longer paid through
+pub(crate) fn subscription_replaceable(is_active: bool, paid_through: Option<i64>, now: i64) -> bool {
+    !is_active || paid_through.is_none_or(|paid_through| now >= paid_through)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use streamflow_sdk::state::CreateParams;
+
+    const PRICE: u64 = 100_000_000;
+    const START: u64 = 1_700_000_000;
+
+    /// Stream created the way join_* creates it: 1s period, no cliff
+    fn stream(amount: u64, duration: u64) -> Contract {
+        let amount_per_period = amount / duration;
+        Contract {
+            magic: 0,
+            version: 0,
+            created_at: START - 60,
+            amount_withdrawn: 0,
+            canceled_at: 0,
+            end_time: START + duration,
+            last_withdrawn_at: 0,
+            sender: Pubkey::new_unique(),
+            sender_tokens: Pubkey::default(),
+            recipient: Pubkey::new_unique(),
+            recipient_tokens: Pubkey::default(),
+            mint: WSOL_MINT,
+            escrow_tokens: Pubkey::default(),
+            streamflow_treasury: STREAMFLOW_TREASURY,
+            streamflow_treasury_tokens: Pubkey::default(),
+            streamflow_fee_total: 0,
+            streamflow_fee_withdrawn: 0,
+            streamflow_fee_percent: 0.0,
+            partner: Pubkey::default(),
+            partner_tokens: Pubkey::default(),
+            partner_fee_total: 0,
+            partner_fee_withdrawn: 0,
+            partner_fee_percent: 0.0,
+            ix: CreateParams {
+                start_time: START,
+                net_amount_deposited: amount_per_period * duration,
+                period: 1,
+                amount_per_period,
+                cliff: START,
+                cliff_amount: 0,
+                cancelable_by_sender: true,
+                cancelable_by_recipient: false,
+                automatic_withdrawal: true,
+                transferable_by_sender: false,
+                transferable_by_recipient: false,
+                can_topup: true,
+                stream_name: create_stream_name(b"EcoMembership"),
+                withdraw_frequency: SECONDS_PER_DAY,
+                ghost: 0,
+                pausable: false,
+                can_update_rate: false,
+            },
+            ix_padding: vec![],
+            closed: false,
+            current_pause_start: 0,
+            pause_cumulative: 0,
+            last_rate_change_time: 0,
+            funds_unlocked_at_last_rate_change: 0,
+        }
+    }
+
+    fn monthly() -> Contract {
+        stream(PRICE, SECONDS_PER_MONTH)
+    }
+
+    #[test]
+    fn monthly_stream_is_paid_through_one_month() {
+        assert_eq!(contract_paid_through(&monthly()), Some((START + SECONDS_PER_MONTH) as i64));
+    }
+
+    #[test]
+    fn yearly_stream_is_paid_through_one_year() {
+        let contract = stream(PRICE * 10, SECONDS_PER_YEAR);
+        assert_eq!(contract_paid_through(&contract), Some((START + SECONDS_PER_YEAR) as i64));
+    }
+
+    #[test]
+    fn topup_extends_at_the_stream_rate() {
+        let mut contract = monthly();
+        contract.ix.net_amount_deposited *= 2;
+        assert_eq!(contract_paid_through(&contract), Some((START + 2 * SECONDS_PER_MONTH) as i64));
+    }
+
+    #[test]
+    fn withdrawals_do_not_shorten_the_window() {
+        let mut contract = monthly();
+        contract.amount_withdrawn = contract.ix.net_amount_deposited / 2;
+        assert_eq!(contract_paid_through(&contract), Some((START + SECONDS_PER_MONTH) as i64));
+    }
+
+    #[test]
+    fn completed_pause_pushes_the_window_out() {
+        let mut contract = monthly();
+        contract.pause_cumulative = SECONDS_PER_DAY;
+        assert_eq!(
+            contract_paid_through(&contract),
+            Some((START + SECONDS_PER_MONTH + SECONDS_PER_DAY) as i64)
+        );
+    }
+
+    #[test]
+    fn paused_stream_stops_at_pause_start() {
+        let mut contract = monthly();
+        contract.current_pause_start = START + SECONDS_PER_DAY;
+        assert_eq!(contract_paid_through(&contract), Some((START + SECONDS_PER_DAY) as i64));
+    }
+
+    #[test]
+    fn cancelled_stream_stops_at_cancellation() {
+        let mut contract = monthly();
+        contract.canceled_at = START + 3 * SECONDS_PER_DAY;
+        assert_eq!(contract_paid_through(&contract), Some((START + 3 * SECONDS_PER_DAY) as i64));
+    }
+
+    #[test]
+    fn cliff_amount_is_not_streamed_time() {
+        let mut contract = monthly();
+        let cliff_amount = contract.ix.amount_per_period * SECONDS_PER_DAY;
+        contract.ix.cliff = START + SECONDS_PER_DAY;
+        contract.ix.cliff_amount = cliff_amount;
+        // Streaming starts at the cliff and the cliff amount buys no time
+        assert_eq!(
+            contract_paid_through(&contract),
+            Some((START + SECONDS_PER_MONTH) as i64)
+        );
+    }
+
+    #[test]
+    fn zero_rate_stream_has_no_window() {
+        let mut contract = monthly();
+        contract.ix.amount_per_period = 0;
+        assert_eq!(contract_paid_through(&contract), None);
+    }
+
+    #[test]
+    fn validate_accepts_monthly_and_yearly_rates() {
+        let contract = monthly();
+        assert!(validate_subscription_contract(&contract, &contract.sender, &contract.recipient, PRICE).is_ok());
+
+        let contract = stream(PRICE * 10, SECONDS_PER_YEAR);
+        assert!(validate_subscription_contract(&contract, &contract.sender, &contract.recipient, PRICE).is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_wrong_rate_and_parties() {
+        let contract = monthly();
+        let (sender, recipient) = (contract.sender, contract.recipient);
+
+        let err = validate_subscription_contract(&contract, &sender, &recipient, PRICE * 2).unwrap_err();
+        assert_eq!(err, ContentRegistryError::StreamRateMismatch.into());
+
+        let err = validate_subscription_contract(&contract, &recipient, &recipient, PRICE).unwrap_err();
+        assert_eq!(err, ContentRegistryError::StreamSenderMismatch.into());
+
+        let err = validate_subscription_contract(&contract, &sender, &sender, PRICE).unwrap_err();
+        assert_eq!(err, ContentRegistryError::StreamRecipientMismatch.into());
+    }
+
+    #[test]
+    fn validate_rejects_cancelled_and_closed_streams() {
+        let mut contract = monthly();
+        contract.canceled_at = START + 1;
+        let err = validate_subscription_contract(&contract, &contract.sender, &contract.recipient, PRICE).unwrap_err();
+        assert_eq!(err, ContentRegistryError::StreamNotActive.into());
+
+        let mut contract = monthly();
+        contract.closed = true;
+        let err = validate_subscription_contract(&contract, &contract.sender, &contract.recipient, PRICE).unwrap_err();
+        assert_eq!(err, ContentRegistryError::StreamNotActive.into());
+    }
+
+    #[test]
+    fn gifted_stream_cannot_be_cancelled_by_recipient() {
+        // join_* with a recipient: the payer is the sender, the recipient holds the record
+        let mut contract = monthly();
+        contract.ix.cancelable_by_sender = false;
+        let recipient = Pubkey::new_unique();
+
+        let err = require_self_funded_stream(&contract, &recipient).unwrap_err();
+        assert_eq!(err, ContentRegistryError::GiftedSubscriptionLocked.into());
+        assert!(require_self_funded_stream(&contract, &contract.sender).is_ok());
+    }
+
+    #[test]
+    fn live_record_is_not_replaceable() {
+        let paid_through = contract_paid_through(&monthly());
+        let end = START as i64 + SECONDS_PER_MONTH as i64;
+        assert_eq!(paid_through, Some(end));
+
+        assert!(!subscription_replaceable(true, paid_through, end - 1));
+        assert!(subscription_replaceable(true, paid_through, end));
+        assert!(subscription_replaceable(false, paid_through, end - 1));
+        assert!(subscription_replaceable(true, None, end - 1));
+    }
+}
@@ -115,6 +115,12 @@ pub fn handle_burn_nft_with_subscription(ctx: Context<BurnNftWithSubscription>)
     // STEP 1: Auto-claim pending rewards from ContentRewardPool
     // =========================================================================
     let content_pool = &mut ctx.accounts.content_reward_pool;
+
+    // USDC rewards need token accounts to pay out - holder must claim them first
+    require!(
+        nft_state.pending_usdc_reward(content_pool.usdc_reward_per_share) == 0,
+        ContentRegistryError::UnclaimedUsdcRewards
+    );
     let weighted_rps = weight as u128 * content_pool.reward_per_share;
     let content_pending = weighted_rps.saturating_sub(nft_state.content_or_bundle_debt) / PRECISION;
 
@@ -309,6 +315,12 @@ pub fn handle_burn_bundle_nft_with_subscription(ctx: Context<BurnBundleNftWithSu
 
     // STEP 1: Auto-claim pending rewards from RewardPool
     let reward_pool = &mut ctx.accounts.reward_pool;
+
+    // USDC rewards need token accounts to pay out - holder must claim them first
+    require!(
+        nft_state.pending_usdc_reward(reward_pool.usdc_reward_per_share) == 0,
+        ContentRegistryError::UnclaimedUsdcRewards
+    );
     let weighted_rps = weight as u128 * reward_pool.reward_per_share;
     let bundle_pending = weighted_rps.saturating_sub(nft_state.content_or_bundle_debt) / PRECISION;
 
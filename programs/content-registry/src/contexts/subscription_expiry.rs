@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ContentRegistryError;
+use crate::events::{SubscriptionCancelledEvent, SubscriptionCancelReason};
+use crate::contexts::streamflow_membership::{read_stream_contract, contract_paid_through};
+
+// ============================================================================
+// EXPIRE SUBSCRIPTION - Permissionless cleanup of lapsed subscriptions
+// ============================================================================
+
+/// Close a patron or ecosystem subscription whose stream is depleted or cancelled
+/// Anyone can call; rent goes back to the subscriber
+/// Exactly one of patron_subscription / ecosystem_subscription must be passed
+#[derive(Accounts)]
+pub struct ExpireSubscription<'info> {
+    /// Patron subscription to expire
+    #[account(
+        mut,
+        constraint = patron_subscription.subscriber == subscriber.key() @ ContentRegistryError::Unauthorized
+    )]
+    pub patron_subscription: Option<Account<'info, CreatorPatronSubscription>>,
+
+    /// Ecosystem subscription to expire
+    #[account(
+        mut,
+        constraint = ecosystem_subscription.subscriber == subscriber.key() @ ContentRegistryError::Unauthorized
+    )]
+    pub ecosystem_subscription: Option<Account<'info, EcosystemSubscription>>,
+
+    /// CHECK: Streamflow stream linked to the subscription - matched against stream_id in handler
+    pub stream_metadata: AccountInfo<'info>,
+
+    /// CHECK: Subscriber receiving the rent refund - matched against the subscription record
+    #[account(mut)]
+    pub subscriber: AccountInfo<'info>,
+
+    /// Anyone can crank expiry
+    pub caller: Signer<'info>,
+}
+
+/// Expire a lapsed subscription
+/// Fails with SubscriptionNotExpired while the stream is still paid through
+pub fn handle_expire_subscription(ctx: Context<ExpireSubscription>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let (subscription_type, patron, stream_id) =
+        match (&ctx.accounts.patron_subscription, &ctx.accounts.ecosystem_subscription) {
+            (Some(sub), None) => ("patron", Some((sub.creator, sub.tier_index)), sub.stream_id),
+            (None, Some(sub)) => ("ecosystem", None, sub.stream_id),
+            _ => return Err(ContentRegistryError::InvalidSubscriptionTarget.into()),
+        };
+
+    let stream_info = &ctx.accounts.stream_metadata;
+    require!(stream_info.key() == stream_id, ContentRegistryError::InvalidStreamId);
+
+    let reason = match read_stream_contract(stream_info)? {
+        None => SubscriptionCancelReason::StreamClosed,
+        Some(contract) if contract.canceled_at > 0 => SubscriptionCancelReason::StreamCancelled,
+        Some(contract) => {
+            let paid_through = contract_paid_through(&contract);
+            require!(
+                paid_through.is_none_or(|paid_through| now >= paid_through),
+                ContentRegistryError::SubscriptionNotExpired
+            );
+            SubscriptionCancelReason::StreamDepleted
+        }
+    };
+
+    let subscriber = ctx.accounts.subscriber.to_account_info();
+    if let Some(sub) = &ctx.accounts.patron_subscription {
+        sub.close(subscriber)?;
+    } else if let Some(sub) = &ctx.accounts.ecosystem_subscription {
+        sub.close(subscriber)?;
+    }
+
+    emit!(SubscriptionCancelledEvent {
+        subscription_type: subscription_type.to_string(),
+        subscriber: ctx.accounts.subscriber.key(),
+        creator: patron.map(|(creator, _)| creator),
+        tier_index: patron.map(|(_, tier_index)| tier_index),
+        stream_id,
+        cancelled_at: now,
+        reason,
+    });
+
+    msg!("Subscription expired");
+    msg!("  Subscriber: {}", ctx.accounts.subscriber.key());
+    msg!("  Type: {}", subscription_type);
+    msg!("  Reason: {:?}", reason);
+
+    Ok(())
+}
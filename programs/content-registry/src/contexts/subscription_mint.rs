@@ -29,6 +29,7 @@ pub fn calculate_virtual_rps(
 /// Trigger epoch distribution for CreatorPatronPool if needed
 /// Returns the holder share that was distributed (12% of streaming treasury)
 /// Uses CPI transfers because streaming treasury is system-owned
+#[allow(clippy::too_many_arguments)]
 pub fn maybe_distribute_patron_pool<'info>(
     pool: &mut Account<'info, CreatorPatronPool>,
     streaming_treasury: &AccountInfo<'info>,
@@ -122,6 +123,7 @@ pub fn maybe_distribute_patron_pool<'info>(
 /// Trigger epoch distribution for ecosystem pools (GlobalHolderPool + CreatorDistPool)
 /// Returns (holder_share, creator_share) that were distributed
 /// Uses CPI transfers because streaming treasury is system-owned
+#[allow(clippy::too_many_arguments)]
 pub fn maybe_distribute_ecosystem_pools<'info>(
     holder_pool: &mut Account<'info, GlobalHolderPool>,
     dist_pool: &mut Account<'info, CreatorDistPool>,
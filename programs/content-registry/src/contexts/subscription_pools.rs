@@ -128,6 +128,7 @@ pub fn handle_initialize_ecosystem_sub_config(
     config.authority = ctx.accounts.admin.key();
     config.created_at = timestamp;
     config.updated_at = timestamp;
+    config.trial_duration = None;
 
     msg!("Ecosystem subscription config initialized. Price: {} lamports/month", price);
 
@@ -153,10 +154,12 @@ pub struct UpdateEcosystemSubConfig<'info> {
 }
 
 /// Handler for update_ecosystem_sub_config
+/// trial_duration: Some(None) stops offering trials
 pub fn handle_update_ecosystem_sub_config(
     ctx: Context<UpdateEcosystemSubConfig>,
     price: Option<u64>,
     is_active: Option<bool>,
+    trial_duration: Option<Option<i64>>,
 ) -> Result<()> {
     let config = &mut ctx.accounts.ecosystem_sub_config;
     let timestamp = Clock::get()?.unix_timestamp;
@@ -169,6 +172,11 @@ pub fn handle_update_ecosystem_sub_config(
         config.is_active = active;
     }
 
+    if let Some(trial_duration) = trial_duration {
+        validate_trial_duration(trial_duration)?;
+        config.trial_duration = trial_duration;
+    }
+
     config.updated_at = timestamp;
 
     Ok(())
@@ -0,0 +1,385 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::ContentRegistryError;
+use crate::events::{
+    SubscriptionVaultCancelledEvent, SubscriptionVaultOpenedEvent, SubscriptionVaultReleasedEvent,
+};
+
+// ============================================================================
+// OPEN PATRON VAULT
+// ============================================================================
+
+/// Prepay a creator subscription into a program-held vault (no Streamflow)
+/// Periods are released to the creator's patron treasury as they start
+#[derive(Accounts)]
+pub struct OpenPatronVault<'info> {
+    /// CHECK: Creator wallet address
+    pub creator: AccountInfo<'info>,
+
+    /// Creator's patron config (must be active)
+    #[account(
+        seeds = [CREATOR_PATRON_CONFIG_SEED, creator.key().as_ref()],
+        bump,
+        constraint = patron_config.is_active @ ContentRegistryError::PatronConfigInactive,
+        constraint = patron_config.creator == creator.key() @ ContentRegistryError::Unauthorized
+    )]
+    pub patron_config: Account<'info, CreatorPatronConfig>,
+
+    /// Vault holding the prepaid periods
+    #[account(
+        init,
+        payer = subscriber,
+        space = 8 + SubscriptionVault::INIT_SPACE,
+        seeds = [SUBSCRIPTION_VAULT_SEED, subscriber.key().as_ref(), creator.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, SubscriptionVault>,
+
+    /// Creator's patron treasury - receives released periods
+    /// CHECK: PDA verified by seeds
+    #[account(
+        mut,
+        seeds = [CREATOR_PATRON_TREASURY_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_treasury: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a patron vault at the given tier, prepaying `periods` months
+/// The first period starts now and is released immediately
+pub fn handle_open_patron_vault(ctx: Context<OpenPatronVault>, tier_index: u8, periods: u16) -> Result<()> {
+    let price = ctx.accounts.patron_config.get_tier(tier_index)?.price;
+    let creator = ctx.accounts.creator.key();
+
+    open_vault(
+        &mut ctx.accounts.vault,
+        &ctx.accounts.subscriber,
+        &ctx.accounts.creator_treasury,
+        &ctx.accounts.system_program,
+        creator,
+        Some(tier_index),
+        price,
+        periods,
+    )
+}
+
+// ============================================================================
+// OPEN ECOSYSTEM VAULT
+// ============================================================================
+
+/// Prepay the ecosystem subscription into a program-held vault (no Streamflow)
+/// Periods are released to the ecosystem streaming treasury as they start
+#[derive(Accounts)]
+pub struct OpenEcosystemVault<'info> {
+    /// Ecosystem subscription config (must be active)
+    #[account(
+        seeds = [ECOSYSTEM_SUB_CONFIG_SEED],
+        bump,
+        constraint = ecosystem_sub_config.is_active @ ContentRegistryError::EcosystemSubInactive
+    )]
+    pub ecosystem_sub_config: Account<'info, EcosystemSubConfig>,
+
+    /// Vault holding the prepaid periods
+    #[account(
+        init,
+        payer = subscriber,
+        space = 8 + SubscriptionVault::INIT_SPACE,
+        seeds = [SUBSCRIPTION_VAULT_SEED, subscriber.key().as_ref(), Pubkey::default().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, SubscriptionVault>,
+
+    /// Ecosystem streaming treasury - receives released periods
+    /// CHECK: PDA verified by seeds
+    #[account(
+        mut,
+        seeds = [ECOSYSTEM_STREAMING_TREASURY_SEED],
+        bump
+    )]
+    pub ecosystem_treasury: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open an ecosystem vault, prepaying `periods` months
+/// The first period starts now and is released immediately
+pub fn handle_open_ecosystem_vault(ctx: Context<OpenEcosystemVault>, periods: u16) -> Result<()> {
+    let price = ctx.accounts.ecosystem_sub_config.price;
+
+    open_vault(
+        &mut ctx.accounts.vault,
+        &ctx.accounts.subscriber,
+        &ctx.accounts.ecosystem_treasury,
+        &ctx.accounts.system_program,
+        Pubkey::default(),
+        None,
+        price,
+        periods,
+    )
+}
+
+/// Fund a new vault and release its first period
+#[allow(clippy::too_many_arguments)]
+fn open_vault<'info>(
+    vault: &mut Account<'info, SubscriptionVault>,
+    subscriber: &Signer<'info>,
+    treasury: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    scope: Pubkey,
+    tier_index: Option<u8>,
+    price_per_period: u64,
+    periods: u16,
+) -> Result<()> {
+    require!(
+        periods > 0 && periods <= MAX_SUBSCRIPTION_VAULT_PERIODS,
+        ContentRegistryError::InvalidVaultPeriods
+    );
+    let now = Clock::get()?.unix_timestamp;
+
+    vault.subscriber = subscriber.key();
+    vault.scope = scope;
+    vault.tier_index = tier_index;
+    vault.price_per_period = price_per_period;
+    vault.periods_paid = periods;
+    vault.periods_released = 0;
+    vault.started_at = now;
+    let deposit = vault.amount_for(periods)?;
+
+    system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: subscriber.to_account_info(),
+                to: vault.to_account_info(),
+            },
+        ),
+        deposit,
+    )?;
+
+    emit!(SubscriptionVaultOpenedEvent {
+        vault: vault.key(),
+        subscriber: subscriber.key(),
+        creator: (scope != Pubkey::default()).then_some(scope),
+        tier_index,
+        price_per_period,
+        periods,
+        started_at: now,
+    });
+
+    release_due_periods(vault, treasury, now)?;
+
+    msg!("Subscription vault opened");
+    msg!("  Subscriber: {}", subscriber.key());
+    msg!("  Deposit: {} lamports ({} periods)", deposit, periods);
+    msg!("  Paid through: {}", vault.paid_through());
+
+    Ok(())
+}
+
+// ============================================================================
+// RELEASE VAULT PERIODS - Permissionless crank
+// ============================================================================
+
+/// Release every started period to the treasury, and close the vault once it
+/// has nothing left to release and is no longer paid through
+#[derive(Accounts)]
+pub struct ReleaseSubscriptionVault<'info> {
+    #[account(
+        mut,
+        seeds = [SUBSCRIPTION_VAULT_SEED, vault.subscriber.as_ref(), vault.scope.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, SubscriptionVault>,
+
+    /// CHECK: Patron or ecosystem treasury - matched against the vault scope in handler
+    #[account(mut)]
+    pub treasury: AccountInfo<'info>,
+
+    /// CHECK: Subscriber receiving the rent refund when the vault closes
+    #[account(mut, address = vault.subscriber @ ContentRegistryError::Unauthorized)]
+    pub subscriber: AccountInfo<'info>,
+
+    /// Anyone can crank releases
+    pub caller: Signer<'info>,
+}
+
+/// Release due periods; fails with VaultNothingDue when there is nothing to do
+pub fn handle_release_subscription_vault(ctx: Context<ReleaseSubscriptionVault>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let vault = &mut ctx.accounts.vault;
+
+    let released = release_due_periods(vault, &ctx.accounts.treasury, now)?;
+    let finished = vault.is_finished(now);
+    require!(released > 0 || finished, ContentRegistryError::VaultNothingDue);
+
+    if finished {
+        vault.close(ctx.accounts.subscriber.to_account_info())?;
+        msg!("Subscription vault closed");
+        msg!("  Subscriber: {}", ctx.accounts.subscriber.key());
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// CANCEL VAULT - Refund unreleased periods
+// ============================================================================
+
+/// Cancel a vault subscription; started periods stay with the treasury
+#[derive(Accounts)]
+pub struct CancelSubscriptionVault<'info> {
+    #[account(
+        mut,
+        seeds = [SUBSCRIPTION_VAULT_SEED, subscriber.key().as_ref(), vault.scope.as_ref()],
+        bump,
+        constraint = vault.subscriber == subscriber.key() @ ContentRegistryError::Unauthorized
+    )]
+    pub vault: Account<'info, SubscriptionVault>,
+
+    /// CHECK: Patron or ecosystem treasury - matched against the vault scope in handler
+    #[account(mut)]
+    pub treasury: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+}
+
+/// Release started periods, refund the rest and stop the subscription at the
+/// end of the current period (the vault closes then via the release crank)
+pub fn handle_cancel_subscription_vault(ctx: Context<CancelSubscriptionVault>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let vault = &mut ctx.accounts.vault;
+
+    release_due_periods(vault, &ctx.accounts.treasury, now)?;
+
+    let (refunded_periods, refund) = vault.cancel_unreleased()?;
+    if refund > 0 {
+        **vault.to_account_info().try_borrow_mut_lamports()? -= refund;
+        **ctx.accounts.subscriber.to_account_info().try_borrow_mut_lamports()? += refund;
+    }
+
+    emit!(SubscriptionVaultCancelledEvent {
+        vault: vault.key(),
+        subscriber: ctx.accounts.subscriber.key(),
+        refunded_periods,
+        refund,
+        paid_through: vault.paid_through(),
+        timestamp: now,
+    });
+
+    msg!("Subscription vault cancelled");
+    msg!("  Subscriber: {}", ctx.accounts.subscriber.key());
+    msg!("  Refunded: {} lamports ({} periods)", refund, refunded_periods);
+    msg!("  Access until: {}", vault.paid_through());
+
+    Ok(())
+}
+
+// ============================================================================
+// VAULT HELPERS
+// ============================================================================
+
+/// Treasury a vault releases into: the creator's patron treasury, or the
+/// ecosystem streaming treasury - the same accounts Streamflow streams pay,
+/// so released periods flow through maybe_distribute_patron_pool /
+/// maybe_distribute_ecosystem_pools unchanged
+fn vault_treasury(scope: &Pubkey) -> Pubkey {
+    let (treasury, _) = if *scope == Pubkey::default() {
+        Pubkey::find_program_address(&[ECOSYSTEM_STREAMING_TREASURY_SEED], &crate::id())
+    } else {
+        Pubkey::find_program_address(&[CREATOR_PATRON_TREASURY_SEED, scope.as_ref()], &crate::id())
+    };
+    treasury
+}
+
+/// Move every started but unreleased period from the vault to its treasury
+/// Returns the lamports released
+fn release_due_periods(
+    vault: &mut Account<SubscriptionVault>,
+    treasury: &AccountInfo,
+    now: i64,
+) -> Result<u64> {
+    require!(
+        treasury.key() == vault_treasury(&vault.scope),
+        ContentRegistryError::InvalidVaultTreasury
+    );
+
+    let due = vault.periods_due(now);
+    if due == 0 {
+        return Ok(0);
+    }
+
+    let amount = vault.amount_for(due)?;
+    **vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **treasury.try_borrow_mut_lamports()? += amount;
+    vault.periods_released += due;
+
+    emit!(SubscriptionVaultReleasedEvent {
+        vault: vault.key(),
+        treasury: treasury.key(),
+        periods: due,
+        amount,
+        timestamp: now,
+    });
+
+    msg!("Released {} vault period(s): {} lamports", due, amount);
+
+    Ok(amount)
+}
+
+/// Read a subscription vault for (user, scope), if the account is that PDA
+fn load_subscription_vault(vault_info: &AccountInfo, user: &Pubkey, scope: &Pubkey) -> Result<Option<SubscriptionVault>> {
+    let (expected_pda, _bump) = Pubkey::find_program_address(
+        &[SUBSCRIPTION_VAULT_SEED, user.as_ref(), scope.as_ref()],
+        &crate::id(),
+    );
+    if vault_info.key() != expected_pda {
+        return Ok(None);
+    }
+
+    let data = vault_info.try_borrow_data()?;
+    if data.len() < 8 + SubscriptionVault::INIT_SPACE {
+        return Ok(None);
+    }
+    Ok(Some(SubscriptionVault::try_deserialize(&mut &data[..])?))
+}
+
+/// Paid-through time of the user's creator vault, if its tier unlocks `visibility`
+pub(crate) fn patron_vault_expiry(
+    vault_info: &AccountInfo,
+    patron_config: Option<&CreatorPatronConfig>,
+    user: &Pubkey,
+    creator: &Pubkey,
+    visibility: u8,
+    now: i64,
+) -> Result<Option<i64>> {
+    let Some(vault) = load_subscription_vault(vault_info, user, creator)? else {
+        return Ok(None);
+    };
+    let Some(config) = patron_config.filter(|config| config.creator == *creator) else {
+        return Ok(None);
+    };
+    let unlocks = vault
+        .tier_index
+        .and_then(|index| config.tiers.get(index as usize))
+        .is_some_and(|tier| tier.grants_visibility(visibility));
+
+    Ok((unlocks && vault.is_active(now)).then_some(vault.paid_through()))
+}
+
+/// Paid-through time of the user's ecosystem vault, if still paid for
+pub(crate) fn ecosystem_vault_expiry(vault_info: &AccountInfo, user: &Pubkey, now: i64) -> Result<Option<i64>> {
+    let Some(vault) = load_subscription_vault(vault_info, user, &Pubkey::default())? else {
+        return Ok(None);
+    };
+    Ok(vault.is_active(now).then_some(vault.paid_through()))
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, CloseAccount, Transfer as TokenTransfer, Mint};
+use anchor_spl::token::{self, Token, TokenAccount, CloseAccount, Mint};
 use streamflow_sdk::cpi::accounts::Withdraw as StreamflowWithdraw;
 use crate::state::*;
 use crate::errors::ContentRegistryError;
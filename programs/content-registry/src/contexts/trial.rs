@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ContentRegistryError;
+
+// ============================================================================
+// START PATRON TRIAL
+// ============================================================================
+
+/// Start a creator's free trial
+/// The TrialMarker is created with `init`, so each wallet gets one trial per creator
+#[derive(Accounts)]
+pub struct StartPatronTrial<'info> {
+    /// CHECK: Creator wallet address
+    pub creator: AccountInfo<'info>,
+
+    /// Creator's patron config (must be active and offer a trial)
+    #[account(
+        seeds = [CREATOR_PATRON_CONFIG_SEED, creator.key().as_ref()],
+        bump,
+        constraint = patron_config.is_active @ ContentRegistryError::PatronConfigInactive,
+        constraint = patron_config.creator == creator.key() @ ContentRegistryError::Unauthorized
+    )]
+    pub patron_config: Account<'info, CreatorPatronConfig>,
+
+    /// Trial-used marker for this wallet and creator
+    #[account(
+        init,
+        payer = subscriber,
+        space = 8 + TrialMarker::INIT_SPACE,
+        seeds = [TRIAL_MARKER_SEED, subscriber.key().as_ref(), creator.key().as_ref()],
+        bump
+    )]
+    pub trial_marker: Account<'info, TrialMarker>,
+
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Start a patron trial at the given tier
+pub fn handle_start_patron_trial(ctx: Context<StartPatronTrial>, tier_index: u8) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = &ctx.accounts.patron_config;
+    let trial_duration = config.trial_duration.ok_or(ContentRegistryError::TrialNotOffered)?;
+    config.get_tier(tier_index)?;
+
+    let marker = &mut ctx.accounts.trial_marker;
+    marker.wallet = ctx.accounts.subscriber.key();
+    marker.scope = ctx.accounts.creator.key();
+    marker.tier_index = Some(tier_index);
+    marker.started_at = now;
+    marker.expires_at = now + trial_duration;
+
+    msg!("Patron trial started");
+    msg!("  Subscriber: {}", ctx.accounts.subscriber.key());
+    msg!("  Creator: {}", ctx.accounts.creator.key());
+    msg!("  Tier: {}", tier_index);
+    msg!("  Expires at: {}", marker.expires_at);
+
+    Ok(())
+}
+
+// ============================================================================
+// START ECOSYSTEM TRIAL
+// ============================================================================
+
+/// Start the ecosystem free trial
+/// The TrialMarker is created with `init`, so each wallet gets one ecosystem trial
+#[derive(Accounts)]
+pub struct StartEcosystemTrial<'info> {
+    /// Ecosystem subscription config (must be active and offer a trial)
+    #[account(
+        seeds = [ECOSYSTEM_SUB_CONFIG_SEED],
+        bump,
+        constraint = ecosystem_sub_config.is_active @ ContentRegistryError::EcosystemSubInactive
+    )]
+    pub ecosystem_sub_config: Account<'info, EcosystemSubConfig>,
+
+    /// Trial-used marker for this wallet and the ecosystem
+    #[account(
+        init,
+        payer = subscriber,
+        space = 8 + TrialMarker::INIT_SPACE,
+        seeds = [TRIAL_MARKER_SEED, subscriber.key().as_ref(), Pubkey::default().as_ref()],
+        bump
+    )]
+    pub trial_marker: Account<'info, TrialMarker>,
+
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Start the ecosystem trial
+pub fn handle_start_ecosystem_trial(ctx: Context<StartEcosystemTrial>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let trial_duration = ctx
+        .accounts
+        .ecosystem_sub_config
+        .trial_duration
+        .ok_or(ContentRegistryError::TrialNotOffered)?;
+
+    let marker = &mut ctx.accounts.trial_marker;
+    marker.wallet = ctx.accounts.subscriber.key();
+    marker.scope = Pubkey::default();
+    marker.tier_index = None;
+    marker.started_at = now;
+    marker.expires_at = now + trial_duration;
+
+    msg!("Ecosystem trial started");
+    msg!("  Subscriber: {}", ctx.accounts.subscriber.key());
+    msg!("  Expires at: {}", marker.expires_at);
+
+    Ok(())
+}
+
+// ============================================================================
+// TRIAL ACCESS HELPERS
+// ============================================================================
+
+/// Read a trial marker for (user, scope), if the account is that PDA
+fn load_trial_marker(trial_info: &AccountInfo, user: &Pubkey, scope: &Pubkey) -> Result<Option<TrialMarker>> {
+    let (expected_pda, _bump) = Pubkey::find_program_address(
+        &[TRIAL_MARKER_SEED, user.as_ref(), scope.as_ref()],
+        &crate::id(),
+    );
+    if trial_info.key() != expected_pda {
+        return Ok(None);
+    }
+
+    let data = trial_info.try_borrow_data()?;
+    if data.len() < 8 + TrialMarker::INIT_SPACE {
+        return Ok(None);
+    }
+    Ok(Some(TrialMarker::try_deserialize(&mut &data[..])?))
+}
+
+/// Expiry of the user's creator trial, if its tier unlocks `visibility`
+pub(crate) fn patron_trial_expiry(
+    trial_info: &AccountInfo,
+    patron_config: Option<&CreatorPatronConfig>,
+    user: &Pubkey,
+    creator: &Pubkey,
+    visibility: u8,
+    now: i64,
+) -> Result<Option<i64>> {
+    let Some(marker) = load_trial_marker(trial_info, user, creator)? else {
+        return Ok(None);
+    };
+    let Some(config) = patron_config.filter(|config| config.creator == *creator) else {
+        return Ok(None);
+    };
+    let unlocks = marker
+        .tier_index
+        .and_then(|index| config.tiers.get(index as usize))
+        .is_some_and(|tier| tier.grants_visibility(visibility));
+
+    Ok((unlocks && marker.is_active(now)).then_some(marker.expires_at))
+}
+
+/// Expiry of the user's ecosystem trial, if still running
+pub(crate) fn ecosystem_trial_expiry(trial_info: &AccountInfo, user: &Pubkey, now: i64) -> Result<Option<i64>> {
+    let Some(marker) = load_trial_marker(trial_info, user, &Pubkey::default())? else {
+        return Ok(None);
+    };
+    Ok(marker.is_active(now).then_some(marker.expires_at))
+}
@@ -0,0 +1,340 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer as TokenTransfer};
+use crate::state::*;
+use crate::errors::ContentRegistryError;
+
+// ============================================================================
+// USDC PAYMENTS
+// ============================================================================
+// Items whose MintConfig.currency is Usdc are paid with SPL token transfers
+// instead of system transfers. The 80/5/3/12 primary split is the same:
+// - Creator, platform and ecosystem treasury receive USDC in their token accounts
+// - Holder rewards go to the reward pool's USDC ATA (owned by the pool PDA)
+//   and are tracked with a separate usdc_reward_per_share on the pool
+//
+// SOL royalties and rentals keep using the lamport side of the same pools.
+
+/// Validate an optional USDC token account passed to a mint instruction
+/// Must be present, hold the ecosystem USDC mint, and be owned by `expected_owner`
+pub fn require_usdc_account<'a, 'info>(
+    account: &'a Option<Box<Account<'info, TokenAccount>>>,
+    usdc_mint: &Pubkey,
+    expected_owner: &Pubkey,
+) -> Result<&'a Account<'info, TokenAccount>> {
+    let account = account.as_ref().ok_or(ContentRegistryError::UsdcAccountsRequired)?;
+    require!(account.mint == *usdc_mint, ContentRegistryError::InvalidUsdcMint);
+    require!(account.owner == *expected_owner, ContentRegistryError::InvalidOwner);
+    Ok(account)
+}
+
+/// Validate an optional reward pool USDC vault (must be the pool PDA's USDC ATA)
+pub fn require_usdc_vault<'a, 'info>(
+    account: &'a Option<Box<Account<'info, TokenAccount>>>,
+    usdc_mint: &Pubkey,
+    pool: &Pubkey,
+) -> Result<&'a Account<'info, TokenAccount>> {
+    let account = require_usdc_account(account, usdc_mint, pool)?;
+    require!(
+        account.key() == get_associated_token_address(pool, usdc_mint),
+        ContentRegistryError::InvalidOwner
+    );
+    Ok(account)
+}
+
+/// Transfer USDC from a token account owned by `authority`
+/// Pass empty signer_seeds when the authority is a transaction signer
+pub fn transfer_usdc<'info>(
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TokenTransfer {
+                from: from.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+/// Distribute a bundle mint's USDC content share to ContentRewardPools by weight
+/// remaining_accounts layout: [bundle_item_1, content_reward_pool_1, pool_1_usdc_ata, bundle_item_2, ...]
+/// Each pool must belong to content in `bundle`, proven by its ["bundle_item", bundle, content] PDA
+/// Returns the amount actually distributed
+pub fn distribute_usdc_to_content_pools<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    bundle: &Pubkey,
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    usdc_mint: &Pubkey,
+    amount: u64,
+) -> Result<u64> {
+    require!(remaining_accounts.len().is_multiple_of(3), ContentRegistryError::InvalidAccountPairs);
+
+    // First pass: load pools, verify bundle membership and vaults, sum weights
+    let mut pools: Vec<(Account<'info, ContentRewardPool>, &'info AccountInfo<'info>)> =
+        Vec::with_capacity(remaining_accounts.len() / 3);
+    let mut total_combined_weight: u64 = 0;
+
+    for triple in remaining_accounts.chunks(3) {
+        let bundle_item = Account::<BundleItem>::try_from(&triple[0])?;
+        let pool = Account::<ContentRewardPool>::try_from(&triple[1])?;
+        let (expected_item, _) = Pubkey::find_program_address(
+            &[BUNDLE_ITEM_SEED, bundle.as_ref(), pool.content.as_ref()],
+            &crate::ID,
+        );
+        require!(
+            triple[0].key() == expected_item && bundle_item.bundle == *bundle,
+            ContentRegistryError::BundleMismatch
+        );
+        // Each pool once, so a buyer can't weight the split towards one item
+        require!(
+            pools.iter().all(|(p, _)| p.key() != pool.key()),
+            ContentRegistryError::InvalidAccountPairs
+        );
+        require!(
+            triple[2].key() == get_associated_token_address(&pool.key(), usdc_mint),
+            ContentRegistryError::InvalidOwner
+        );
+        total_combined_weight += pool.total_weight;
+        pools.push((pool, &triple[2]));
+    }
+
+    if total_combined_weight == 0 {
+        return Ok(0);
+    }
+
+    // Second pass: transfer by weight proportion and bump usdc_reward_per_share
+    let mut distributed: u64 = 0;
+    for (mut pool, vault_info) in pools {
+        if pool.total_weight == 0 {
+            continue;
+        }
+        let pool_share = (amount as u128 * pool.total_weight as u128 / total_combined_weight as u128) as u64;
+        if pool_share == 0 {
+            continue;
+        }
+
+        transfer_usdc(token_program, from, vault_info, authority, pool_share, &[])?;
+        pool.add_usdc_rewards(pool_share);
+        pool.exit(&crate::ID)?;
+        distributed += pool_share;
+    }
+
+    Ok(distributed)
+}
+
+// ============================================================================
+// CLAIM USDC CONTENT REWARDS
+// ============================================================================
+
+/// Claim USDC holder rewards from a ContentRewardPool's USDC vault
+#[derive(Accounts)]
+pub struct ClaimUnifiedContentUsdcRewards<'info> {
+    #[account(
+        seeds = [ECOSYSTEM_CONFIG_SEED],
+        bump
+    )]
+    pub ecosystem_config: Box<Account<'info, EcosystemConfig>>,
+
+    /// The content this NFT belongs to
+    pub content: Box<Account<'info, ContentEntry>>,
+
+    /// ContentRewardPool - tracks USDC reward per share
+    #[account(
+        mut,
+        seeds = [CONTENT_REWARD_POOL_SEED, content.key().as_ref()],
+        bump
+    )]
+    pub content_reward_pool: Box<Account<'info, ContentRewardPool>>,
+
+    /// Pool's USDC ATA holding holder rewards
+    #[account(
+        mut,
+        constraint = content_reward_pool_usdc.key() == get_associated_token_address(
+            &content_reward_pool.key(),
+            &ecosystem_config.usdc_mint
+        ) @ ContentRegistryError::InvalidOwner
+    )]
+    pub content_reward_pool_usdc: Box<Account<'info, TokenAccount>>,
+
+    /// UnifiedNftRewardState for this NFT
+    #[account(
+        mut,
+        seeds = [UNIFIED_NFT_REWARD_STATE_SEED, nft_asset.key().as_ref()],
+        bump,
+        constraint = nft_reward_state.content_or_bundle == content.key() @ ContentRegistryError::ContentMismatch,
+        constraint = !nft_reward_state.is_bundle @ ContentRegistryError::InvalidNftType
+    )]
+    pub nft_reward_state: Box<Account<'info, UnifiedNftRewardState>>,
+
+    /// The NFT asset (Metaplex Core)
+    /// CHECK: Ownership and collection verified in handler
+    pub nft_asset: AccountInfo<'info>,
+
+    /// The NFT holder (must be signer to claim)
+    pub holder: Signer<'info>,
+
+    /// Holder's USDC token account
+    #[account(
+        mut,
+        constraint = holder_usdc.owner == holder.key() @ ContentRegistryError::InvalidOwner,
+        constraint = holder_usdc.mint == ecosystem_config.usdc_mint @ ContentRegistryError::InvalidUsdcMint
+    )]
+    pub holder_usdc: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Handler for claim_unified_content_usdc_rewards
+pub fn handle_claim_unified_content_usdc_rewards(ctx: Context<ClaimUnifiedContentUsdcRewards>) -> Result<()> {
+    require!(
+        crate::verify_core_nft_ownership(
+            &ctx.accounts.nft_asset,
+            &ctx.accounts.holder.key(),
+            &ctx.accounts.content.collection_asset,
+        )?,
+        ContentRegistryError::NotNftOwner
+    );
+
+    let usdc_rps = ctx.accounts.content_reward_pool.usdc_reward_per_share;
+    let pending = ctx.accounts.nft_reward_state.pending_usdc_reward(usdc_rps);
+    require!(pending > 0, ContentRegistryError::NothingToClaim);
+
+    let content_key = ctx.accounts.content.key();
+    let pool_seeds: &[&[u8]] = &[
+        CONTENT_REWARD_POOL_SEED,
+        content_key.as_ref(),
+        &[ctx.bumps.content_reward_pool],
+    ];
+
+    transfer_usdc(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.content_reward_pool_usdc.to_account_info(),
+        &ctx.accounts.holder_usdc.to_account_info(),
+        &ctx.accounts.content_reward_pool.to_account_info(),
+        pending,
+        &[pool_seeds],
+    )?;
+
+    ctx.accounts.content_reward_pool.usdc_total_claimed += pending;
+    ctx.accounts.nft_reward_state.update_usdc_debt(usdc_rps);
+
+    msg!("Claimed {} USDC base units from content reward pool", pending);
+
+    Ok(())
+}
+
+// ============================================================================
+// CLAIM USDC BUNDLE REWARDS
+// ============================================================================
+
+/// Claim USDC holder rewards from a bundle RewardPool's USDC vault
+#[derive(Accounts)]
+pub struct ClaimUnifiedBundleUsdcRewards<'info> {
+    #[account(
+        seeds = [ECOSYSTEM_CONFIG_SEED],
+        bump
+    )]
+    pub ecosystem_config: Box<Account<'info, EcosystemConfig>>,
+
+    /// The bundle this NFT belongs to
+    pub bundle: Box<Account<'info, Bundle>>,
+
+    /// RewardPool - tracks USDC reward per share
+    #[account(
+        mut,
+        seeds = [REWARD_POOL_SEED, bundle.key().as_ref()],
+        bump
+    )]
+    pub reward_pool: Box<Account<'info, RewardPool>>,
+
+    /// Pool's USDC ATA holding holder rewards
+    #[account(
+        mut,
+        constraint = reward_pool_usdc.key() == get_associated_token_address(
+            &reward_pool.key(),
+            &ecosystem_config.usdc_mint
+        ) @ ContentRegistryError::InvalidOwner
+    )]
+    pub reward_pool_usdc: Box<Account<'info, TokenAccount>>,
+
+    /// UnifiedNftRewardState for this NFT
+    #[account(
+        mut,
+        seeds = [UNIFIED_NFT_REWARD_STATE_SEED, nft_asset.key().as_ref()],
+        bump,
+        constraint = nft_reward_state.content_or_bundle == bundle.key() @ ContentRegistryError::ContentMismatch,
+        constraint = nft_reward_state.is_bundle @ ContentRegistryError::InvalidNftType
+    )]
+    pub nft_reward_state: Box<Account<'info, UnifiedNftRewardState>>,
+
+    /// The NFT asset (Metaplex Core)
+    /// CHECK: Ownership and collection verified in handler
+    pub nft_asset: AccountInfo<'info>,
+
+    /// The NFT holder (must be signer to claim)
+    pub holder: Signer<'info>,
+
+    /// Holder's USDC token account
+    #[account(
+        mut,
+        constraint = holder_usdc.owner == holder.key() @ ContentRegistryError::InvalidOwner,
+        constraint = holder_usdc.mint == ecosystem_config.usdc_mint @ ContentRegistryError::InvalidUsdcMint
+    )]
+    pub holder_usdc: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Handler for claim_unified_bundle_usdc_rewards
+pub fn handle_claim_unified_bundle_usdc_rewards(ctx: Context<ClaimUnifiedBundleUsdcRewards>) -> Result<()> {
+    require!(
+        crate::verify_core_nft_ownership(
+            &ctx.accounts.nft_asset,
+            &ctx.accounts.holder.key(),
+            &ctx.accounts.bundle.collection_asset,
+        )?,
+        ContentRegistryError::NotNftOwner
+    );
+
+    let usdc_rps = ctx.accounts.reward_pool.usdc_reward_per_share;
+    let pending = ctx.accounts.nft_reward_state.pending_usdc_reward(usdc_rps);
+    require!(pending > 0, ContentRegistryError::NothingToClaim);
+
+    let bundle_key = ctx.accounts.bundle.key();
+    let pool_seeds: &[&[u8]] = &[
+        REWARD_POOL_SEED,
+        bundle_key.as_ref(),
+        &[ctx.bumps.reward_pool],
+    ];
+
+    transfer_usdc(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.reward_pool_usdc.to_account_info(),
+        &ctx.accounts.holder_usdc.to_account_info(),
+        &ctx.accounts.reward_pool.to_account_info(),
+        pending,
+        &[pool_seeds],
+    )?;
+
+    ctx.accounts.reward_pool.usdc_total_claimed += pending;
+    ctx.accounts.nft_reward_state.update_usdc_debt(usdc_rps);
+
+    msg!("Claimed {} USDC base units from bundle reward pool", pending);
+
+    Ok(())
+}
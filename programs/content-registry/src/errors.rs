@@ -38,6 +38,24 @@ pub enum ContentRegistryError {
     InvalidCurrency,
     #[msg("Cannot increase supply after minting has started")]
     CannotIncreaseSupply,
+    #[msg("Minting is outside the active mint phase")]
+    MintPhaseNotActive,
+    #[msg("Invalid mint phase configuration")]
+    InvalidMintPhase,
+    #[msg("Wallet is not on the allowlist")]
+    InvalidAllowlistProof,
+    #[msg("Allowlist mint limit reached for this wallet")]
+    AllowlistLimitReached,
+    #[msg("Wallet mint record account required")]
+    WalletMintRecordRequired,
+    #[msg("Mint limit reached for this wallet")]
+    WalletMintLimitReached,
+    #[msg("Max per wallet must be greater than 0")]
+    InvalidMaxPerWallet,
+    #[msg("Current mint price exceeds max_price")]
+    PriceExceedsMax,
+    #[msg("Invalid pricing mode parameters")]
+    InvalidPricingMode,
     #[msg("Cannot set supply below already minted count")]
     SupplyBelowMinted,
     #[msg("Ecosystem is paused")]
@@ -46,6 +64,10 @@ pub enum ContentRegistryError {
     EcosystemAlreadyInitialized,
     #[msg("Invalid USDC mint address")]
     InvalidUsdcMint,
+    #[msg("USDC token accounts are required for USDC-priced items")]
+    UsdcAccountsRequired,
+    #[msg("Claim pending USDC rewards before burning")]
+    UnclaimedUsdcRewards,
 
     // Holder reward claim errors
     #[msg("NFT asset does not match the reward state")]
@@ -142,6 +164,14 @@ pub enum ContentRegistryError {
     PendingMintNotFound,
     #[msg("Invalid NftRarity PDA")]
     InvalidNftRarity,
+    #[msg("Rarity probabilities must sum to 10000 bps and every weight must be at least 1")]
+    InvalidRarityTable,
+    #[msg("Rarity quota totals must sum to max_supply")]
+    InvalidRarityQuota,
+    #[msg("Rarity quota exhausted")]
+    RarityQuotaExhausted,
+    #[msg("Random rarity requires request_mint unless the creator enabled instant rarity")]
+    CommitRevealRequired,
     #[msg("Cannot cancel pending mint yet - must wait at least 10 minutes")]
     CancelTooEarly,
     #[msg("Randomness already fulfilled for this request")]
@@ -170,8 +200,10 @@ pub enum ContentRegistryError {
     // Subscription system errors
     #[msg("Invalid NFT type - expected content NFT but got bundle NFT or vice versa")]
     InvalidNftType,
-    #[msg("Invalid patron config - at least one tier must be enabled")]
+    #[msg("Invalid patron config - list 1-8 named tiers with visibility up to 2 and holder share up to 10000 bps, at least one priced")]
     InvalidPatronConfig,
+    #[msg("Existing patron tiers can only change price - append new tiers instead")]
+    PatronTierLocked,
     #[msg("Patron config is not active")]
     PatronConfigInactive,
     #[msg("Selected tier is not available")]
@@ -194,7 +226,7 @@ pub enum ContentRegistryError {
     InvalidDurationType,
     #[msg("Invalid stream ID - does not match subscription record")]
     InvalidStreamId,
-    #[msg("Invalid tier - must be 0 (membership) or 1 (subscription)")]
+    #[msg("Invalid tier index")]
     InvalidTier,
 
     // Profile errors
@@ -210,4 +242,118 @@ pub enum ContentRegistryError {
     InvalidOwner,
     #[msg("Invalid token mint - expected WSOL")]
     InvalidMint,
+
+    // Auction errors
+    #[msg("Only unminted 1-of-1 content priced in SOL can be auctioned")]
+    NotAuctionable,
+    #[msg("Invalid auction parameters")]
+    InvalidAuctionParams,
+    #[msg("Auction is not open for bids")]
+    AuctionNotOpen,
+    #[msg("Bid is below the minimum next bid")]
+    BidTooLow,
+    #[msg("Auction has not ended yet")]
+    AuctionNotEnded,
+    #[msg("Account does not match the auction's highest bidder")]
+    InvalidBidder,
+    #[msg("Auction randomness is already committed")]
+    RandomnessAlreadyCommitted,
+    #[msg("Max supply can't change while editions are reserved by an auction or mint request")]
+    SupplyReserved,
+
+    // Marketplace errors
+    #[msg("Reward pool for the NFT's content or bundle is required")]
+    InvalidRewardPool,
+    #[msg("Offer must target exactly one content or bundle")]
+    InvalidOfferTarget,
+    #[msg("NFT rarity is below the offer's minimum")]
+    RarityBelowMinimum,
+
+    // Rental extension errors
+    #[msg("Rental attributes are immutable - rental cannot be extended")]
+    RentalNotExtendable,
+    #[msg("Rental has not expired yet")]
+    RentalNotExpired,
+    #[msg("Too many rentals in one reap batch")]
+    ReapBatchTooLarge,
+
+    // Rental option errors
+    #[msg("Rent options must list between 1 and 8 durations")]
+    InvalidRentOptions,
+    #[msg("Rent option index out of range")]
+    InvalidRentOption,
+
+    // Concurrent rental errors
+    #[msg("All rental slots are in use")]
+    RentalCapacityReached,
+    #[msg("Max concurrent rentals must be at least 1")]
+    InvalidMaxConcurrentRentals,
+    #[msg("Rent config required to free the rental's slot")]
+    RentConfigRequired,
+    #[msg("Rental does not hold a slot")]
+    RentalSlotNotHeld,
+
+    // Rent-to-own errors
+    #[msg("Rent-to-own credit must be between 1 and 10000 basis points")]
+    InvalidRentToOwnBps,
+    #[msg("Rental credit account is required while rent-to-own is enabled")]
+    RentalCreditRequired,
+
+    // Access grant errors
+    #[msg("No valid NFT, rental or subscription grants access to this content")]
+    AccessDenied,
+    #[msg("Access grant has not expired yet")]
+    AccessGrantNotExpired,
+
+    // Stream validation errors
+    #[msg("Stream account is not a valid Streamflow contract")]
+    InvalidStreamMetadata,
+    #[msg("Stream sender is not the subscriber")]
+    StreamSenderMismatch,
+    #[msg("Stream recipient is not the expected treasury")]
+    StreamRecipientMismatch,
+    #[msg("Stream mint is not WSOL")]
+    StreamMintMismatch,
+    #[msg("Stream rate does not match the subscription price")]
+    StreamRateMismatch,
+    #[msg("Stream is cancelled, closed or unfunded")]
+    StreamNotActive,
+
+    // Subscription expiry errors
+    #[msg("Pass exactly one of patron_subscription or ecosystem_subscription")]
+    InvalidSubscriptionTarget,
+    #[msg("Subscription stream is still paid through")]
+    SubscriptionNotExpired,
+
+    // Patron tier change errors
+    #[msg("Subscription is already on this tier")]
+    TierUnchanged,
+    #[msg("Unstreamed balance is too small to fund the new tier")]
+    InsufficientTierCredit,
+
+    // Free trial errors
+    #[msg("Trial duration must be between 1 second and 30 days")]
+    InvalidTrialDuration,
+    #[msg("No free trial is offered")]
+    TrialNotOffered,
+
+    // Subscription vault errors
+    #[msg("Subscription vault must prepay between 1 and 12 periods")]
+    InvalidVaultPeriods,
+    #[msg("Treasury does not match the subscription vault")]
+    InvalidVaultTreasury,
+    #[msg("No vault period is due and the vault is still paid through")]
+    VaultNothingDue,
+    #[msg("Vault amount overflows")]
+    VaultAmountOverflow,
+
+    // Migration errors
+    #[msg("Account already has the current layout")]
+    AlreadyMigrated,
+
+    // Gifted subscription errors
+    #[msg("Subscription record is still active and paid through")]
+    SubscriptionStillActive,
+    #[msg("Gifted subscription streams can't be cancelled or changed by the recipient")]
+    GiftedSubscriptionLocked,
 }
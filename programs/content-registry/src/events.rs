@@ -206,7 +206,7 @@ pub struct RewardTransferEvent {
 /// Emitted when a subscription is created (patron or ecosystem)
 #[event]
 pub struct SubscriptionCreatedEvent {
-    /// Subscription type: "patron_membership", "patron_subscription", "ecosystem"
+    /// Subscription type: "patron" or "ecosystem"
     pub subscription_type: String,
     /// Subscriber wallet
     pub subscriber: Pubkey,
@@ -220,17 +220,189 @@ pub struct SubscriptionCreatedEvent {
     pub started_at: i64,
 }
 
+/// Why a subscription record was cancelled
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SubscriptionCancelReason {
+    /// Stream ran out of deposited funds
+    StreamDepleted,
+    /// Stream was cancelled through Streamflow
+    StreamCancelled,
+    /// Stream account no longer exists
+    StreamClosed,
+}
+
 /// Emitted when a subscription is cancelled
 #[event]
 pub struct SubscriptionCancelledEvent {
-    /// Subscription type: "patron_membership", "patron_subscription", "ecosystem"
+    /// Subscription type: "patron" or "ecosystem"
     pub subscription_type: String,
     /// Subscriber wallet
     pub subscriber: Pubkey,
     /// Creator (for patron subscriptions)
     pub creator: Option<Pubkey>,
+    /// Patron tier index (for patron subscriptions)
+    pub tier_index: Option<u8>,
     /// Streamflow stream ID
     pub stream_id: Pubkey,
     /// Cancelled timestamp
     pub cancelled_at: i64,
+    /// Why the subscription ended
+    pub reason: SubscriptionCancelReason,
+}
+
+/// Emitted when a patron moves to another tier via change_patron_tier
+#[event]
+pub struct PatronTierChangedEvent {
+    /// Subscriber wallet
+    pub subscriber: Pubkey,
+    /// Creator subscribed to
+    pub creator: Pubkey,
+    /// Tier index before the change
+    pub old_tier_index: u8,
+    /// Tier index after the change
+    pub new_tier_index: u8,
+    /// Cancelled Streamflow stream
+    pub old_stream_id: Pubkey,
+    /// Replacement Streamflow stream
+    pub new_stream_id: Pubkey,
+    /// Unstreamed balance carried into the new stream (lamports)
+    pub credited_amount: u64,
+    /// Timestamp of the change
+    pub timestamp: i64,
+}
+
+/// Emitted when a subscriber prepays periods into a subscription vault
+#[event]
+pub struct SubscriptionVaultOpenedEvent {
+    /// Subscription vault PDA
+    pub vault: Pubkey,
+    /// Subscriber wallet
+    pub subscriber: Pubkey,
+    /// Creator (for patron vaults)
+    pub creator: Option<Pubkey>,
+    /// Patron tier index (for patron vaults)
+    pub tier_index: Option<u8>,
+    /// Lamports per period
+    pub price_per_period: u64,
+    /// Periods prepaid
+    pub periods: u16,
+    /// Started timestamp
+    pub started_at: i64,
+}
+
+/// Emitted when vault periods are released to the patron or ecosystem treasury
+#[event]
+pub struct SubscriptionVaultReleasedEvent {
+    /// Subscription vault PDA
+    pub vault: Pubkey,
+    /// Treasury receiving the periods
+    pub treasury: Pubkey,
+    /// Periods released by this call
+    pub periods: u16,
+    /// Lamports released by this call
+    pub amount: u64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when a subscriber cancels a subscription vault
+#[event]
+pub struct SubscriptionVaultCancelledEvent {
+    /// Subscription vault PDA
+    pub vault: Pubkey,
+    /// Subscriber wallet
+    pub subscriber: Pubkey,
+    /// Periods refunded
+    pub refunded_periods: u16,
+    /// Lamports refunded to the subscriber
+    pub refund: u64,
+    /// Access continues until the end of the last released period
+    pub paid_through: i64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when a bid is accepted on a 1-of-1 auction
+#[event]
+pub struct AuctionBidEvent {
+    /// Auction PDA
+    pub auction: Pubkey,
+    /// Content being auctioned
+    pub content: Pubkey,
+    /// New highest bidder
+    pub bidder: Pubkey,
+    /// Bid amount (lamports)
+    pub amount: u64,
+    /// Auction end after any anti-sniping extension
+    pub end_at: i64,
+    /// Timestamp of the bid
+    pub timestamp: i64,
+}
+
+/// Emitted when an NFT sells on the native secondary marketplace
+/// Amounts are the exact calculate_secondary_split payouts
+#[event]
+pub struct SecondarySaleEvent {
+    /// NFT asset sold
+    pub nft_asset: Pubkey,
+    /// Content or bundle the NFT belongs to
+    pub content_or_bundle: Pubkey,
+    /// Previous owner
+    pub seller: Pubkey,
+    /// New owner
+    pub buyer: Pubkey,
+    /// Sale price (lamports)
+    pub price: u64,
+    /// Creator royalty paid
+    pub creator_royalty: u64,
+    /// Platform fee paid
+    pub platform_fee: u64,
+    /// Ecosystem fee paid
+    pub ecosystem_fee: u64,
+    /// Deposited into the holder reward pool
+    pub holder_reward: u64,
+    /// Paid to the seller
+    pub seller_amount: u64,
+    /// Seller's accrued holder rewards settled at sale
+    pub seller_rewards_settled: u64,
+    /// Seller's accrued USDC holder rewards settled at sale (base units)
+    pub seller_usdc_rewards_settled: u64,
+    /// Timestamp of sale
+    pub timestamp: i64,
+}
+
+/// What was gifted
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GiftType {
+    /// Content edition NFT
+    ContentNft,
+    /// Bundle edition NFT
+    BundleNft,
+    /// Content rental NFT
+    ContentRental,
+    /// Bundle rental NFT
+    BundleRental,
+    /// Creator (patron) subscription
+    PatronSubscription,
+    /// Ecosystem subscription
+    EcosystemSubscription,
+}
+
+/// Emitted when a payer buys an NFT, rental or subscription for another wallet
+#[event]
+pub struct GiftEvent {
+    /// Wallet that paid
+    pub payer: Pubkey,
+    /// Wallet that received the NFT or subscription
+    pub recipient: Pubkey,
+    /// What was gifted
+    pub gift_type: GiftType,
+    /// Content, bundle or creator gifted (default pubkey for the ecosystem)
+    pub item: Pubkey,
+    /// NFT asset or subscription record
+    pub asset: Pubkey,
+    /// Optional gift message stored off-chain
+    pub message_cid: Option<String>,
+    /// Timestamp of the gift
+    pub timestamp: i64,
 }
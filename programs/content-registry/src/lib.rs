@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_lang::Discriminator;
 
 // Import mpl-core types and CPI builders
-use mpl_core::instructions::{CreateCollectionV2CpiBuilder, CreateV2CpiBuilder, AddPluginV1CpiBuilder, BurnV1CpiBuilder};
+use mpl_core::instructions::{CreateCollectionV2CpiBuilder, CreateV2CpiBuilder};
 use mpl_core::types::{
     DataState, Plugin, PluginAuthorityPair, PluginAuthority,
     Royalties, Creator, RuleSet, FreezeDelegate, PermanentBurnDelegate,
@@ -28,6 +28,7 @@ pub const DEFAULT_MAX_SUPPLY: u64 = 999_999;
 /// - Platform: 1%
 /// - Ecosystem (Treasury): 1%
 /// - Holder Reward Pool: 4%
+#[allow(clippy::too_many_arguments)]
 fn create_collection<'info>(
     mpl_core_program: &AccountInfo<'info>,
     collection: &AccountInfo<'info>,
@@ -202,45 +203,6 @@ fn verify_core_nft_ownership(
     Ok(true)
 }
 
-/// Create a Metaplex Core NFT within a collection
-/// The content_collection PDA is the update_authority and must sign
-/// Adds PermanentBurnDelegate plugin so only our program can burn NFTs
-fn create_core_nft<'info>(
-    mpl_core_program: &AccountInfo<'info>,
-    asset: &AccountInfo<'info>,
-    collection: &AccountInfo<'info>,
-    authority: &AccountInfo<'info>,  // content_collection PDA
-    payer: &AccountInfo<'info>,
-    owner: &AccountInfo<'info>,
-    system_program: &AccountInfo<'info>,
-    name: String,
-    uri: String,
-    signer_seeds: &[&[&[u8]]],
-) -> Result<()> {
-    // Create PermanentBurnDelegate plugin with content_collection PDA as authority
-    // This ensures only our program can burn NFTs (through the burn_nft instruction)
-    let burn_delegate_plugin = PluginAuthorityPair {
-        plugin: Plugin::PermanentBurnDelegate(PermanentBurnDelegate {}),
-        authority: Some(PluginAuthority::Address { address: authority.key() }),
-    };
-
-    // Create NFT within the collection with PDA signing and PermanentBurnDelegate
-    CreateV2CpiBuilder::new(mpl_core_program)
-        .asset(asset)
-        .collection(Some(collection))
-        .authority(Some(authority))
-        .payer(payer)
-        .owner(Some(owner))
-        .system_program(system_program)
-        .name(name)
-        .uri(uri)
-        .data_state(DataState::AccountState)
-        .plugins(vec![burn_delegate_plugin])
-        .invoke_signed(signer_seeds)?;
-
-    Ok(())
-}
-
 pub mod state;
 pub mod errors;
 pub mod contexts;
@@ -249,18 +211,20 @@ pub mod utils;
 
 use state::{
     ItemType,
-    MintConfig, PaymentCurrency, MINT_CONFIG_SEED,
+    MintConfig, PaymentCurrency, MINT_CONFIG_SEED, RarityQuota, RarityTable, AllowlistPhase, PricingMode, MintSettingsUpdate,
     EcosystemConfig,
-    ContentRewardPool, WalletContentState, PRECISION,
-    RentConfig, RentTier,
+    ContentRewardPool, WalletContentState,
+    RentConfig, RentOption,
     BundleType,
     Rarity,
-    PatronTier,
+    PatronTierConfig,
     UnifiedNftRewardState, UNIFIED_NFT_REWARD_STATE_SEED,
+    AccessDecision,
 };
 use utils::hash_cid;
 use errors::ContentRegistryError;
 use contexts::*;
+use contexts::gift::{emit_gift, validate_gift_message};
 use events::*;
 
 declare_id!("2ZDX86a1YmY3AvhFrq6CYQJr938qzhgMFytr9bCaoXS5");
@@ -364,12 +328,14 @@ pub mod content_registry {
     /// metadata_cid: IPFS CID for collection metadata JSON (contains name, description, contentCid, contentType, etc.)
     /// collection_name: Optional collection name - if provided, collection is named "HC: <Username>: <CollectionName>"
     ///                  If not provided, collection is named "HC: <Username>"
+    #[allow(clippy::too_many_arguments)]
     pub fn register_content_with_mint(
         ctx: Context<RegisterContentWithMint>,
         cid_hash: [u8; 32],
         content_cid: String,
         metadata_cid: String,
         price: u64,
+        currency: PaymentCurrency,
         max_supply: Option<u64>,
         creator_royalty_bps: u16,
         is_encrypted: bool,
@@ -388,9 +354,9 @@ pub mod content_registry {
         let computed_hash = hash_cid(&content_cid);
         require!(computed_hash == cid_hash, ContentRegistryError::CidHashMismatch);
 
-        // Validate mint config (SOL only)
+        // Validate mint config (minimum price depends on currency)
         require!(
-            MintConfig::validate_price(price, PaymentCurrency::Sol),
+            MintConfig::validate_price(price, currency),
             ContentRegistryError::PriceTooLow
         );
         require!(
@@ -419,18 +385,27 @@ pub mod content_registry {
         content.encryption_meta_cid = encryption_meta_cid;
         content.visibility_level = visibility_level;
 
-        // Initialize mint config (SOL only)
+        // Initialize mint config
         mint_config.item_type = ItemType::Content;
         mint_config.item = content.key();
         mint_config.creator = ctx.accounts.authority.key();
         mint_config.price = price;
-        mint_config.currency = PaymentCurrency::Sol;
+        mint_config.currency = currency;
         // Default to DEFAULT_MAX_SUPPLY if not specified
         mint_config.max_supply = Some(max_supply.unwrap_or(DEFAULT_MAX_SUPPLY));
         mint_config.creator_royalty_bps = creator_royalty_bps;
         mint_config.is_active = true;
         mint_config.created_at = timestamp;
         mint_config.updated_at = timestamp;
+        mint_config.rarity_table = RarityTable::default();
+        mint_config.rarity_quota = None;
+        mint_config.allowlist_phase = None;
+        mint_config.public_start_at = 0;
+        mint_config.public_end_at = None;
+        mint_config.max_per_wallet = None;
+        mint_config.pricing_mode = PricingMode::Fixed;
+        mint_config.rent_to_own_bps = None;
+        mint_config.instant_rarity = false;
 
         // Create Metaplex Core Collection for this content with Royalties plugin
         // NFT ownership is verified at claim time instead of using lifecycle hooks
@@ -552,17 +527,19 @@ pub mod content_registry {
     // MINT CONFIGURATION
     // ============================================
 
-    /// Configure NFT minting for content (creator only, SOL only)
+    /// Configure NFT minting for content (creator only)
+    /// currency: SOL (lamports) or USDC (6-decimal base units)
     /// If max_supply is None, defaults to DEFAULT_MAX_SUPPLY (999,999)
     pub fn configure_mint(
         ctx: Context<ConfigureMint>,
         price: u64,
+        currency: PaymentCurrency,
         max_supply: Option<u64>,
         creator_royalty_bps: u16,
     ) -> Result<()> {
-        // Validate price (SOL only)
+        // Validate price (minimum depends on currency)
         require!(
-            MintConfig::validate_price(price, PaymentCurrency::Sol),
+            MintConfig::validate_price(price, currency),
             ContentRegistryError::PriceTooLow
         );
 
@@ -584,13 +561,22 @@ pub mod content_registry {
         mint_config.item = ctx.accounts.content.key();
         mint_config.creator = ctx.accounts.creator.key();
         mint_config.price = price;
-        mint_config.currency = PaymentCurrency::Sol;
+        mint_config.currency = currency;
         // Default to DEFAULT_MAX_SUPPLY if not specified
         mint_config.max_supply = Some(max_supply.unwrap_or(DEFAULT_MAX_SUPPLY));
         mint_config.creator_royalty_bps = creator_royalty_bps;
         mint_config.is_active = true;
         mint_config.created_at = timestamp;
         mint_config.updated_at = timestamp;
+        mint_config.rarity_table = RarityTable::default();
+        mint_config.rarity_quota = None;
+        mint_config.allowlist_phase = None;
+        mint_config.public_start_at = 0;
+        mint_config.public_end_at = None;
+        mint_config.max_per_wallet = None;
+        mint_config.pricing_mode = PricingMode::Fixed;
+        mint_config.rent_to_own_bps = None;
+        mint_config.instant_rarity = false;
 
         Ok(())
     }
@@ -598,11 +584,19 @@ pub mod content_registry {
     /// Update mint settings (creator only, with restrictions after first mint)
     pub fn update_mint_settings(
         ctx: Context<UpdateMintSettings>,
-        price: Option<u64>,
-        max_supply: Option<Option<u64>>,
-        creator_royalty_bps: Option<u16>,
-        is_active: Option<bool>,
+        settings: MintSettingsUpdate,
     ) -> Result<()> {
+        let MintSettingsUpdate {
+            price,
+            max_supply,
+            creator_royalty_bps,
+            is_active,
+            rarity_table,
+            rarity_quota,
+            max_per_wallet,
+            pricing_mode,
+            instant_rarity,
+        } = settings;
         let content = &ctx.accounts.content;
         let mint_config = &mut ctx.accounts.mint_config;
         let timestamp = Clock::get()?.unix_timestamp;
@@ -618,6 +612,8 @@ pub mod content_registry {
 
         // Max supply restrictions after minting starts
         if let Some(new_max_supply) = max_supply {
+            // Frozen while an auction or commit-reveal requests reserve editions
+            require!(content.pending_count == 0, ContentRegistryError::SupplyReserved);
             // Validate max_supply doesn't exceed limit (for 6-digit edition format)
             if let Some(new_max) = new_max_supply {
                 require!(new_max <= DEFAULT_MAX_SUPPLY, ContentRegistryError::MaxSupplyTooHigh);
@@ -662,11 +658,63 @@ pub mod content_registry {
             mint_config.is_active = active;
         }
 
+        // Rarity table is frozen once locked (and while commit-reveal mints are in flight)
+        if let Some(new_table) = rarity_table {
+            require!(
+                !content.is_locked && content.pending_count == 0,
+                ContentRegistryError::ContentLocked
+            );
+            require!(new_table.is_valid(), ContentRegistryError::InvalidRarityTable);
+            mint_config.rarity_table = new_table;
+        }
+
+        // Quota mode (Some(None) disables) - same freeze rules as the table
+        if let Some(new_quota) = rarity_quota {
+            require!(
+                !content.is_locked && content.pending_count == 0,
+                ContentRegistryError::ContentLocked
+            );
+            mint_config.rarity_quota = new_quota.map(RarityQuota::new);
+        }
+        // Also rejects supply changes that would break an active quota
+        require!(mint_config.quota_matches_supply(), ContentRegistryError::InvalidRarityQuota);
+
+        // Per-wallet cap can always be changed (Some(None) removes it)
+        if let Some(new_max_per_wallet) = max_per_wallet {
+            require!(new_max_per_wallet != Some(0), ContentRegistryError::InvalidMaxPerWallet);
+            mint_config.max_per_wallet = new_max_per_wallet;
+        }
+
+        // Pricing mode can always be updated, like price
+        if let Some(new_mode) = pricing_mode {
+            require!(
+                new_mode.is_valid(mint_config.currency),
+                ContentRegistryError::InvalidPricingMode
+            );
+            mint_config.pricing_mode = new_mode;
+        }
+
+        // Slot-hash rarity opt-in can always be toggled
+        if let Some(instant) = instant_rarity {
+            mint_config.instant_rarity = instant;
+        }
+
         mint_config.updated_at = timestamp;
 
         Ok(())
     }
 
+    /// Configure mint phases for content (creator only)
+    /// Optional allowlist window (Merkle root, price, per-wallet cap) followed by the public window
+    pub fn configure_mint_phases(
+        ctx: Context<ConfigureMintPhases>,
+        allowlist_phase: Option<AllowlistPhase>,
+        public_start_at: i64,
+        public_end_at: Option<i64>,
+    ) -> Result<()> {
+        handle_configure_mint_phases(ctx, allowlist_phase, public_start_at, public_end_at)
+    }
+
     // ============================================
     // HOLDER REWARD CLAIMS
     // ============================================
@@ -747,7 +795,7 @@ pub mod content_registry {
 
         // Must have pairs of accounts (nft_asset, nft_reward_state)
         let remaining = &ctx.remaining_accounts;
-        require!(remaining.len() % 2 == 0, ContentRegistryError::InvalidAccountPairs);
+        require!(remaining.len().is_multiple_of(2), ContentRegistryError::InvalidAccountPairs);
 
         let num_pairs = remaining.len() / 2;
         let mut total_pending: u64 = 0;
@@ -853,8 +901,8 @@ pub mod content_registry {
         let timestamp = Clock::get()?.unix_timestamp;
 
         // Must have pairs of accounts (wallet_state, pool)
-        require!(remaining.len() % 2 == 0, ContentRegistryError::InvalidAccountPairs);
-        require!(remaining.len() > 0, ContentRegistryError::NothingToClaim);
+        require!(remaining.len().is_multiple_of(2), ContentRegistryError::InvalidAccountPairs);
+        require!(!remaining.is_empty(), ContentRegistryError::NothingToClaim);
 
         let mut total_claimed: u64 = 0;
         let num_pairs = remaining.len() / 2;
@@ -1117,27 +1165,20 @@ pub mod content_registry {
     // ============================================
 
     /// Configure rental settings for content (creator only)
-    /// Sets the rent fee and rental period
-    /// Configure rental with 3-tier pricing: 6 hours, 1 day, 7 days
-    /// Creator must set fees for all three tiers
+    /// rent_options: Creator-defined (duration_seconds, fee) options, up to 8
+    /// Durations must be between 1 hour and 365 days
+    /// max_concurrent_rentals: Optional cap on simultaneous rentals (library lending)
+    /// A slot stays taken until its rental is returned or reaped after expiry
     pub fn configure_rent(
         ctx: Context<ConfigureRent>,
-        rent_fee_6h: u64,
-        rent_fee_1d: u64,
-        rent_fee_7d: u64,
+        rent_options: Vec<RentOption>,
+        max_concurrent_rentals: Option<u16>,
     ) -> Result<()> {
-        // Validate all rent fees meet minimum
+        // Validate durations and fees
+        RentConfig::validate_options(&rent_options)?;
         require!(
-            RentConfig::validate_fee(rent_fee_6h),
-            ContentRegistryError::RentFeeTooLow
-        );
-        require!(
-            RentConfig::validate_fee(rent_fee_1d),
-            ContentRegistryError::RentFeeTooLow
-        );
-        require!(
-            RentConfig::validate_fee(rent_fee_7d),
-            ContentRegistryError::RentFeeTooLow
+            RentConfig::validate_max_concurrent(max_concurrent_rentals),
+            ContentRegistryError::InvalidMaxConcurrentRentals
         );
 
         let rent_config = &mut ctx.accounts.rent_config;
@@ -1146,56 +1187,45 @@ pub mod content_registry {
         rent_config.item_type = ItemType::Content;
         rent_config.item = ctx.accounts.content.key();
         rent_config.creator = ctx.accounts.creator.key();
-        rent_config.rent_fee_6h = rent_fee_6h;
-        rent_config.rent_fee_1d = rent_fee_1d;
-        rent_config.rent_fee_7d = rent_fee_7d;
+        rent_config.rent_options = rent_options;
         rent_config.is_active = true;
         rent_config.total_rentals = 0;
         rent_config.total_fees_collected = 0;
         rent_config.created_at = timestamp;
         rent_config.updated_at = timestamp;
+        rent_config.max_concurrent_rentals = max_concurrent_rentals;
+        rent_config.active_rental_count = 0;
 
         Ok(())
     }
 
     /// Update rental settings (creator only)
-    /// Allows updating individual tier fees or all at once
+    /// rent_options replaces the whole option list when provided
+    /// max_concurrent_rentals: Some(None) removes the cap, Some(Some(n)) sets it
     pub fn update_rent_config(
         ctx: Context<UpdateRentConfig>,
-        rent_fee_6h: Option<u64>,
-        rent_fee_1d: Option<u64>,
-        rent_fee_7d: Option<u64>,
+        rent_options: Option<Vec<RentOption>>,
         is_active: Option<bool>,
+        max_concurrent_rentals: Option<Option<u16>>,
     ) -> Result<()> {
         let rent_config = &mut ctx.accounts.rent_config;
         let timestamp = Clock::get()?.unix_timestamp;
 
-        if let Some(fee) = rent_fee_6h {
-            require!(
-                RentConfig::validate_fee(fee),
-                ContentRegistryError::RentFeeTooLow
-            );
-            rent_config.rent_fee_6h = fee;
+        if let Some(options) = rent_options {
+            RentConfig::validate_options(&options)?;
+            rent_config.rent_options = options;
         }
 
-        if let Some(fee) = rent_fee_1d {
-            require!(
-                RentConfig::validate_fee(fee),
-                ContentRegistryError::RentFeeTooLow
-            );
-            rent_config.rent_fee_1d = fee;
+        if let Some(active) = is_active {
+            rent_config.is_active = active;
         }
 
-        if let Some(fee) = rent_fee_7d {
+        if let Some(new_max) = max_concurrent_rentals {
             require!(
-                RentConfig::validate_fee(fee),
-                ContentRegistryError::RentFeeTooLow
+                RentConfig::validate_max_concurrent(new_max),
+                ContentRegistryError::InvalidMaxConcurrentRentals
             );
-            rent_config.rent_fee_7d = fee;
-        }
-
-        if let Some(active) = is_active {
-            rent_config.is_active = active;
+            rent_config.max_concurrent_rentals = new_max;
         }
 
         rent_config.updated_at = timestamp;
@@ -1203,13 +1233,30 @@ pub mod content_registry {
         Ok(())
     }
 
+    /// Enable rent-to-own for a content or bundle (creator only)
+    /// rent_to_own_bps: Share of a wallet's prior rental fees credited toward its next
+    /// simple mint (None = disabled). Discounted price never goes below MIN_PRICE_LAMPORTS
+    pub fn configure_rent_to_own(ctx: Context<ConfigureRentToOwn>, rent_to_own_bps: Option<u16>) -> Result<()> {
+        handle_configure_rent_to_own(ctx, rent_to_own_bps)
+    }
+
     /// Rent content with SOL payment
     /// Creates a frozen (non-transferable) NFT with expiry stored in Attributes plugin
-    /// User selects one of 3 tiers: 6 hours, 1 day, or 7 days
+    /// User selects one of the creator's rent options by index
     /// Payment is distributed according to primary sale percentages
-    pub fn rent_content_sol(ctx: Context<RentContentSol>, tier: RentTier) -> Result<()> {
+    /// gift_message_cid: Optional gift message when `recipient` is not the renter
+    /// Rent-to-own credit goes to the recipient, who holds the rental
+    /// Expired rentals still holding a max_concurrent_rentals slot can be passed as
+    /// remaining_accounts to free their slots first
+    pub fn rent_content_sol<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RentContentSol<'info>>,
+        option_index: u8,
+        gift_message_cid: Option<String>,
+    ) -> Result<()> {
         use mpl_core::types::{Attributes, Attribute};
 
+        validate_gift_message(&gift_message_cid)?;
+
         let ecosystem = &ctx.accounts.ecosystem_config;
         let rent_config = &ctx.accounts.rent_config;
         let content = &ctx.accounts.content;
@@ -1233,9 +1280,10 @@ pub mod content_registry {
             content_reward_pool.created_at = timestamp;
         }
 
-        // Get fee and period based on selected tier
-        let rent_fee = rent_config.get_fee_for_tier(tier);
-        let rent_period = tier.period_seconds();
+        // Get fee and period based on selected option
+        let option = rent_config.get_option(option_index)?;
+        let rent_fee = option.fee;
+        let rent_period = option.duration_seconds;
         let expires_at = timestamp + rent_period;
         let has_existing_nfts = content_reward_pool.total_nfts > 0;
 
@@ -1272,7 +1320,7 @@ pub mod content_registry {
             if has_existing_nfts && holder_reward_amount > 0 {
                 let transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
                     &ctx.accounts.renter.key(),
-                    &content_reward_pool.to_account_info().key,
+                    content_reward_pool.to_account_info().key,
                     holder_reward_amount,
                 );
                 anchor_lang::solana_program::program::invoke(
@@ -1347,6 +1395,21 @@ pub mod content_registry {
             &[mint_config_bump],
         ]];
 
+        // Free the slots of expired rentals passed in, then occupy one (if capped)
+        // The NFT is marked so return/reap free it
+        release_expired_rental_slots(
+            ctx.remaining_accounts,
+            &mut ctx.accounts.rent_config,
+            &ctx.accounts.mpl_core_program,
+            &ctx.accounts.collection_asset,
+            &ctx.accounts.mint_config.to_account_info(),
+            &ctx.accounts.renter.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            signer_seeds,
+            timestamp,
+        )?;
+        let holds_slot = ctx.accounts.rent_config.occupy_rental_slot()?;
+
         // Create rental NFT with all plugins in one call:
         // - PermanentBurnDelegate: only our program can burn
         // - FreezeDelegate(frozen=true): non-transferable rental
@@ -1361,24 +1424,26 @@ pub mod content_registry {
             authority: Some(PluginAuthority::None), // Permanently frozen, no one can unfreeze
         };
 
+        let mut attribute_list = vec![
+            Attribute {
+                key: "expires_at".to_string(),
+                value: expires_at.to_string(),
+            },
+            Attribute {
+                key: "rented_at".to_string(),
+                value: timestamp.to_string(),
+            },
+            Attribute {
+                key: "duration".to_string(),
+                value: rent_period.to_string(),
+            },
+        ];
+        if holds_slot {
+            attribute_list.push(rental_slot_attribute());
+        }
         let attributes_plugin = PluginAuthorityPair {
-            plugin: Plugin::Attributes(Attributes {
-                attribute_list: vec![
-                    Attribute {
-                        key: "expires_at".to_string(),
-                        value: expires_at.to_string(),
-                    },
-                    Attribute {
-                        key: "rented_at".to_string(),
-                        value: timestamp.to_string(),
-                    },
-                    Attribute {
-                        key: "tier".to_string(),
-                        value: format!("{:?}", tier),
-                    },
-                ],
-            }),
-            authority: Some(PluginAuthority::None), // Immutable attributes
+            plugin: Plugin::Attributes(Attributes { attribute_list }),
+            authority: Some(PluginAuthority::UpdateAuthority), // Program-updatable for extensions
         };
 
         CreateV2CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
@@ -1386,7 +1451,7 @@ pub mod content_registry {
             .collection(Some(&ctx.accounts.collection_asset.to_account_info()))
             .authority(Some(&ctx.accounts.mint_config.to_account_info()))
             .payer(&ctx.accounts.renter.to_account_info())
-            .owner(Some(&ctx.accounts.renter.to_account_info()))
+            .owner(Some(&ctx.accounts.recipient.to_account_info()))
             .system_program(&ctx.accounts.system_program.to_account_info())
             .name(rental_nft_name)
             .uri(rental_nft_uri)
@@ -1394,21 +1459,27 @@ pub mod content_registry {
             .plugins(vec![burn_delegate_plugin, freeze_delegate_plugin, attributes_plugin])
             .invoke_signed(signer_seeds)?;
 
-        // Update rent config stats (manual update to avoid borrow issues)
+        record_rental_credit(
+            &ctx.accounts.mint_config,
+            ctx.accounts.rental_credit.as_deref_mut().map(|c| &mut **c),
+            ctx.accounts.recipient.key(),
+            content_key,
+            rent_fee,
+            timestamp,
+        )?;
+
+        // Update rent config stats
         {
-            let rent_config_info = ctx.accounts.rent_config.to_account_info();
-            let mut rent_config_data = rent_config_info.try_borrow_mut_data()?;
-            let mut updated_config = RentConfig::try_deserialize(&mut &rent_config_data[..])?;
-            updated_config.total_rentals += 1;
-            updated_config.total_fees_collected += rent_fee;
-            updated_config.updated_at = timestamp;
-            updated_config.try_serialize(&mut &mut rent_config_data[..])?;
+            let rent_config = &mut ctx.accounts.rent_config;
+            rent_config.total_rentals += 1;
+            rent_config.total_fees_collected += rent_fee;
+            rent_config.updated_at = timestamp;
         }
 
         // Emit rental event
         emit!(ContentRentedEvent {
             content: content.key(),
-            renter: ctx.accounts.renter.key(),
+            renter: ctx.accounts.recipient.key(),
             creator: ctx.accounts.creator.key(),
             nft_asset: nft_asset_key,
             fee_paid: rent_fee,
@@ -1416,9 +1487,42 @@ pub mod content_registry {
             expires_at,
         });
 
+        emit_gift(
+            ctx.accounts.renter.key(),
+            ctx.accounts.recipient.key(),
+            GiftType::ContentRental,
+            content_key,
+            nft_asset_key,
+            gift_message_cid,
+            timestamp,
+        );
+
         Ok(())
     }
 
+    /// Extend an existing content rental by another rent option
+    /// Pushes expires_at forward on the same rental NFT (no new mint)
+    /// Payment is distributed according to primary sale percentages
+    pub fn extend_rental(ctx: Context<ExtendRental>, option_index: u8) -> Result<()> {
+        handle_extend_rental(ctx, option_index)
+    }
+
+    /// Burn expired rental NFTs (content or bundle) of one collection - permissionless
+    /// Pass pairs of (nft_asset, renter) as remaining_accounts
+    /// Reclaimed asset rent returns to the renter, minus a small caller reward
+    /// Frees the max_concurrent_rentals slots of reaped rentals (pass rent_config)
+    pub fn reap_expired_rentals<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReapExpiredRentals<'info>>,
+    ) -> Result<()> {
+        handle_reap_expired_rentals(ctx)
+    }
+
+    /// Return a rental (content or bundle) before it expires
+    /// Burns the rental NFT and frees its slot under max_concurrent_rentals
+    pub fn return_rental(ctx: Context<ReturnRental>) -> Result<()> {
+        handle_return_rental(ctx)
+    }
+
     /// Check if a rental has expired
     /// Reads expiry from NFT's Attributes plugin
     /// Returns error if expired, success if still valid
@@ -1479,17 +1583,17 @@ pub mod content_registry {
     /// Bundle is created as published with mint and rent enabled by default
     /// metadata_cid: IPFS CID for collection metadata JSON
     /// collection_name: Optional collection name - if provided, collection is named "HC: <Username>: <CollectionName>"
+    #[allow(clippy::too_many_arguments)]
     pub fn create_bundle_with_mint_and_rent(
         ctx: Context<CreateBundleWithMintAndRent>,
         bundle_id: String,
         metadata_cid: String,
         bundle_type: BundleType,
         mint_price: u64,
+        mint_currency: PaymentCurrency,
         mint_max_supply: Option<u64>,
         creator_royalty_bps: u16,
-        rent_fee_6h: u64,
-        rent_fee_1d: u64,
-        rent_fee_7d: u64,
+        rent_options: Vec<RentOption>,
         collection_name: Option<String>,
     ) -> Result<()> {
         handle_create_bundle_with_mint_and_rent(
@@ -1498,11 +1602,10 @@ pub mod content_registry {
             metadata_cid,
             bundle_type,
             mint_price,
+            mint_currency,
             mint_max_supply,
             creator_royalty_bps,
-            rent_fee_6h,
-            rent_fee_1d,
-            rent_fee_7d,
+            rent_options,
             collection_name,
         )
     }
@@ -1554,16 +1657,142 @@ pub mod content_registry {
 
     /// Simple mint content NFT with slot hash randomness + full subscription pool tracking
     /// Single transaction - no VRF, immediate mint, tracks all reward pools
+    /// A random rarity requires the creator's instant_rarity opt-in (otherwise use request_mint)
     /// content_name: Content title for NFT naming - format: "<ContentName> (<R> #XXXXXX)"
-    pub fn simple_mint(ctx: Context<SimpleMint>, content_name: String) -> Result<()> {
-        SimpleMint::handler(ctx, content_name)
+    /// USDC-priced content requires the optional USDC token accounts and token program
+    /// allowlist_proof: Merkle proof for the payer, required during the allowlist phase
+    /// max_price: Slippage limit - fails if the current price is higher
+    /// gift_message_cid: Optional gift message when `recipient` is not the payer
+    pub fn simple_mint(
+        ctx: Context<SimpleMint>,
+        content_name: String,
+        allowlist_proof: Option<Vec<[u8; 32]>>,
+        max_price: u64,
+        gift_message_cid: Option<String>,
+    ) -> Result<()> {
+        SimpleMint::handler(ctx, content_name, allowlist_proof, max_price, gift_message_cid)
     }
 
     /// Simple mint bundle NFT with slot hash randomness + full subscription pool tracking
     /// Single transaction - grants access to all bundle content
     /// bundle_name: Bundle title for NFT naming - format: "<BundleName> (<R> #XXXXXX)"
-    pub fn simple_mint_bundle<'a>(ctx: Context<'_, '_, 'a, 'a, SimpleMintBundle<'a>>, bundle_name: String) -> Result<()> {
-        SimpleMintBundle::handler(ctx, bundle_name)
+    /// USDC-priced bundles pass (bundle_item, content_reward_pool, pool_usdc_ata) triples as remaining_accounts
+    /// max_price: Slippage limit - fails if the current price is higher
+    /// gift_message_cid: Optional gift message when `recipient` is not the payer
+    pub fn simple_mint_bundle<'a>(
+        ctx: Context<'_, '_, 'a, 'a, SimpleMintBundle<'a>>,
+        bundle_name: String,
+        max_price: u64,
+        gift_message_cid: Option<String>,
+    ) -> Result<()> {
+        SimpleMintBundle::handler(ctx, bundle_name, max_price, gift_message_cid)
+    }
+
+    // =========================================================================
+    // Commit-Reveal Mint - Oracle randomness for content NFT rarity
+    // =========================================================================
+
+    /// Request a content mint: escrows the SOL price and commits to a Switchboard
+    /// randomness account created/committed in the previous slot
+    /// allowlist_proof: Merkle proof for the buyer, required during the allowlist phase
+    /// max_price: Slippage limit - fails if the current price is higher
+    pub fn request_mint(
+        ctx: Context<RequestMint>,
+        allowlist_proof: Option<Vec<[u8; 32]>>,
+        max_price: u64,
+    ) -> Result<()> {
+        handle_request_mint(ctx, allowlist_proof, max_price)
+    }
+
+    /// Reveal a pending mint in the oracle's reveal slot (bundle with the Switchboard reveal)
+    /// Mints the NFT to the buyer and pays out the escrow (80/5/3/12 split)
+    /// After 10 minutes without a readable reveal, anyone can complete the request
+    /// with the forfeited roll (lowest available rarity) - requests are never refunded
+    /// content_name: Content title for NFT naming - format: "<ContentName> (<R> #XXXXXX)"
+    pub fn reveal_mint(ctx: Context<RevealMint>, content_name: String) -> Result<()> {
+        RevealMint::handler(ctx, content_name)
+    }
+
+    // =========================================================================
+    // Auctions - English auctions for 1-of-1 content editions
+    // =========================================================================
+
+    /// Open an auction for an unminted max_supply == 1 content (creator only)
+    /// Reserves the edition (and freezes max_supply) until settled or cancelled
+    /// extension_window: anti-sniping window in seconds (None = 10 minutes)
+    pub fn create_auction(
+        ctx: Context<CreateAuction>,
+        reserve_price: u64,
+        min_increment_bps: u16,
+        start_at: i64,
+        end_at: i64,
+        extension_window: Option<i64>,
+    ) -> Result<()> {
+        handle_create_auction(ctx, reserve_price, min_increment_bps, start_at, end_at, extension_window)
+    }
+
+    /// Bid on an auction - escrows the bid and refunds the previous highest bidder
+    pub fn place_bid(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
+        handle_place_bid(ctx, amount)
+    }
+
+    /// Commit the winner's Switchboard randomness for the rarity roll (after end_at)
+    /// One-shot: a commitment can't be replaced, even if the oracle never answers
+    pub fn commit_auction_randomness(ctx: Context<CommitAuctionRandomness>) -> Result<()> {
+        handle_commit_auction_randomness(ctx)
+    }
+
+    /// Settle an ended auction: mint the NFT to the winner and pay out the bid
+    /// with the 80/5/3/12 primary split (permissionless)
+    /// Runs in the oracle's reveal slot; 10 minutes after the commitment (or 7 days
+    /// after end_at if none was made) it can settle without randomness_account
+    /// using the forfeited roll (lowest available tier)
+    /// content_name: Content title for NFT naming - format: "<ContentName> (<R> #XXXXXX)"
+    pub fn settle_auction(ctx: Context<SettleAuction>, content_name: String) -> Result<()> {
+        SettleAuction::handler(ctx, content_name)
+    }
+
+    /// Cancel an auction with no bids (creator only)
+    pub fn cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
+        handle_cancel_auction(ctx)
+    }
+
+    // =========================================================================
+    // Marketplace - Native secondary sales with exact fee routing
+    // =========================================================================
+
+    /// List an NFT for a fixed SOL price (NFT moves into the listing PDA)
+    pub fn list_nft(ctx: Context<ListNft>, price: u64) -> Result<()> {
+        handle_list_nft(ctx, price)
+    }
+
+    /// Cancel a listing and return the NFT to the seller
+    pub fn cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
+        handle_cancel_listing(ctx)
+    }
+
+    /// Buy a listed NFT - pays the secondary split and settles the seller's holder rewards
+    /// Content NFTs pass content_reward_pool, bundle NFTs pass bundle_reward_pool
+    /// max_price: Slippage limit - fails if the listing price is higher
+    pub fn buy_listed_nft(ctx: Context<BuyListedNft>, max_price: u64) -> Result<()> {
+        handle_buy_listed_nft(ctx, max_price)
+    }
+
+    /// Escrow an offer for any edition of a content or bundle (pass exactly one)
+    /// min_rarity: Only NFTs of at least this rarity can fill the offer
+    pub fn make_offer(ctx: Context<MakeOffer>, price: u64, min_rarity: Option<Rarity>) -> Result<()> {
+        handle_make_offer(ctx, price, min_rarity)
+    }
+
+    /// Cancel an offer and refund the escrow
+    pub fn cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
+        handle_cancel_offer(ctx)
+    }
+
+    /// Fill an offer with a matching NFT - pays the secondary split
+    /// min_price: Fails if the offer price is lower
+    pub fn accept_offer(ctx: Context<AcceptOffer>, min_price: u64) -> Result<()> {
+        handle_accept_offer(ctx, min_price)
     }
 
     // =========================================================================
@@ -1577,49 +1806,60 @@ pub mod content_registry {
         ctx: Context<ConfigureBundleMint>,
         metadata_cid: String,
         price: u64,
+        currency: PaymentCurrency,
         max_supply: Option<u64>,
         creator_royalty_bps: u16,
     ) -> Result<()> {
-        handle_configure_bundle_mint(ctx, metadata_cid, price, max_supply, creator_royalty_bps)
+        handle_configure_bundle_mint(ctx, metadata_cid, price, currency, max_supply, creator_royalty_bps)
     }
 
     /// Update bundle mint settings (creator only)
     pub fn update_bundle_mint_settings(
         ctx: Context<UpdateBundleMintSettings>,
-        price: Option<u64>,
-        max_supply: Option<Option<u64>>,
-        creator_royalty_bps: Option<u16>,
-        is_active: Option<bool>,
+        settings: MintSettingsUpdate,
     ) -> Result<()> {
-        handle_update_bundle_mint_settings(ctx, price, max_supply, creator_royalty_bps, is_active)
+        handle_update_bundle_mint_settings(ctx, settings)
     }
 
     /// Configure rental for a bundle (creator only)
-    /// Sets the rent fees for 3 tiers: 6h, 1d, 7d
+    /// rent_options: Creator-defined (duration_seconds, fee) options, up to 8
+    /// max_concurrent_rentals: Optional cap on simultaneous rentals (library lending)
     pub fn configure_bundle_rent(
         ctx: Context<ConfigureBundleRent>,
-        rent_fee_6h: u64,
-        rent_fee_1d: u64,
-        rent_fee_7d: u64,
+        rent_options: Vec<RentOption>,
+        max_concurrent_rentals: Option<u16>,
     ) -> Result<()> {
-        handle_configure_bundle_rent(ctx, rent_fee_6h, rent_fee_1d, rent_fee_7d)
+        handle_configure_bundle_rent(ctx, rent_options, max_concurrent_rentals)
     }
 
     /// Update bundle rent settings (creator only)
     pub fn update_bundle_rent_config(
         ctx: Context<UpdateBundleRentConfig>,
-        rent_fee_6h: Option<u64>,
-        rent_fee_1d: Option<u64>,
-        rent_fee_7d: Option<u64>,
+        rent_options: Option<Vec<RentOption>>,
         is_active: Option<bool>,
+        max_concurrent_rentals: Option<Option<u16>>,
     ) -> Result<()> {
-        handle_update_bundle_rent_config(ctx, rent_fee_6h, rent_fee_1d, rent_fee_7d, is_active)
+        handle_update_bundle_rent_config(ctx, rent_options, is_active, max_concurrent_rentals)
     }
 
     /// Rent a bundle with SOL payment
     /// Creates a frozen rental NFT granting access to all bundle content
-    pub fn rent_bundle_sol(ctx: Context<RentBundleSol>, tier: RentTier) -> Result<()> {
-        handle_rent_bundle_sol(ctx, tier)
+    /// gift_message_cid: Optional gift message when `recipient` is not the renter
+    /// Rent-to-own credit goes to the recipient, who holds the rental
+    /// Expired rentals still holding a max_concurrent_rentals slot can be passed as
+    /// remaining_accounts to free their slots first
+    pub fn rent_bundle_sol<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RentBundleSol<'info>>,
+        option_index: u8,
+        gift_message_cid: Option<String>,
+    ) -> Result<()> {
+        handle_rent_bundle_sol(ctx, option_index, gift_message_cid)
+    }
+
+    /// Extend an existing bundle rental by another rent option
+    /// Pushes expires_at forward on the same rental NFT (no new mint)
+    pub fn extend_bundle_rental(ctx: Context<ExtendBundleRental>, option_index: u8) -> Result<()> {
+        handle_extend_bundle_rental(ctx, option_index)
     }
 
     /// Check if a bundle rental has expired
@@ -1665,12 +1905,14 @@ pub mod content_registry {
     }
 
     /// Update ecosystem subscription settings (admin only)
+    /// trial_duration: Some(Some(seconds)) offers a free trial, Some(None) removes it
     pub fn update_ecosystem_sub_config(
         ctx: Context<UpdateEcosystemSubConfig>,
         price: Option<u64>,
         is_active: Option<bool>,
+        trial_duration: Option<Option<i64>>,
     ) -> Result<()> {
-        handle_update_ecosystem_sub_config(ctx, price, is_active)
+        handle_update_ecosystem_sub_config(ctx, price, is_active, trial_duration)
     }
 
     /// Update epoch duration (admin only, for E2E testing)
@@ -1695,6 +1937,16 @@ pub mod content_registry {
         handle_claim_unified_bundle_rewards(ctx)
     }
 
+    /// Claim USDC rewards from ContentRewardPool's USDC vault (USDC-priced content)
+    pub fn claim_unified_content_usdc_rewards(ctx: Context<ClaimUnifiedContentUsdcRewards>) -> Result<()> {
+        handle_claim_unified_content_usdc_rewards(ctx)
+    }
+
+    /// Claim USDC rewards from RewardPool's USDC vault (USDC-priced bundles)
+    pub fn claim_unified_bundle_usdc_rewards(ctx: Context<ClaimUnifiedBundleUsdcRewards>) -> Result<()> {
+        handle_claim_unified_bundle_usdc_rewards(ctx)
+    }
+
     /// Claim rewards from CreatorPatronPool (lazy pool, triggers epoch distribution)
     pub fn claim_patron_rewards(ctx: Context<ClaimPatronRewards>) -> Result<()> {
         handle_claim_patron_rewards(ctx)
@@ -1757,34 +2009,37 @@ pub mod content_registry {
     // =========================================================================
 
     /// Initialize patron configuration for a creator
-    /// Sets membership (support-only) and subscription (support + access) prices
+    /// tiers: 1-8 named tiers, each with a monthly price, max visibility and optional
+    /// holder share (recorded, not yet applied - patron payouts use the standard split)
     pub fn init_patron_config(
         ctx: Context<InitPatronConfig>,
-        membership_price: u64,
-        subscription_price: u64,
+        tiers: Vec<PatronTierConfig>,
     ) -> Result<()> {
-        handle_init_patron_config(ctx, membership_price, subscription_price)
+        handle_init_patron_config(ctx, tiers)
     }
 
     /// Update patron configuration (creator only)
+    /// tiers: existing tiers may only change price (0 retires a tier); new tiers are appended
+    /// trial_duration: Some(Some(seconds)) offers a free trial, Some(None) removes it
     pub fn update_patron_config(
         ctx: Context<UpdatePatronConfig>,
-        membership_price: Option<u64>,
-        subscription_price: Option<u64>,
+        tiers: Option<Vec<PatronTierConfig>>,
         is_active: Option<bool>,
+        trial_duration: Option<Option<i64>>,
     ) -> Result<()> {
-        handle_update_patron_config(ctx, membership_price, subscription_price, is_active)
+        handle_update_patron_config(ctx, tiers, is_active, trial_duration)
     }
 
     /// Subscribe to a creator (Streamflow payment)
     /// Creates subscription record - payment handled via Streamflow stream
+    /// tier_index: Index into the creator's patron tiers
     /// stream_id: The Streamflow stream ID for this subscription's payment
     pub fn subscribe_patron(
         ctx: Context<SubscribePatron>,
-        tier: PatronTier,
+        tier_index: u8,
         stream_id: Pubkey,
     ) -> Result<()> {
-        handle_subscribe_patron(ctx, tier, stream_id)
+        handle_subscribe_patron(ctx, tier_index, stream_id)
     }
 
     /// Cancel patron subscription
@@ -1814,16 +2069,34 @@ pub mod content_registry {
         handle_cancel_ecosystem_subscription(ctx)
     }
 
-    /// Renew ecosystem subscription (another 30 days)
+    /// Renew ecosystem subscription (access follows the topped-up stream)
     pub fn renew_ecosystem_subscription(ctx: Context<RenewEcosystemSubscription>) -> Result<()> {
         handle_renew_ecosystem_subscription(ctx)
     }
 
     /// Check if user has valid subscription access for content
+    /// Validity is read from the subscription's Streamflow stream
     pub fn check_subscription_access(ctx: Context<CheckSubscriptionAccess>) -> Result<()> {
         handle_check_subscription_access(ctx)
     }
 
+    /// Single on-chain access check covering NFTs, rentals, bundle membership and subscriptions
+    /// Returns an AccessDecision via return data naming the grant used
+    pub fn check_content_access(ctx: Context<CheckContentAccess>) -> Result<AccessDecision> {
+        handle_check_content_access(ctx)
+    }
+
+    /// Check access and write a short-lived AccessGrant PDA for the decryption key service
+    /// session: Ephemeral key the key server releases content keys to
+    pub fn issue_access_grant(ctx: Context<IssueAccessGrant>, session: Pubkey) -> Result<()> {
+        handle_issue_access_grant(ctx, session)
+    }
+
+    /// Close an AccessGrant (anyone after expiry, the user at any time) - rent goes to the user
+    pub fn close_access_grant(ctx: Context<CloseAccessGrant>) -> Result<()> {
+        handle_close_access_grant(ctx)
+    }
+
     // =========================================================================
     // STREAMFLOW MEMBERSHIP SYSTEM (Secure CPI-based stream creation)
     // =========================================================================
@@ -1831,25 +2104,33 @@ pub mod content_registry {
     /// Join ecosystem membership via Streamflow CPI
     /// Program creates stream to treasury PDA - prevents fund redirection attacks
     /// duration_type: 0 = monthly, 1 = yearly (10 months for 12 months access)
+    /// gift_message_cid: Optional gift message when `recipient` is not the subscriber
+    /// Replacing an active record requires its stream (previous_stream) to have run out
+    /// Gifted streams can't be cancelled by the payer
     pub fn join_ecosystem_membership(
         ctx: Context<JoinEcosystemMembership>,
         duration_type: u8,
+        gift_message_cid: Option<String>,
     ) -> Result<()> {
-        handle_join_ecosystem_membership(ctx, duration_type)
+        handle_join_ecosystem_membership(ctx, duration_type, gift_message_cid)
     }
 
     /// Join creator membership via Streamflow CPI
-    /// tier: 0 = Membership (support only), 1 = Subscription (support + access)
+    /// tier_index: Index into the creator's patron tiers
     /// duration_type: 0 = monthly, 1 = yearly
+    /// gift_message_cid: Optional gift message when `recipient` is not the subscriber
+    /// Gifted streams can't be cancelled by the payer
     pub fn join_creator_membership(
         ctx: Context<JoinCreatorMembership>,
-        tier: u8,
+        tier_index: u8,
         duration_type: u8,
+        gift_message_cid: Option<String>,
     ) -> Result<()> {
-        handle_join_creator_membership(ctx, tier, duration_type)
+        handle_join_creator_membership(ctx, tier_index, duration_type, gift_message_cid)
     }
 
     /// Cancel ecosystem membership - cancels Streamflow stream and returns remaining funds
+    /// Gifted memberships can't be cancelled (their stream belongs to the payer)
     pub fn cancel_ecosystem_membership_stream(
         ctx: Context<CancelEcosystemMembershipStream>,
     ) -> Result<()> {
@@ -1865,6 +2146,58 @@ pub mod content_registry {
         handle_topup_ecosystem_membership(ctx, duration_type)
     }
 
+    /// Move a patron subscription to another tier
+    /// Cancels the current stream and re-streams the unstreamed balance at the new tier's rate
+    /// Gifted subscriptions keep their tier (their stream belongs to the payer)
+    /// duration_type: 0 = monthly, 1 = yearly
+    pub fn change_patron_tier(
+        ctx: Context<ChangePatronTier>,
+        new_tier_index: u8,
+        duration_type: u8,
+    ) -> Result<()> {
+        handle_change_patron_tier(ctx, new_tier_index, duration_type)
+    }
+
+    /// Start the creator's free trial at a tier (once per wallet per creator)
+    pub fn start_patron_trial(ctx: Context<StartPatronTrial>, tier_index: u8) -> Result<()> {
+        handle_start_patron_trial(ctx, tier_index)
+    }
+
+    /// Start the ecosystem free trial (once per wallet)
+    pub fn start_ecosystem_trial(ctx: Context<StartEcosystemTrial>) -> Result<()> {
+        handle_start_ecosystem_trial(ctx)
+    }
+
+    /// Close a patron or ecosystem subscription whose stream is depleted or cancelled
+    /// Permissionless crank - rent refunded to the subscriber
+    pub fn expire_subscription(ctx: Context<ExpireSubscription>) -> Result<()> {
+        handle_expire_subscription(ctx)
+    }
+
+    /// Prepay a creator subscription into a program-held vault (no Streamflow)
+    /// tier_index: Index into the creator's patron tiers
+    /// periods: Months to prepay (1-12) - unreleased months are refundable
+    pub fn open_patron_vault(ctx: Context<OpenPatronVault>, tier_index: u8, periods: u16) -> Result<()> {
+        handle_open_patron_vault(ctx, tier_index, periods)
+    }
+
+    /// Prepay the ecosystem subscription into a program-held vault (no Streamflow)
+    /// periods: Months to prepay (1-12) - unreleased months are refundable
+    pub fn open_ecosystem_vault(ctx: Context<OpenEcosystemVault>, periods: u16) -> Result<()> {
+        handle_open_ecosystem_vault(ctx, periods)
+    }
+
+    /// Release started vault periods to the patron or ecosystem treasury
+    /// Permissionless crank - closes the vault (rent to subscriber) once fully used
+    pub fn release_subscription_vault(ctx: Context<ReleaseSubscriptionVault>) -> Result<()> {
+        handle_release_subscription_vault(ctx)
+    }
+
+    /// Cancel a vault subscription - refunds unreleased periods, access runs to the end of the current period
+    pub fn cancel_subscription_vault(ctx: Context<CancelSubscriptionVault>) -> Result<()> {
+        handle_cancel_subscription_vault(ctx)
+    }
+
     // =========================================================================
     // USER PROFILE SYSTEM
     // =========================================================================
@@ -1918,5 +2251,39 @@ pub mod content_registry {
     ) -> Result<()> {
         handle_withdraw_creator_stream_to_treasury(ctx)
     }
+
+    // =========================================================================
+    // ACCOUNT MIGRATIONS
+    // =========================================================================
+
+    /// Grow a reward pool or NFT reward state created before the USDC fields were added
+    /// Permissionless - the caller pays the extra rent
+    pub fn migrate_reward_account(ctx: Context<MigrateRewardAccount>) -> Result<()> {
+        handle_migrate_reward_account(ctx)
+    }
+
+    /// Grow the ecosystem sub config created before trial_duration was added (admin only)
+    pub fn migrate_ecosystem_sub_config(ctx: Context<MigrateEcosystemSubConfig>) -> Result<()> {
+        handle_migrate_ecosystem_sub_config(ctx)
+    }
+
+    /// Rewrite a creator's legacy two-price patron config as a tier ladder (admin only)
+    /// Membership becomes tier 0 and Subscription tier 1, matching existing subscriptions
+    pub fn migrate_creator_patron_config(ctx: Context<MigrateCreatorPatronConfig>) -> Result<()> {
+        handle_migrate_creator_patron_config(ctx)
+    }
+
+    /// Rewrite a content or bundle mint config created before rarity tables, mint
+    /// phases, pricing modes and rent-to-own were added (admin only)
+    /// New fields get the defaults of a freshly created config
+    pub fn migrate_mint_config(ctx: Context<MigrateMintConfig>) -> Result<()> {
+        handle_migrate_mint_config(ctx)
+    }
+
+    /// Rewrite a content or bundle rent config created with fixed 6h/1d/7d fees (admin only)
+    /// The fees become rent options 0, 1 and 2, matching the old tier indices
+    pub fn migrate_rent_config(ctx: Context<MigrateRentConfig>) -> Result<()> {
+        handle_migrate_rent_config(ctx)
+    }
 }
 
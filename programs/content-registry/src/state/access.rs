@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+pub const ACCESS_GRANT_SEED: &[u8] = b"access_grant";
+
+/// Maximum lifetime of an AccessGrant (15 minutes)
+/// Grants from rentals or subscriptions end earlier if those lapse first
+pub const ACCESS_GRANT_DURATION: i64 = 15 * 60;
+
+/// What granted access to a piece of content
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum AccessSource {
+    /// Visibility level 0 - free content
+    Public,
+    /// User owns an edition NFT of the content
+    ContentNft,
+    /// User holds an unexpired rental of the content
+    ContentRental,
+    /// User owns an edition NFT of a bundle containing the content
+    BundleNft,
+    /// User holds an unexpired rental of a bundle containing the content
+    BundleRental,
+    /// Active creator (patron) subscription
+    CreatorSubscription,
+    /// Active ecosystem subscription
+    EcosystemSubscription,
+    /// Creator free trial within its window
+    CreatorTrial,
+    /// Ecosystem free trial within its window
+    EcosystemTrial,
+}
+
+/// Result of check_content_access, returned via return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AccessDecision {
+    /// Content checked
+    pub content: Pubkey,
+    /// User checked
+    pub user: Pubkey,
+    /// Content visibility level at check time
+    pub visibility_level: u8,
+    /// Whether access is granted
+    pub granted: bool,
+    /// Grant used (None when denied)
+    pub source: Option<AccessSource>,
+    /// When the grant lapses (None = permanent or denied)
+    pub expires_at: Option<i64>,
+}
+
+/// Short-lived proof of access for the decryption key service
+/// Written after a successful access check - the key server verifies it
+/// with a single account read instead of re-checking NFTs, rentals and subscriptions
+/// PDA seeds: ["access_grant", content, user, session]
+#[account]
+#[derive(InitSpace)]
+pub struct AccessGrant {
+    /// User granted access
+    pub user: Pubkey,
+    /// Content the grant covers
+    pub content: Pubkey,
+    /// What granted access
+    pub source: AccessSource,
+    /// When the grant lapses
+    pub expires_at: i64,
+    /// Ephemeral session key the key server releases keys to
+    pub session: Pubkey,
+    /// Timestamp when the grant was issued
+    pub issued_at: i64,
+}
+
+impl AccessGrant {
+    /// Whether the grant has lapsed
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+}
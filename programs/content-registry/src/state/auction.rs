@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+
+use crate::state::mint_request::MINT_REQUEST_TIMEOUT;
+
+pub const AUCTION_SEED: &[u8] = b"auction";
+
+/// Default anti-sniping window: bids in the last 10 minutes extend the auction
+pub const DEFAULT_AUCTION_EXTENSION: i64 = 10 * 60;
+
+/// Maximum anti-sniping window (1 hour)
+pub const MAX_AUCTION_EXTENSION: i64 = 60 * 60;
+
+/// Minimum auction duration (1 hour)
+pub const MIN_AUCTION_DURATION: i64 = 60 * 60;
+
+/// Time after end_at from which an auction without a randomness commitment can be
+/// settled with the forfeited roll (lowest available rarity tier) - 7 days
+pub const AUCTION_SETTLE_GRACE: i64 = 7 * 24 * 60 * 60;
+
+/// English auction for a 1-of-1 content edition
+/// The account escrows the highest bid (lamports above rent)
+/// Reserves the single edition via content.pending_count until settled
+/// PDA seeds: ["auction", content]
+#[account]
+#[derive(InitSpace)]
+pub struct Auction {
+    /// Content being auctioned
+    pub content: Pubkey,
+    /// Creator who receives the proceeds
+    pub creator: Pubkey,
+    /// Minimum first bid in lamports
+    pub reserve_price: u64,
+    /// Minimum raise over the current highest bid (basis points)
+    pub min_increment_bps: u16,
+    /// Bidding opens at this timestamp
+    pub start_at: i64,
+    /// Bidding closes at this timestamp (pushed back by late bids)
+    pub end_at: i64,
+    /// Bids within this many seconds of end_at extend end_at to now + extension
+    pub extension_window: i64,
+    /// Current highest bidder (None = no bids)
+    pub highest_bidder: Option<Pubkey>,
+    /// Current highest bid in lamports (held in escrow)
+    pub highest_bid: u64,
+    /// Platform wallet chosen by the highest bidder (receives the 5% fee)
+    pub platform: Option<Pubkey>,
+    /// Number of accepted bids
+    pub bid_count: u32,
+    /// Timestamp when the auction was created
+    pub created_at: i64,
+    /// Switchboard randomness account committed by the winner for the rarity roll
+    pub randomness_account: Option<Pubkey>,
+    /// Slot the randomness was committed to
+    pub randomness_seed_slot: u64,
+    /// Timestamp of the randomness commitment
+    pub randomness_committed_at: i64,
+}
+
+impl Auction {
+    /// Smallest bid that would be accepted now
+    pub fn min_next_bid(&self) -> u64 {
+        if self.highest_bidder.is_none() {
+            return self.reserve_price;
+        }
+        let increment = (self.highest_bid as u128 * self.min_increment_bps as u128 / 10000) as u64;
+        self.highest_bid.saturating_add(increment.max(1))
+    }
+
+    /// Whether bidding is open at `now`
+    pub fn is_open(&self, now: i64) -> bool {
+        now >= self.start_at && now < self.end_at
+    }
+
+    /// Whether settlement may use the forfeited roll instead of oracle randomness:
+    /// the commitment timed out, or nothing was committed within the settle grace
+    pub fn roll_forfeited(&self, now: i64) -> bool {
+        match self.randomness_account {
+            Some(_) => now >= self.randomness_committed_at + MINT_REQUEST_TIMEOUT,
+            None => now >= self.end_at + AUCTION_SETTLE_GRACE,
+        }
+    }
+
+    /// Anti-sniping: a bid close to the end pushes the end back
+    pub fn extend_if_sniped(&mut self, now: i64) {
+        if self.end_at - now < self.extension_window {
+            self.end_at = now + self.extension_window;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn auction() -> Auction {
+        Auction {
+            content: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            reserve_price: 1_000_000_000,
+            min_increment_bps: 500,
+            start_at: 0,
+            end_at: 1_000,
+            extension_window: DEFAULT_AUCTION_EXTENSION,
+            highest_bidder: Some(Pubkey::new_unique()),
+            highest_bid: 1_000_000_000,
+            platform: None,
+            bid_count: 1,
+            created_at: 0,
+            randomness_account: None,
+            randomness_seed_slot: 0,
+            randomness_committed_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_roll_forfeited_after_commitment_timeout() {
+        let mut auction = auction();
+        // Nothing committed: only the settle grace forfeits the roll
+        assert!(!auction.roll_forfeited(1_000 + MINT_REQUEST_TIMEOUT));
+        assert!(auction.roll_forfeited(1_000 + AUCTION_SETTLE_GRACE));
+
+        // Committed: the winner has MINT_REQUEST_TIMEOUT to settle with the reveal
+        auction.randomness_account = Some(Pubkey::new_unique());
+        auction.randomness_committed_at = 2_000;
+        assert!(!auction.roll_forfeited(2_000 + MINT_REQUEST_TIMEOUT - 1));
+        assert!(auction.roll_forfeited(2_000 + MINT_REQUEST_TIMEOUT));
+    }
+}
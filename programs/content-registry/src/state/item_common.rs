@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::errors::ContentRegistryError;
+use crate::state::mint_phase::WalletMintRecord;
 
 // ============================================================================
 // ITEM TYPE DISCRIMINATOR
@@ -67,4 +69,26 @@ pub trait MintableItem {
     fn increment_pending(&mut self) {
         self.set_pending_count(self.pending_count() + 1);
     }
+
+    /// Count a mint by `wallet` against the item's per-wallet cap
+    /// The record is the ["wallet_mint", item, wallet] PDA; it may be omitted when uncapped
+    fn record_wallet_mint(
+        &self,
+        record: Option<&mut WalletMintRecord>,
+        item: Pubkey,
+        wallet: Pubkey,
+        max_per_wallet: Option<u32>,
+    ) -> Result<()> {
+        let record = match (record, max_per_wallet) {
+            (Some(record), _) => record,
+            (None, None) => return Ok(()),
+            (None, Some(_)) => return Err(ContentRegistryError::WalletMintRecordRequired.into()),
+        };
+        record.init_if_new(wallet, item);
+        if let Some(max) = max_per_wallet {
+            require!(record.minted < max, ContentRegistryError::WalletMintLimitReached);
+        }
+        record.minted += 1;
+        Ok(())
+    }
 }
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+pub const LISTING_SEED: &[u8] = b"listing";
+
+/// Fixed-price listing on the native secondary marketplace
+/// The listing PDA takes custody of the NFT until it is bought or cancelled
+/// PDA seeds: ["listing", nft_asset]
+#[account]
+#[derive(InitSpace)]
+pub struct Listing {
+    /// Seller who receives the proceeds and the rent back
+    pub seller: Pubkey,
+    /// NFT asset held in escrow
+    pub nft_asset: Pubkey,
+    /// Metaplex Core collection the NFT belongs to
+    pub collection_asset: Pubkey,
+    /// Content or bundle the NFT was minted from
+    pub content_or_bundle: Pubkey,
+    /// Asking price in lamports
+    pub price: u64,
+    /// Timestamp when the NFT was listed
+    pub created_at: i64,
+}
@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
+use crate::errors::ContentRegistryError;
 use crate::state::item_common::ItemType;
+use crate::state::rarity::{Rarity, RarityQuota, RarityTable};
+use crate::state::mint_phase::{AllowlistPhase, MintPhase};
 
 pub const MINT_CONFIG_SEED: &[u8] = b"mint_config";
 
@@ -20,6 +23,118 @@ pub enum PaymentCurrency {
     Usdc,
 }
 
+/// Pricing mode for primary mints
+/// The effective price is computed at mint time and never drops below the currency minimum
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum PricingMode {
+    /// Always charge MintConfig.price
+    Fixed,
+    /// Linear decay from start_price to floor_price between start_at and end_at
+    DutchAuction {
+        start_price: u64,
+        floor_price: u64,
+        start_at: i64,
+        end_at: i64,
+    },
+    /// base_price + increment * minted_count
+    LinearCurve {
+        base_price: u64,
+        increment: u64,
+    },
+    /// base_price * (1 + growth_bps / 10000) ^ minted_count
+    ExponentialCurve {
+        base_price: u64,
+        growth_bps: u16,
+    },
+}
+
+/// Fixed-point scale for exponential curve math
+const CURVE_PRECISION: u128 = 1_000_000_000_000;
+
+impl PricingMode {
+    /// Validate mode parameters against the currency minimum
+    pub fn is_valid(&self, currency: PaymentCurrency) -> bool {
+        match *self {
+            PricingMode::Fixed => true,
+            PricingMode::DutchAuction { start_price, floor_price, start_at, end_at } => {
+                start_price > floor_price
+                    && MintConfig::validate_price(floor_price, currency)
+                    && start_at < end_at
+            }
+            PricingMode::LinearCurve { base_price, .. } => {
+                MintConfig::validate_price(base_price, currency)
+            }
+            PricingMode::ExponentialCurve { base_price, growth_bps } => {
+                growth_bps > 0 && MintConfig::validate_price(base_price, currency)
+            }
+        }
+    }
+
+    /// Raw price for the mode (before the currency floor)
+    /// `fixed_price` is MintConfig.price, `minted_count` is editions already sold
+    fn raw_price(&self, fixed_price: u64, minted_count: u64, now: i64) -> u64 {
+        match *self {
+            PricingMode::Fixed => fixed_price,
+            PricingMode::DutchAuction { start_price, floor_price, start_at, end_at } => {
+                if now <= start_at {
+                    start_price
+                } else if now >= end_at {
+                    floor_price
+                } else {
+                    let elapsed = (now - start_at) as u128;
+                    let duration = (end_at - start_at) as u128;
+                    let decay = (start_price - floor_price) as u128 * elapsed / duration;
+                    start_price - decay as u64
+                }
+            }
+            PricingMode::LinearCurve { base_price, increment } => {
+                base_price.saturating_add(increment.saturating_mul(minted_count))
+            }
+            PricingMode::ExponentialCurve { base_price, growth_bps } => {
+                // Exponentiation by squaring in fixed point, saturating at u64::MAX
+                let mut factor = CURVE_PRECISION + growth_bps as u128 * CURVE_PRECISION / 10000;
+                let mut multiplier = CURVE_PRECISION;
+                let mut exp = minted_count;
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        multiplier = multiplier.saturating_mul(factor) / CURVE_PRECISION;
+                    }
+                    exp >>= 1;
+                    if exp > 0 {
+                        factor = factor.saturating_mul(factor) / CURVE_PRECISION;
+                    }
+                }
+                let price = (base_price as u128).saturating_mul(multiplier) / CURVE_PRECISION;
+                u64::try_from(price).unwrap_or(u64::MAX)
+            }
+        }
+    }
+}
+
+/// Changes for update_mint_settings / update_bundle_mint_settings
+/// None leaves a setting unchanged; Some(None) clears an optional setting
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct MintSettingsUpdate {
+    /// Fixed price (always updatable)
+    pub price: Option<u64>,
+    /// Max supply (Some(None) = DEFAULT_MAX_SUPPLY; can only decrease after the first mint)
+    pub max_supply: Option<Option<u64>>,
+    /// Creator royalty (only before the first mint)
+    pub creator_royalty_bps: Option<u16>,
+    /// Enable or disable minting
+    pub is_active: Option<bool>,
+    /// Rarity odds and weights (only before the item is locked)
+    pub rarity_table: Option<RarityTable>,
+    /// Per-tier edition counts (Some(None) disables quota mode, only before the item is locked)
+    pub rarity_quota: Option<Option<[u32; 5]>>,
+    /// Per-wallet cap (Some(None) removes it)
+    pub max_per_wallet: Option<Option<u32>>,
+    /// Public-phase pricing mode
+    pub pricing_mode: Option<PricingMode>,
+    /// Let simple_mint roll a random rarity from slot hashes (content only)
+    pub instant_rarity: Option<bool>,
+}
+
 /// Unified mint configuration for content or bundle
 /// PDA seeds: ["mint_config", item_pda] where item_pda is content or bundle
 #[account]
@@ -45,6 +160,27 @@ pub struct MintConfig {
     pub created_at: i64,
     /// Timestamp when config was last updated
     pub updated_at: i64,
+    /// Rarity odds and reward weights (frozen once the item is locked)
+    pub rarity_table: RarityTable,
+    /// Optional fixed per-tier edition counts (None = independent rolls from rarity_table)
+    pub rarity_quota: Option<RarityQuota>,
+    /// Optional allowlist (presale) phase, runs before the public phase
+    pub allowlist_phase: Option<AllowlistPhase>,
+    /// Public phase start (0 = open as soon as minting is active)
+    pub public_start_at: i64,
+    /// Public phase end (None = open-ended)
+    pub public_end_at: Option<i64>,
+    /// Maximum mints per wallet across all phases (None = unlimited)
+    pub max_per_wallet: Option<u32>,
+    /// Public-phase pricing mode (Fixed uses price)
+    pub pricing_mode: PricingMode,
+    /// Rent-to-own: share of the wallet's prior rental spend credited toward a mint
+    /// (basis points, None = disabled, SOL mints only)
+    pub rent_to_own_bps: Option<u16>,
+    /// Creator opt-in: simple_mint may roll a random rarity from slot hashes, which
+    /// validators and simulating bots can predict. Without it, content whose rarity
+    /// isn't fixed mints through request_mint / reveal_mint
+    pub instant_rarity: bool,
 }
 
 impl MintConfig {
@@ -59,6 +195,64 @@ impl MintConfig {
         }
     }
 
+    /// Resolve which mint phase is open at `now` (None = outside every phase)
+    /// The allowlist window takes precedence over the public window
+    pub fn active_phase(&self, now: i64) -> Option<MintPhase> {
+        if let Some(phase) = &self.allowlist_phase {
+            if phase.is_open(now) {
+                return Some(MintPhase::Allowlist);
+            }
+        }
+        let public_ended = match self.public_end_at {
+            Some(end) => now >= end,
+            None => false,
+        };
+        if now >= self.public_start_at && !public_ended {
+            Some(MintPhase::Public)
+        } else {
+            None
+        }
+    }
+
+    /// Minimum allowed price for a currency
+    pub fn min_price(currency: PaymentCurrency) -> u64 {
+        match currency {
+            PaymentCurrency::Sol => MIN_PRICE_LAMPORTS,
+            PaymentCurrency::Usdc => MIN_PRICE_USDC,
+        }
+    }
+
+    /// Effective public-phase price at execution time
+    /// `minted_count` includes editions reserved by pending commit-reveal requests
+    pub fn current_price(&self, minted_count: u64, now: i64) -> u64 {
+        self.pricing_mode
+            .raw_price(self.price, minted_count, now)
+            .max(Self::min_price(self.currency))
+    }
+
+    /// Slippage guard: reject when the price to charge exceeds the buyer's max_price
+    pub fn require_max_price(price: u64, max_price: u64) -> Result<()> {
+        require!(price <= max_price, ContentRegistryError::PriceExceedsMax);
+        Ok(())
+    }
+
+    /// Mint price after the rent-to-own credit for `rental_spend` lamports of prior rentals
+    /// Never discounted below MIN_PRICE_LAMPORTS
+    pub fn rent_to_own_price(&self, price: u64, rental_spend: u64) -> u64 {
+        match (self.rent_to_own_bps, self.currency) {
+            (Some(bps), PaymentCurrency::Sol) => {
+                let credit = (rental_spend as u128 * bps as u128 / 10000) as u64;
+                price.saturating_sub(credit).max(MIN_PRICE_LAMPORTS.min(price))
+            }
+            _ => price,
+        }
+    }
+
+    /// Whether mints consume rental credit
+    pub fn rent_to_own_enabled(&self) -> bool {
+        self.rent_to_own_bps.is_some() && self.currency == PaymentCurrency::Sol
+    }
+
     /// Validate price based on currency
     /// Free minting is not allowed - price must be at least the minimum
     pub fn validate_price(price: u64, currency: PaymentCurrency) -> bool {
@@ -72,8 +266,178 @@ impl MintConfig {
         }
     }
 
+    /// Pick rarity and pool weight for a new mint
+    /// Quota mode draws without replacement; otherwise rolls the rarity table
+    pub fn draw_rarity(&mut self, random_bytes: [u8; 32]) -> Option<(Rarity, u16)> {
+        let rarity = match self.rarity_quota.as_mut() {
+            Some(quota) => quota.draw(random_bytes)?,
+            None => self.rarity_table.roll(random_bytes),
+        };
+        Some((rarity, self.rarity_table.weight(rarity)))
+    }
+
+    /// Whether the next mint can draw more than one tier
+    pub fn rarity_is_random(&self) -> bool {
+        let possible_tiers = match &self.rarity_quota {
+            Some(quota) => quota.remaining.iter().filter(|&&n| n > 0).count(),
+            None => self.rarity_table.probabilities_bps.iter().filter(|&&bps| bps > 0).count(),
+        };
+        possible_tiers > 1
+    }
+
+    /// Slot-hash rarity is grindable, so simple_mint only rolls a random rarity
+    /// when the creator opted in
+    pub fn require_instant_rarity(&self) -> Result<()> {
+        require!(
+            self.instant_rarity || !self.rarity_is_random(),
+            ContentRegistryError::CommitRevealRequired
+        );
+        Ok(())
+    }
+
+    /// Quota totals must always equal max_supply so published counts stay exact
+    pub fn quota_matches_supply(&self) -> bool {
+        match &self.rarity_quota {
+            Some(quota) => self.max_supply == Some(quota.total()),
+            None => true,
+        }
+    }
+
     /// Validate royalty is the fixed 4% rate
     pub fn validate_royalty(royalty_bps: u16) -> bool {
         royalty_bps == FIXED_CREATOR_ROYALTY_BPS
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(currency: PaymentCurrency, pricing_mode: PricingMode) -> MintConfig {
+        MintConfig {
+            item_type: ItemType::Content,
+            item: Pubkey::default(),
+            creator: Pubkey::default(),
+            price: 50_000_000,
+            currency,
+            max_supply: None,
+            creator_royalty_bps: FIXED_CREATOR_ROYALTY_BPS,
+            is_active: true,
+            created_at: 0,
+            updated_at: 0,
+            rarity_table: RarityTable::default(),
+            rarity_quota: None,
+            allowlist_phase: None,
+            public_start_at: 0,
+            public_end_at: None,
+            max_per_wallet: None,
+            pricing_mode,
+            rent_to_own_bps: None,
+            instant_rarity: false,
+        }
+    }
+
+    #[test]
+    fn test_dutch_auction_decay() {
+        let mode = PricingMode::DutchAuction {
+            start_price: 100_000_000,
+            floor_price: 10_000_000,
+            start_at: 1_000,
+            end_at: 2_000,
+        };
+        let cfg = config(PaymentCurrency::Sol, mode);
+
+        assert_eq!(cfg.current_price(0, 500), 100_000_000);
+        assert_eq!(cfg.current_price(0, 1_000), 100_000_000);
+        // Halfway through the window: half the spread has decayed
+        assert_eq!(cfg.current_price(0, 1_500), 55_000_000);
+        // At and after the end the floor applies
+        assert_eq!(cfg.current_price(0, 2_000), 10_000_000);
+        assert_eq!(cfg.current_price(0, 1_000_000), 10_000_000);
+    }
+
+    #[test]
+    fn test_curve_overflow_saturates() {
+        let linear = config(
+            PaymentCurrency::Sol,
+            PricingMode::LinearCurve { base_price: MIN_PRICE_LAMPORTS, increment: u64::MAX / 2 },
+        );
+        assert_eq!(linear.current_price(0, 0), MIN_PRICE_LAMPORTS);
+        assert_eq!(linear.current_price(3, 0), u64::MAX);
+
+        let exponential = config(
+            PaymentCurrency::Sol,
+            PricingMode::ExponentialCurve { base_price: MIN_PRICE_LAMPORTS, growth_bps: 10000 },
+        );
+        // Doubling per edition: 1, 2, 4, ...
+        assert_eq!(exponential.current_price(0, 0), MIN_PRICE_LAMPORTS);
+        assert_eq!(exponential.current_price(1, 0), 2 * MIN_PRICE_LAMPORTS);
+        assert_eq!(exponential.current_price(10, 0), 1024 * MIN_PRICE_LAMPORTS);
+        assert_eq!(exponential.current_price(200, 0), u64::MAX);
+        assert_eq!(exponential.current_price(u64::MAX, 0), u64::MAX);
+    }
+
+    #[test]
+    fn test_min_price_floor() {
+        // A config priced below the minimum is lifted to the currency floor
+        let mut sol = config(PaymentCurrency::Sol, PricingMode::Fixed);
+        sol.price = 1;
+        assert_eq!(sol.current_price(0, 0), MIN_PRICE_LAMPORTS);
+
+        let mut usdc = config(PaymentCurrency::Usdc, PricingMode::Fixed);
+        usdc.price = 0;
+        assert_eq!(usdc.current_price(0, 0), MIN_PRICE_USDC);
+
+        // Invalid modes are rejected up front
+        let below_floor = PricingMode::DutchAuction {
+            start_price: MIN_PRICE_LAMPORTS,
+            floor_price: MIN_PRICE_LAMPORTS - 1,
+            start_at: 0,
+            end_at: 1,
+        };
+        assert!(!below_floor.is_valid(PaymentCurrency::Sol));
+        assert!(below_floor.is_valid(PaymentCurrency::Usdc));
+    }
+
+    #[test]
+    fn test_max_price_rejection() {
+        let cfg = config(
+            PaymentCurrency::Sol,
+            PricingMode::LinearCurve { base_price: 10_000_000, increment: 1_000_000 },
+        );
+        let price = cfg.current_price(5, 0);
+        assert_eq!(price, 15_000_000);
+
+        assert!(MintConfig::require_max_price(price, price).is_ok());
+        assert!(MintConfig::require_max_price(price, u64::MAX).is_ok());
+        assert_eq!(
+            MintConfig::require_max_price(price, price - 1).unwrap_err(),
+            ContentRegistryError::PriceExceedsMax.into()
+        );
+    }
+
+    #[test]
+    fn test_random_rarity_requires_opt_in() {
+        let mut cfg = config(PaymentCurrency::Sol, PricingMode::Fixed);
+        assert!(cfg.rarity_is_random());
+        assert_eq!(
+            cfg.require_instant_rarity().unwrap_err(),
+            ContentRegistryError::CommitRevealRequired.into()
+        );
+        cfg.instant_rarity = true;
+        assert!(cfg.require_instant_rarity().is_ok());
+
+        // A flat table can't be ground, so it never needs the opt-in
+        cfg.instant_rarity = false;
+        cfg.rarity_table.probabilities_bps = [10000, 0, 0, 0, 0];
+        assert!(cfg.require_instant_rarity().is_ok());
+
+        // Quota mode: random until only one tier has editions left
+        let mut quota = RarityQuota::new([0, 0, 5, 0, 1]);
+        cfg.rarity_quota = Some(quota);
+        assert!(cfg.rarity_is_random());
+        quota.remaining[4] = 0;
+        cfg.rarity_quota = Some(quota);
+        assert!(cfg.require_instant_rarity().is_ok());
+    }
+}
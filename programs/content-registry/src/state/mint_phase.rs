@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+pub const WALLET_MINT_RECORD_SEED: &[u8] = b"wallet_mint";
+
+/// Time-windowed allowlist (presale) phase
+/// Eligible wallets are the leaves of a Merkle tree whose root is stored on-chain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct AllowlistPhase {
+    /// Merkle root of allowlisted wallets (leaf = sha256(wallet))
+    pub merkle_root: [u8; 32],
+    /// Price during the allowlist phase (same currency as MintConfig)
+    pub price: u64,
+    /// Maximum allowlist mints per wallet
+    pub max_per_wallet: u32,
+    /// Phase start (inclusive)
+    pub start_at: i64,
+    /// Phase end (exclusive)
+    pub end_at: i64,
+}
+
+impl AllowlistPhase {
+    /// Whether the phase window contains `now`
+    pub fn is_open(&self, now: i64) -> bool {
+        now >= self.start_at && now < self.end_at
+    }
+}
+
+/// Mint phase resolved at execution time
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MintPhase {
+    Allowlist,
+    Public,
+}
+
+/// Per-(wallet, item) mint counter, shared by content and bundle mints
+/// Lazily created on a wallet's first tracked mint of an item
+/// PDA seeds: ["wallet_mint", item, wallet]
+#[account]
+#[derive(InitSpace)]
+pub struct WalletMintRecord {
+    /// Wallet that minted
+    pub wallet: Pubkey,
+    /// Content or bundle minted
+    pub item: Pubkey,
+    /// Mints made during the allowlist phase
+    pub allowlist_minted: u32,
+    /// Total mints across all phases (checked against MintConfig.max_per_wallet)
+    pub minted: u32,
+}
+
+impl WalletMintRecord {
+    /// Lazily initialize on the wallet's first tracked mint
+    pub fn init_if_new(&mut self, wallet: Pubkey, item: Pubkey) {
+        if self.wallet == Pubkey::default() {
+            self.wallet = wallet;
+            self.item = item;
+            self.allowlist_minted = 0;
+            self.minted = 0;
+        }
+    }
+}
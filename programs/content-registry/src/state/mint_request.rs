@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+pub const MINT_REQUEST_SEED: &[u8] = b"mint_request";
+
+/// Seed for NFT assets minted through commit-reveal: ["request_nft", buyer, content, seed_slot]
+pub const REQUEST_NFT_SEED: &[u8] = b"request_nft";
+
+/// Time after which an unrevealed mint request is completed with the forfeited roll (10 minutes)
+pub const MINT_REQUEST_TIMEOUT: i64 = 10 * 60;
+
+/// Pending commit-reveal mint request
+/// Created by request_mint, consumed by reveal_mint
+/// The account itself escrows the payment (lamports above rent)
+/// PDA seeds: ["mint_request", buyer, content]
+#[account]
+#[derive(InitSpace)]
+pub struct MintRequest {
+    /// Buyer who paid and will receive the NFT
+    pub buyer: Pubkey,
+    /// Content being minted
+    pub content: Pubkey,
+    /// Platform wallet chosen at request time (receives the 5% fee at reveal)
+    pub platform: Option<Pubkey>,
+    /// Switchboard randomness account committed at request time
+    pub randomness_account: Pubkey,
+    /// Slot the randomness was committed to
+    pub seed_slot: u64,
+    /// Lamports escrowed for the mint price
+    pub amount_paid: u64,
+    /// Timestamp when the request was created
+    pub created_at: i64,
+    /// NFT asset address, fixed at request time so the oracle value can't be
+    /// re-bound to a different asset by timing the reveal
+    pub nft_asset: Pubkey,
+}
+
+impl MintRequest {
+    /// Whether the request has passed the timeout and may use the forfeited roll
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.created_at + MINT_REQUEST_TIMEOUT
+    }
+}
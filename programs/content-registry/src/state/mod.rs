@@ -10,12 +10,18 @@ pub mod bundle;
 pub mod rarity;
 pub mod subscription;
 pub mod profile;
+pub mod mint_request;
+pub mod mint_phase;
+pub mod auction;
+pub mod listing;
+pub mod offer;
+pub mod access;
 
 pub use item_common::{ItemType, MintableItem};
 pub use content::{ContentEntry, ContentType};
 // NOTE: CidRegistry removed - use hash_cid from crate::utils if needed
 pub use mint_config::{
-    MintConfig, PaymentCurrency, MINT_CONFIG_SEED,
+    MintConfig, MintSettingsUpdate, PaymentCurrency, PricingMode, MINT_CONFIG_SEED,
     FIXED_CREATOR_ROYALTY_BPS, MIN_PRICE_LAMPORTS, MIN_PRICE_USDC,
 };
 pub use ecosystem_config::{
@@ -35,10 +41,10 @@ pub use reward_pool::{
 };
 // NOTE: ContentCollection removed - collection_asset stored in ContentEntry
 pub use rent::{
-    RentConfig, RentTier,
-    RENT_CONFIG_SEED,
-    RENT_PERIOD_6H, RENT_PERIOD_1D, RENT_PERIOD_7D,
-    MIN_RENT_FEE_LAMPORTS,
+    RentConfig, RentOption, RentalCredit,
+    RENT_CONFIG_SEED, RENTAL_CREDIT_SEED,
+    MIN_RENT_PERIOD, MAX_RENT_PERIOD, MAX_RENT_OPTIONS,
+    MIN_RENT_FEE_LAMPORTS, RENTAL_REAP_REWARD_BPS, MAX_REAP_BATCH,
 };
 // NOTE: RentEntry removed - rental expiry stored in NFT Attributes
 pub use bundle::{
@@ -46,7 +52,7 @@ pub use bundle::{
     BUNDLE_SEED, BUNDLE_ITEM_SEED, MAX_BUNDLE_ITEMS,
 };
 // NOTE: BundleCollection, BundleRentEntry removed - data stored in Bundle and NFT Attributes
-pub use rarity::Rarity;
+pub use rarity::{Rarity, RarityQuota, RarityTable};
 // Subscription system types (Phase 1)
 pub use subscription::{
     // Unified NFT reward tracking
@@ -62,14 +68,29 @@ pub use subscription::{
     // Streaming treasury
     ECOSYSTEM_STREAMING_TREASURY_SEED,
     // Patron config and subscription
-    CreatorPatronConfig, CREATOR_PATRON_CONFIG_SEED,
-    CreatorPatronSubscription, CREATOR_PATRON_SUB_SEED, PatronTier,
+    CreatorPatronConfig, CREATOR_PATRON_CONFIG_SEED, PatronTierConfig,
+    MAX_PATRON_TIERS, MAX_PATRON_TIER_NAME_LEN, MAX_PATRON_TIER_VISIBILITY,
+    CreatorPatronSubscription, CREATOR_PATRON_SUB_SEED,
     // Ecosystem subscription
     EcosystemSubConfig, ECOSYSTEM_SUB_CONFIG_SEED,
     EcosystemSubscription, ECOSYSTEM_SUB_SEED,
+    // Free trials
+    TrialMarker, TRIAL_MARKER_SEED, MAX_TRIAL_DURATION, validate_trial_duration,
+    // Program-native subscription vaults
+    SubscriptionVault, SUBSCRIPTION_VAULT_SEED,
+    SUBSCRIPTION_VAULT_PERIOD, MAX_SUBSCRIPTION_VAULT_PERIODS,
     // Constants
-    DEFAULT_EPOCH_DURATION, TEST_EPOCH_DURATION, SUBSCRIPTION_VALIDITY_PERIOD,
+    DEFAULT_EPOCH_DURATION, TEST_EPOCH_DURATION,
     // Helper functions
     calculate_primary_split, calculate_ecosystem_split,
 };
 pub use profile::{UserProfile, USER_PROFILE_SEED, MAX_USERNAME_LENGTH};
+pub use mint_request::{MintRequest, MINT_REQUEST_SEED, MINT_REQUEST_TIMEOUT, REQUEST_NFT_SEED};
+pub use mint_phase::{AllowlistPhase, MintPhase, WalletMintRecord, WALLET_MINT_RECORD_SEED};
+pub use auction::{
+    Auction, AUCTION_SEED,
+    DEFAULT_AUCTION_EXTENSION, MAX_AUCTION_EXTENSION, MIN_AUCTION_DURATION, AUCTION_SETTLE_GRACE,
+};
+pub use listing::{Listing, LISTING_SEED};
+pub use offer::{Offer, OFFER_SEED};
+pub use access::{AccessDecision, AccessGrant, AccessSource, ACCESS_GRANT_SEED, ACCESS_GRANT_DURATION};
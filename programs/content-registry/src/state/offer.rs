@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::rarity::Rarity;
+
+pub const OFFER_SEED: &[u8] = b"offer";
+
+/// Collection-wide offer: "I'll pay X for any edition of this content/bundle"
+/// The account escrows the offered lamports (above rent)
+/// PDA seeds: ["offer", content_or_bundle, buyer]
+#[account]
+#[derive(InitSpace)]
+pub struct Offer {
+    /// Buyer who escrowed the offer and receives the NFT
+    pub buyer: Pubkey,
+    /// Content or bundle the offer is scoped to
+    pub content_or_bundle: Pubkey,
+    /// Whether the scope is a bundle
+    pub is_bundle: bool,
+    /// Metaplex Core collection of the content or bundle
+    pub collection_asset: Pubkey,
+    /// Offered price in lamports (held in escrow)
+    pub price: u64,
+    /// Minimum rarity accepted (None = any edition)
+    pub min_rarity: Option<Rarity>,
+    /// Timestamp when the offer was made
+    pub created_at: i64,
+}
+
+impl Offer {
+    /// Whether an NFT of `rarity` satisfies this offer
+    pub fn accepts_rarity(&self, rarity: Rarity) -> bool {
+        match self.min_rarity {
+            Some(min) => rarity.to_u8() >= min.to_u8(),
+            None => true,
+        }
+    }
+}
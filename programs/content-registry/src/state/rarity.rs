@@ -2,7 +2,8 @@ use anchor_lang::prelude::*;
 
 // NOTE: NFT_RARITY_SEED removed - rarity is now stored in UnifiedNftRewardState
 
-/// Rarity tiers with their probabilities and weights
+/// Rarity tiers with their standard probabilities and weights
+/// Collections can override both via RarityTable on MintConfig
 /// Probability is in basis points (out of 10000)
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
 pub enum Rarity {
@@ -14,7 +15,7 @@ pub enum Rarity {
 }
 
 impl Rarity {
-    /// Get the weight multiplier for this rarity
+    /// Get the weight multiplier for this rarity under the standard table
     /// Used to calculate share of reward pool
     pub fn weight(&self) -> u16 {
         match self {
@@ -52,37 +53,25 @@ impl Rarity {
     /// Determine rarity from a random u128 value
     /// Uses the full range of u128 for maximum precision
     ///
-    /// Probability distribution:
+    /// Probability distribution (the standard table):
     /// - Common:    55% (0 - 5500)
     /// - Uncommon:  27% (5500 - 8200)
     /// - Rare:      13% (8200 - 9500)
     /// - Epic:       4% (9500 - 9900)
     /// - Legendary:  1% (9900 - 10000)
     pub fn from_random(random_value: [u8; 32]) -> Self {
-        // Convert first 4 bytes to u32 for simpler calculation
-        let random_u32 = u32::from_le_bytes([
-            random_value[0],
-            random_value[1],
-            random_value[2],
-            random_value[3],
-        ]);
-
-        // Normalize to 0-9999 range (10000 possible values)
-        let roll = (random_u32 % 10000) as u16;
-
-        if roll < 5500 {
-            Rarity::Common      // 0-5499: 55%
-        } else if roll < 8200 {
-            Rarity::Uncommon    // 5500-8199: 27%
-        } else if roll < 9500 {
-            Rarity::Rare        // 8200-9499: 13%
-        } else if roll < 9900 {
-            Rarity::Epic        // 9500-9899: 4%
-        } else {
-            Rarity::Legendary   // 9900-9999: 1%
-        }
+        RarityTable::default().roll(random_value)
     }
 
+    /// All tiers in table order (Common first)
+    pub const ALL: [Rarity; RARITY_TIERS] = [
+        Rarity::Common,
+        Rarity::Uncommon,
+        Rarity::Rare,
+        Rarity::Epic,
+        Rarity::Legendary,
+    ];
+
     /// Convert to u8 for storage
     pub fn to_u8(&self) -> u8 {
         match self {
@@ -107,6 +96,138 @@ impl Rarity {
     }
 }
 
+/// Number of rarity tiers
+pub const RARITY_TIERS: usize = 5;
+
+/// Basis point total every rarity table must sum to
+pub const RARITY_BPS_TOTAL: u16 = 10000;
+
+/// Per-collection rarity odds and reward weights
+/// Stored on MintConfig and frozen once the item is locked (first mint)
+/// Both arrays are indexed by Rarity::to_u8 (Common..Legendary)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct RarityTable {
+    /// Probability of each tier in basis points (must sum to 10000)
+    pub probabilities_bps: [u16; 5],
+    /// Reward pool weight of each tier (must be at least 1)
+    pub weights: [u16; 5],
+}
+
+impl Default for RarityTable {
+    /// The platform's standard 55/27/13/4/1 odds with weights 1/5/20/60/120
+    fn default() -> Self {
+        Self {
+            probabilities_bps: [5500, 2700, 1300, 400, 100],
+            weights: [1, 5, 20, 60, 120],
+        }
+    }
+}
+
+impl RarityTable {
+    /// Check probabilities sum to exactly 10000 bps and every weight is non-zero
+    pub fn is_valid(&self) -> bool {
+        let total: u32 = self.probabilities_bps.iter().map(|&bps| bps as u32).sum();
+        total == RARITY_BPS_TOTAL as u32 && self.weights.iter().all(|&w| w > 0)
+    }
+
+    /// Reward pool weight for a rarity under this table
+    pub fn weight(&self, rarity: Rarity) -> u16 {
+        self.weights[rarity.to_u8() as usize]
+    }
+
+    /// Pick a rarity from random bytes using this table's odds
+    /// Tiers with 0 bps can never be rolled
+    pub fn roll(&self, random_value: [u8; 32]) -> Rarity {
+        // Convert first 4 bytes to u32 for simpler calculation
+        let random_u32 = u32::from_le_bytes([
+            random_value[0],
+            random_value[1],
+            random_value[2],
+            random_value[3],
+        ]);
+
+        // Normalize to 0-9999 range (10000 possible values)
+        let roll = random_u32 % RARITY_BPS_TOTAL as u32;
+
+        let mut threshold: u32 = 0;
+        for (rarity, &bps) in Rarity::ALL.iter().zip(self.probabilities_bps.iter()) {
+            threshold += bps as u32;
+            if roll < threshold {
+                return *rarity;
+            }
+        }
+        // Only reachable with an invalid table; fall back to the highest non-zero tier
+        Rarity::ALL
+            .iter()
+            .zip(self.probabilities_bps.iter())
+            .rev()
+            .find(|(_, &bps)| bps > 0)
+            .map(|(rarity, _)| *rarity)
+            .unwrap_or(Rarity::Common)
+    }
+}
+
+/// Fixed per-tier edition counts for a capped drop (quota mode)
+/// Rarities are drawn without replacement, so the published totals are exact
+/// Both arrays are indexed by Rarity::to_u8 (Common..Legendary)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct RarityQuota {
+    /// Published number of editions per tier (sums to max_supply)
+    pub totals: [u32; 5],
+    /// Editions per tier not yet minted
+    pub remaining: [u32; 5],
+}
+
+impl RarityQuota {
+    /// Create a fresh quota with every tier fully remaining
+    pub fn new(totals: [u32; RARITY_TIERS]) -> Self {
+        Self { totals, remaining: totals }
+    }
+
+    /// Sum of the published totals
+    pub fn total(&self) -> u64 {
+        self.totals.iter().map(|&n| n as u64).sum()
+    }
+
+    /// Editions left across all tiers
+    pub fn total_remaining(&self) -> u64 {
+        self.remaining.iter().map(|&n| n as u64).sum()
+    }
+
+    /// Draw a rarity from the remaining editions and consume it
+    /// Each remaining edition is equally likely, so odds shift as tiers sell out
+    /// Returns None once every tier is exhausted
+    pub fn draw(&mut self, random_value: [u8; 32]) -> Option<Rarity> {
+        let total_remaining = self.total_remaining();
+        if total_remaining == 0 {
+            return None;
+        }
+
+        // Use 8 bytes so large supplies are covered evenly
+        let random_u64 = u64::from_le_bytes([
+            random_value[0],
+            random_value[1],
+            random_value[2],
+            random_value[3],
+            random_value[4],
+            random_value[5],
+            random_value[6],
+            random_value[7],
+        ]);
+        let roll = random_u64 % total_remaining;
+
+        let mut threshold: u64 = 0;
+        for (i, remaining) in self.remaining.iter_mut().enumerate() {
+            threshold += *remaining as u64;
+            if roll < threshold {
+                *remaining -= 1;
+                return Some(Rarity::ALL[i]);
+            }
+        }
+        None
+    }
+}
+
 // NOTE: NftRarity struct removed - rarity is now stored in UnifiedNftRewardState
 // NOTE: ContentWeightedPool removed - use ContentRewardPool from reward_pool.rs instead
 
@@ -142,4 +263,92 @@ mod tests {
         assert_eq!(Rarity::Epic.weight(), 60);
         assert_eq!(Rarity::Legendary.weight(), 120);
     }
+
+    #[test]
+    fn test_default_table_matches_standard() {
+        let table = RarityTable::default();
+        assert!(table.is_valid());
+        for rarity in Rarity::ALL {
+            assert_eq!(table.weight(rarity), rarity.weight());
+        }
+
+        let mut bytes = [0u8; 32];
+        for roll in [0u32, 5499, 5500, 8199, 8200, 9499, 9500, 9899, 9900, 9999] {
+            bytes[0..4].copy_from_slice(&roll.to_le_bytes());
+            assert_eq!(table.roll(bytes), Rarity::from_random(bytes));
+        }
+    }
+
+    #[test]
+    fn test_custom_table_roll() {
+        // Flat "all Common" edition
+        let all_common = RarityTable {
+            probabilities_bps: [10000, 0, 0, 0, 0],
+            weights: [1, 1, 1, 1, 1],
+        };
+        assert!(all_common.is_valid());
+        let mut bytes = [0u8; 32];
+        bytes[0..4].copy_from_slice(&9999u32.to_le_bytes());
+        assert_eq!(all_common.roll(bytes), Rarity::Common);
+
+        // Guaranteed-rare-or-better drop
+        let rare_plus = RarityTable {
+            probabilities_bps: [0, 0, 8000, 1500, 500],
+            weights: [1, 5, 20, 60, 120],
+        };
+        assert!(rare_plus.is_valid());
+        bytes[0..4].copy_from_slice(&0u32.to_le_bytes());
+        assert_eq!(rare_plus.roll(bytes), Rarity::Rare);
+        bytes[0..4].copy_from_slice(&7999u32.to_le_bytes());
+        assert_eq!(rare_plus.roll(bytes), Rarity::Rare);
+        bytes[0..4].copy_from_slice(&8000u32.to_le_bytes());
+        assert_eq!(rare_plus.roll(bytes), Rarity::Epic);
+        bytes[0..4].copy_from_slice(&9500u32.to_le_bytes());
+        assert_eq!(rare_plus.roll(bytes), Rarity::Legendary);
+        assert_eq!(rare_plus.weight(Rarity::Epic), 60);
+    }
+
+    #[test]
+    fn test_invalid_tables() {
+        let short = RarityTable {
+            probabilities_bps: [5000, 2700, 1300, 400, 100],
+            weights: [1, 5, 20, 60, 120],
+        };
+        assert!(!short.is_valid());
+
+        let zero_weight = RarityTable {
+            probabilities_bps: [5500, 2700, 1300, 400, 100],
+            weights: [0, 5, 20, 60, 120],
+        };
+        assert!(!zero_weight.is_valid());
+    }
+
+    #[test]
+    fn test_quota_draws_exact_totals() {
+        let mut quota = RarityQuota::new([60, 25, 10, 4, 1]);
+        assert_eq!(quota.total(), 100);
+
+        let mut counts = [0u32; RARITY_TIERS];
+        for i in 0..100u64 {
+            let mut bytes = [0u8; 32];
+            bytes[0..8].copy_from_slice(&(i.wrapping_mul(2654435761)).to_le_bytes());
+            let rarity = quota.draw(bytes).unwrap();
+            counts[rarity.to_u8() as usize] += 1;
+        }
+
+        assert_eq!(counts, [60, 25, 10, 4, 1]);
+        assert_eq!(quota.remaining, [0; RARITY_TIERS]);
+        assert_eq!(quota.draw([0xff; 32]), None);
+    }
+
+    #[test]
+    fn test_quota_skips_exhausted_tiers() {
+        let mut quota = RarityQuota::new([1, 0, 0, 0, 1]);
+
+        // Roll 0 lands on Common first
+        assert_eq!(quota.draw([0; 32]), Some(Rarity::Common));
+        // Common is gone, so any roll must land on Legendary
+        assert_eq!(quota.draw([0; 32]), Some(Rarity::Legendary));
+        assert_eq!(quota.total_remaining(), 0);
+    }
 }
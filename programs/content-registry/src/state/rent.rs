@@ -1,38 +1,39 @@
 use anchor_lang::prelude::*;
+use crate::errors::ContentRegistryError;
 use crate::state::item_common::ItemType;
 
 pub const RENT_CONFIG_SEED: &[u8] = b"rent_config";
+pub const RENTAL_CREDIT_SEED: &[u8] = b"rental_credit";
 // NOTE: RENT_ENTRY_SEED removed - rental expiry is now stored in NFT Attributes plugin
 
-/// Standard rental periods (in seconds)
-pub const RENT_PERIOD_6H: i64 = 6 * 3600;      // 6 hours = 21,600 seconds
-pub const RENT_PERIOD_1D: i64 = 24 * 3600;     // 1 day = 86,400 seconds
-pub const RENT_PERIOD_7D: i64 = 7 * 24 * 3600; // 7 days = 604,800 seconds
+/// Rental duration bounds (in seconds)
+pub const MIN_RENT_PERIOD: i64 = 3600;             // 1 hour
+pub const MAX_RENT_PERIOD: i64 = 365 * 24 * 3600;  // 365 days
+
+/// Maximum rental options per RentConfig
+pub const MAX_RENT_OPTIONS: usize = 8;
 
 /// Minimum rent fee: 0.001 SOL
 pub const MIN_RENT_FEE_LAMPORTS: u64 = 1_000_000;
 
-/// Rental tier selection (0 = 6h, 1 = 1d, 2 = 7d)
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
-pub enum RentTier {
-    SixHours = 0,
-    OneDay = 1,
-    SevenDays = 2,
-}
+/// Share of reclaimed asset rent paid to whoever reaps an expired rental (10%)
+pub const RENTAL_REAP_REWARD_BPS: u64 = 1000;
 
-impl RentTier {
-    /// Get the period in seconds for this tier
-    pub fn period_seconds(&self) -> i64 {
-        match self {
-            RentTier::SixHours => RENT_PERIOD_6H,
-            RentTier::OneDay => RENT_PERIOD_1D,
-            RentTier::SevenDays => RENT_PERIOD_7D,
-        }
-    }
+/// Maximum rental assets reaped per instruction
+pub const MAX_REAP_BATCH: usize = 10;
+
+/// Creator-defined rental option: access duration and its fee
+/// Renters pick an option by its index in RentConfig.rent_options
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct RentOption {
+    /// Access duration in seconds (1 hour to 365 days)
+    pub duration_seconds: i64,
+    /// Rent fee in lamports
+    pub fee: u64,
 }
 
 /// Unified rent configuration for content or bundle
-/// Uses 3-tier pricing: 6 hours, 1 day, 7 days
+/// Pricing is a creator-defined list of (duration, fee) options
 /// PDA seeds: ["rent_config", item_pda] where item_pda is content or bundle
 #[account]
 #[derive(InitSpace)]
@@ -43,12 +44,9 @@ pub struct RentConfig {
     pub item: Pubkey,
     /// Creator who can update rent settings
     pub creator: Pubkey,
-    /// Rent fee for 6-hour access (lamports)
-    pub rent_fee_6h: u64,
-    /// Rent fee for 1-day access (lamports)
-    pub rent_fee_1d: u64,
-    /// Rent fee for 7-day access (lamports)
-    pub rent_fee_7d: u64,
+    /// Rental options offered (up to MAX_RENT_OPTIONS)
+    #[max_len(MAX_RENT_OPTIONS)]
+    pub rent_options: Vec<RentOption>,
     /// Whether renting is currently enabled
     pub is_active: bool,
     /// Total number of times this item has been rented
@@ -59,6 +57,12 @@ pub struct RentConfig {
     pub created_at: i64,
     /// Timestamp when config was last updated
     pub updated_at: i64,
+    /// Max simultaneous rentals (None = unlimited, library lending model when set)
+    pub max_concurrent_rentals: Option<u16>,
+    /// Rental NFTs holding a slot (marked with the holds_slot attribute)
+    /// A slot frees up when its rental is returned, reaped after expiry, or passed
+    /// expired to the next rental
+    pub active_rental_count: u16,
 }
 
 impl RentConfig {
@@ -67,15 +71,161 @@ impl RentConfig {
         fee >= MIN_RENT_FEE_LAMPORTS
     }
 
-    /// Get rent fee for a specific tier
-    pub fn get_fee_for_tier(&self, tier: RentTier) -> u64 {
-        match tier {
-            RentTier::SixHours => self.rent_fee_6h,
-            RentTier::OneDay => self.rent_fee_1d,
-            RentTier::SevenDays => self.rent_fee_7d,
+    /// Validate rent duration (1 hour to 365 days)
+    pub fn validate_period(duration_seconds: i64) -> bool {
+        (MIN_RENT_PERIOD..=MAX_RENT_PERIOD).contains(&duration_seconds)
+    }
+
+    /// Validate a full option list (non-empty, bounded, each duration and fee valid)
+    pub fn validate_options(options: &[RentOption]) -> Result<()> {
+        require!(
+            !options.is_empty() && options.len() <= MAX_RENT_OPTIONS,
+            ContentRegistryError::InvalidRentOptions
+        );
+        for option in options {
+            require!(
+                Self::validate_period(option.duration_seconds),
+                ContentRegistryError::InvalidRentPeriod
+            );
+            require!(
+                Self::validate_fee(option.fee),
+                ContentRegistryError::RentFeeTooLow
+            );
         }
+        Ok(())
+    }
+
+    /// Validate a concurrency cap (at least 1 when set)
+    pub fn validate_max_concurrent(max_concurrent_rentals: Option<u16>) -> bool {
+        max_concurrent_rentals.is_none_or(|max| max >= 1)
+    }
+
+    /// Occupy a slot for a new rental
+    /// Returns whether the rental holds a slot - false (untracked) when no cap is set
+    pub fn occupy_rental_slot(&mut self) -> Result<bool> {
+        let Some(max) = self.max_concurrent_rentals else {
+            return Ok(false);
+        };
+        require!(
+            self.active_rental_count < max,
+            ContentRegistryError::RentalCapacityReached
+        );
+        self.active_rental_count += 1;
+        Ok(true)
+    }
+
+    /// Free the slot held by a returned or reaped rental
+    /// Still counted after the cap is removed, so re-adding a cap sees outstanding slots
+    pub fn release_rental_slot(&mut self) {
+        self.active_rental_count = self.active_rental_count.saturating_sub(1);
+    }
+
+    /// Get the rental option at `index`
+    pub fn get_option(&self, index: u8) -> Result<RentOption> {
+        self.rent_options
+            .get(index as usize)
+            .copied()
+            .ok_or(ContentRegistryError::InvalidRentOption.into())
     }
 }
 
 // NOTE: RentEntry removed - rental expiry is now stored in NFT Attributes plugin
 // Rental access is checked by reading the NFT's expires_at attribute
+
+/// Cumulative rental spend of a wallet on one content or bundle (rent-to-own)
+/// Recorded while MintConfig.rent_to_own_bps is set, consumed (closed) by the wallet's next mint
+/// PDA seeds: ["rental_credit", item, wallet]
+#[account]
+#[derive(InitSpace)]
+pub struct RentalCredit {
+    /// Renter wallet
+    pub wallet: Pubkey,
+    /// Content or bundle rented
+    pub item: Pubkey,
+    /// Total rent fees paid (lamports)
+    pub total_spent: u64,
+    /// Number of rentals and extensions paid
+    pub rental_count: u32,
+    /// Timestamp of the last recorded rental
+    pub updated_at: i64,
+}
+
+impl RentalCredit {
+    /// Add a paid rent fee, initializing on the wallet's first rental
+    pub fn record(&mut self, wallet: Pubkey, item: Pubkey, fee: u64, now: i64) {
+        if self.wallet == Pubkey::default() {
+            self.wallet = wallet;
+            self.item = item;
+            self.total_spent = 0;
+            self.rental_count = 0;
+        }
+        self.total_spent += fee;
+        self.rental_count += 1;
+        self.updated_at = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(max_concurrent_rentals: Option<u16>) -> RentConfig {
+        RentConfig {
+            item_type: ItemType::Content,
+            item: Pubkey::default(),
+            creator: Pubkey::default(),
+            rent_options: vec![],
+            is_active: true,
+            total_rentals: 0,
+            total_fees_collected: 0,
+            created_at: 0,
+            updated_at: 0,
+            max_concurrent_rentals,
+            active_rental_count: 0,
+        }
+    }
+
+    #[test]
+    fn uncapped_rentals_are_not_tracked() {
+        let mut rent_config = config(None);
+        for _ in 0..100 {
+            assert!(!rent_config.occupy_rental_slot().unwrap());
+        }
+        assert_eq!(rent_config.active_rental_count, 0);
+    }
+
+    #[test]
+    fn cap_limits_slots_until_released() {
+        let mut rent_config = config(Some(2));
+        assert!(rent_config.occupy_rental_slot().unwrap());
+        assert!(rent_config.occupy_rental_slot().unwrap());
+        assert!(rent_config.occupy_rental_slot().is_err());
+
+        rent_config.release_rental_slot();
+        assert!(rent_config.occupy_rental_slot().unwrap());
+        assert_eq!(rent_config.active_rental_count, 2);
+    }
+
+    #[test]
+    fn outstanding_slots_survive_cap_removal() {
+        let mut rent_config = config(Some(2));
+        rent_config.occupy_rental_slot().unwrap();
+        rent_config.occupy_rental_slot().unwrap();
+
+        rent_config.max_concurrent_rentals = None;
+        assert!(!rent_config.occupy_rental_slot().unwrap());
+        rent_config.release_rental_slot();
+
+        rent_config.max_concurrent_rentals = Some(2);
+        assert!(rent_config.occupy_rental_slot().unwrap());
+        assert!(rent_config.occupy_rental_slot().is_err());
+    }
+
+    #[test]
+    fn validate_max_concurrent_rejects_zero() {
+        assert!(RentConfig::validate_max_concurrent(None));
+        assert!(RentConfig::validate_max_concurrent(Some(1)));
+        assert!(RentConfig::validate_max_concurrent(Some(1000)));
+        assert!(!RentConfig::validate_max_concurrent(Some(0)));
+    }
+}
@@ -36,6 +36,13 @@ pub struct ContentRewardPool {
     pub total_claimed: u64,
     /// Timestamp when pool was created
     pub created_at: i64,
+    /// Accumulated USDC reward per weight unit (scaled by PRECISION)
+    /// Only used when the content is priced in USDC; tokens are held in the pool's USDC ATA
+    pub usdc_reward_per_share: u128,
+    /// Total USDC rewards ever deposited to this pool (base units)
+    pub usdc_total_deposited: u64,
+    /// Total USDC rewards claimed from this pool (base units)
+    pub usdc_total_claimed: u64,
 }
 
 impl ContentRewardPool {
@@ -49,6 +56,16 @@ impl ContentRewardPool {
         self.total_deposited += amount;
     }
 
+    /// Add USDC rewards to the pool and update usdc_reward_per_share
+    /// Should be called BEFORE adding new NFT weight
+    pub fn add_usdc_rewards(&mut self, amount: u64) {
+        if self.total_weight == 0 || amount == 0 {
+            return;
+        }
+        self.usdc_reward_per_share += (amount as u128 * PRECISION) / self.total_weight as u128;
+        self.usdc_total_deposited += amount;
+    }
+
     /// Add an NFT with its weight (call AFTER adding rewards)
     /// weight: The rarity weight of the NFT (1=Common, 5=Uncommon, 20=Rare, 60=Epic, 120=Legendary)
     pub fn add_nft(&mut self, weight: u16) {
@@ -199,6 +216,13 @@ pub struct RewardPool {
     pub pending_content_share: u64,
     /// Total content share distributed to content pools
     pub total_content_distributed: u64,
+    /// Accumulated USDC reward per weight unit (scaled by PRECISION)
+    /// Only used when the item is priced in USDC; tokens are held in the pool's USDC ATA
+    pub usdc_reward_per_share: u128,
+    /// Total USDC rewards ever deposited to this pool (base units)
+    pub usdc_total_deposited: u64,
+    /// Total USDC rewards claimed from this pool (base units)
+    pub usdc_total_claimed: u64,
 }
 
 impl RewardPool {
@@ -211,6 +235,15 @@ impl RewardPool {
         self.total_deposited += amount;
     }
 
+    /// Add USDC rewards to the pool and update usdc_reward_per_share
+    pub fn add_usdc_rewards(&mut self, amount: u64) {
+        if self.total_weight == 0 || amount == 0 {
+            return;
+        }
+        self.usdc_reward_per_share += (amount as u128 * PRECISION) / self.total_weight as u128;
+        self.usdc_total_deposited += amount;
+    }
+
     /// Add an NFT with its weight
     pub fn add_nft(&mut self, weight: u16) {
         self.total_nfts += 1;
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::reward_pool::PRECISION;
 use crate::state::rarity::Rarity;
+use crate::errors::ContentRegistryError;
 
 // ============================================================================
 // SEED CONSTANTS
@@ -42,14 +43,35 @@ pub const ECOSYSTEM_SUB_CONFIG_SEED: &[u8] = b"ecosystem_sub_config";
 /// Ecosystem subscription - user's platform subscription
 pub const ECOSYSTEM_SUB_SEED: &[u8] = b"ecosystem_sub";
 
+/// Trial marker - one free trial per wallet per creator (or for the ecosystem)
+pub const TRIAL_MARKER_SEED: &[u8] = b"trial_marker";
+
+/// Subscription vault - program-held prepaid periods for a patron or ecosystem subscription
+pub const SUBSCRIPTION_VAULT_SEED: &[u8] = b"subscription_vault";
+
 /// Default epoch duration: 1 day in seconds (for distribution batching)
 pub const DEFAULT_EPOCH_DURATION: i64 = 24 * 60 * 60;
 
 /// Test epoch duration: 60 seconds (for E2E testing)
 pub const TEST_EPOCH_DURATION: i64 = 60;
 
-/// Subscription validity period: 30 days in seconds (for content access)
-pub const SUBSCRIPTION_VALIDITY_PERIOD: i64 = 30 * 24 * 60 * 60;
+/// Maximum number of patron tiers a creator can configure
+pub const MAX_PATRON_TIERS: usize = 8;
+
+/// Maximum length of a patron tier name
+pub const MAX_PATRON_TIER_NAME_LEN: usize = 32;
+
+/// Highest visibility level a patron tier can unlock (Level 3 is NFT/rental only)
+pub const MAX_PATRON_TIER_VISIBILITY: u8 = 2;
+
+/// Longest free trial a creator or admin can offer: 30 days
+pub const MAX_TRIAL_DURATION: i64 = 30 * 24 * 60 * 60;
+
+/// Length of one prepaid subscription vault period: 30 days
+pub const SUBSCRIPTION_VAULT_PERIOD: i64 = 30 * 24 * 60 * 60;
+
+/// Most periods a subscription vault can prepay at once
+pub const MAX_SUBSCRIPTION_VAULT_PERIODS: u16 = 12;
 
 // ============================================================================
 // UNIFIED NFT REWARD STATE
@@ -84,6 +106,9 @@ pub struct UnifiedNftRewardState {
 
     /// Timestamp when this state was created (at mint time)
     pub created_at: i64,
+
+    /// Debt for the USDC side of ContentRewardPool OR RewardPool (USDC-priced items only)
+    pub usdc_debt: u128,
 }
 
 impl UnifiedNftRewardState {
@@ -110,6 +135,12 @@ impl UnifiedNftRewardState {
         (weighted_rps.saturating_sub(self.global_debt) / PRECISION) as u64
     }
 
+    /// Calculate pending USDC content/bundle rewards (IMMEDIATE pool)
+    pub fn pending_usdc_reward(&self, current_usdc_rps: u128) -> u64 {
+        let weighted_rps = self.weight as u128 * current_usdc_rps;
+        (weighted_rps.saturating_sub(self.usdc_debt) / PRECISION) as u64
+    }
+
     /// Update content/bundle debt after claiming
     pub fn update_content_or_bundle_debt(&mut self, current_rps: u128) {
         self.content_or_bundle_debt = self.weight as u128 * current_rps;
@@ -124,6 +155,11 @@ impl UnifiedNftRewardState {
     pub fn update_global_debt(&mut self, current_rps: u128) {
         self.global_debt = self.weight as u128 * current_rps;
     }
+
+    /// Update USDC debt after claiming
+    pub fn update_usdc_debt(&mut self, current_usdc_rps: u128) {
+        self.usdc_debt = self.weight as u128 * current_usdc_rps;
+    }
 }
 
 // ============================================================================
@@ -356,6 +392,29 @@ impl CreatorWeight {
 // CREATOR PATRON CONFIG (per creator)
 // ============================================================================
 
+/// A named patron tier, e.g. Bronze / Silver / Gold
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Debug)]
+pub struct PatronTierConfig {
+    /// Display name of the tier
+    #[max_len(MAX_PATRON_TIER_NAME_LEN)]
+    pub name: String,
+    /// SOL per month for this tier (0 = retired, closed to new subscriptions)
+    pub price: u64,
+    /// Highest content visibility level this tier unlocks (0 = support only)
+    pub max_visibility: u8,
+    /// Holder reward share of this tier in basis points (None = standard 12%)
+    /// Recorded only for now: patron payments of all tiers stream into one treasury,
+    /// so maybe_distribute_patron_pool still applies the standard split
+    pub holder_share_bps: Option<u16>,
+}
+
+impl PatronTierConfig {
+    /// Whether this tier unlocks content at the given visibility level
+    pub fn grants_visibility(&self, visibility: u8) -> bool {
+        visibility > 0 && visibility <= self.max_visibility
+    }
+}
+
 /// Creator's subscription/membership tier configuration
 /// PDA seeds: ["creator_patron_config", creator]
 #[account]
@@ -363,31 +422,70 @@ impl CreatorWeight {
 pub struct CreatorPatronConfig {
     /// The creator who owns this config
     pub creator: Pubkey,
-    /// SOL per month for support-only tier (0 = disabled)
-    pub membership_price: u64,
-    /// SOL per month for support + Level 2 access tier (0 = disabled)
-    pub subscription_price: u64,
+    /// Tier ladder, referenced by index from CreatorPatronSubscription
+    #[max_len(MAX_PATRON_TIERS)]
+    pub tiers: Vec<PatronTierConfig>,
     /// Whether patron system is active for this creator
     pub is_active: bool,
     /// Timestamp when config was created
     pub created_at: i64,
     /// Timestamp when config was last updated
     pub updated_at: i64,
+    /// Free trial length in seconds (None = no trial)
+    pub trial_duration: Option<i64>,
+}
+
+impl CreatorPatronConfig {
+    /// Validate a tier ladder: 1-8 named tiers, at least one of them priced
+    pub fn validate_tiers(tiers: &[PatronTierConfig]) -> Result<()> {
+        require!(
+            !tiers.is_empty()
+                && tiers.len() <= MAX_PATRON_TIERS
+                && tiers.iter().any(|tier| tier.price > 0),
+            ContentRegistryError::InvalidPatronConfig
+        );
+        for tier in tiers {
+            require!(
+                !tier.name.is_empty()
+                    && tier.name.len() <= MAX_PATRON_TIER_NAME_LEN
+                    && tier.max_visibility <= MAX_PATRON_TIER_VISIBILITY
+                    && tier.holder_share_bps.is_none_or(|bps| bps <= 10000),
+                ContentRegistryError::InvalidPatronConfig
+            );
+        }
+        Ok(())
+    }
+
+    /// Validate a replacement tier ladder
+    /// Subscriptions reference tiers by index, so existing tiers keep their position,
+    /// name and max_visibility - only their price may change (0 retires a tier).
+    /// New tiers can be appended
+    pub fn validate_tier_update(&self, tiers: &[PatronTierConfig]) -> Result<()> {
+        Self::validate_tiers(tiers)?;
+        require!(tiers.len() >= self.tiers.len(), ContentRegistryError::PatronTierLocked);
+        for (current, updated) in self.tiers.iter().zip(tiers) {
+            require!(
+                current.name == updated.name && current.max_visibility == updated.max_visibility,
+                ContentRegistryError::PatronTierLocked
+            );
+        }
+        Ok(())
+    }
+
+    /// Look up a tier open to new subscriptions by index
+    pub fn get_tier(&self, index: u8) -> Result<&PatronTierConfig> {
+        let tier = self.tiers
+            .get(index as usize)
+            .ok_or(ContentRegistryError::InvalidTier)?;
+        require!(tier.price > 0, ContentRegistryError::TierNotAvailable);
+        Ok(tier)
+    }
 }
 
 // ============================================================================
 // CREATOR PATRON SUBSCRIPTION (per user per creator)
 // ============================================================================
 
-/// Patron tier type
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
-pub enum PatronTier {
-    /// Support only, no content access
-    Membership,
-    /// Support + Level 2 content access
-    Subscription,
-}
-
 /// Tracks a user's subscription to a specific creator
 /// PDA seeds: ["creator_patron_sub", subscriber, creator]
 #[account]
@@ -397,8 +495,8 @@ pub struct CreatorPatronSubscription {
     pub subscriber: Pubkey,
     /// The creator being subscribed to
     pub creator: Pubkey,
-    /// Membership or Subscription tier
-    pub tier: PatronTier,
+    /// Index into CreatorPatronConfig.tiers
+    pub tier_index: u8,
     /// Streamflow stream account for ongoing payments
     pub stream_id: Pubkey,
     /// Timestamp when subscription started
@@ -426,6 +524,8 @@ pub struct EcosystemSubConfig {
     pub created_at: i64,
     /// Timestamp when config was last updated
     pub updated_at: i64,
+    /// Free trial length in seconds (None = no trial)
+    pub trial_duration: Option<i64>,
 }
 
 // ============================================================================
@@ -447,6 +547,118 @@ pub struct EcosystemSubscription {
     pub is_active: bool,
 }
 
+// ============================================================================
+// TRIAL MARKER (per wallet per creator, or per wallet for the ecosystem)
+// ============================================================================
+
+/// Records that a wallet used its free trial; never closed so trials can't be farmed
+/// PDA seeds: ["trial_marker", wallet, scope]
+/// scope is the creator for patron trials, Pubkey::default() for the ecosystem trial
+#[account]
+#[derive(InitSpace)]
+pub struct TrialMarker {
+    /// Wallet that took the trial
+    pub wallet: Pubkey,
+    /// Creator, or Pubkey::default() for the ecosystem
+    pub scope: Pubkey,
+    /// Patron tier granted during the trial (None for ecosystem trials)
+    pub tier_index: Option<u8>,
+    /// Timestamp when the trial started
+    pub started_at: i64,
+    /// Timestamp when the trial ends
+    pub expires_at: i64,
+}
+
+impl TrialMarker {
+    /// Whether the trial window is still open
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.expires_at
+    }
+}
+
+// ============================================================================
+// SUBSCRIPTION VAULT (per wallet per creator, or per wallet for the ecosystem)
+// ============================================================================
+
+/// Program-native subscription paid from escrow instead of a Streamflow stream
+/// The subscriber prepays whole periods; each period is released to the treasury when it starts
+/// PDA seeds: ["subscription_vault", subscriber, scope]
+/// scope is the creator for patron vaults, Pubkey::default() for the ecosystem
+#[account]
+#[derive(InitSpace)]
+pub struct SubscriptionVault {
+    /// The subscriber's wallet
+    pub subscriber: Pubkey,
+    /// Creator, or Pubkey::default() for the ecosystem
+    pub scope: Pubkey,
+    /// Patron tier paid for (None for ecosystem vaults)
+    pub tier_index: Option<u8>,
+    /// Lamports per period, locked at the price when the vault was opened
+    pub price_per_period: u64,
+    /// Periods prepaid (trimmed to periods_released on cancel)
+    pub periods_paid: u16,
+    /// Periods already released to the treasury
+    pub periods_released: u16,
+    /// Timestamp when the first period started
+    pub started_at: i64,
+}
+
+impl SubscriptionVault {
+    /// End of the last prepaid period
+    pub fn paid_through(&self) -> i64 {
+        self.started_at + self.periods_paid as i64 * SUBSCRIPTION_VAULT_PERIOD
+    }
+
+    /// Whether the subscription is still paid for
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.paid_through()
+    }
+
+    /// Periods that have started by `now` (and so may be released), capped at periods_paid
+    pub fn periods_started(&self, now: i64) -> u16 {
+        if now < self.started_at {
+            return 0;
+        }
+        let started = (now - self.started_at) / SUBSCRIPTION_VAULT_PERIOD + 1;
+        started.min(self.periods_paid as i64) as u16
+    }
+
+    /// Periods that have started but are not yet released
+    pub fn periods_due(&self, now: i64) -> u16 {
+        self.periods_started(now).saturating_sub(self.periods_released)
+    }
+
+    /// Lamports held for `periods` at the locked price
+    pub fn amount_for(&self, periods: u16) -> Result<u64> {
+        self.price_per_period
+            .checked_mul(periods as u64)
+            .ok_or(ContentRegistryError::VaultAmountOverflow.into())
+    }
+
+    /// Whether everything is released and the last period has ended
+    pub fn is_finished(&self, now: i64) -> bool {
+        self.periods_released == self.periods_paid && !self.is_active(now)
+    }
+
+    /// Drop the unreleased periods so access ends with the current period
+    /// Returns (refunded periods, refund in lamports)
+    pub fn cancel_unreleased(&mut self) -> Result<(u16, u64)> {
+        let refunded_periods = self.periods_paid - self.periods_released;
+        let refund = self.amount_for(refunded_periods)?;
+        self.periods_paid = self.periods_released;
+        Ok((refunded_periods, refund))
+    }
+}
+
+/// Validate a trial length: None, or 1 second up to MAX_TRIAL_DURATION
+pub fn validate_trial_duration(trial_duration: Option<i64>) -> Result<()> {
+    require!(
+        trial_duration.is_none_or(|duration| duration > 0 && duration <= MAX_TRIAL_DURATION),
+        ContentRegistryError::InvalidTrialDuration
+    );
+    Ok(())
+}
+
 // ============================================================================
 // HELPER FUNCTIONS
 // ============================================================================
@@ -470,3 +682,159 @@ pub fn calculate_ecosystem_split(amount: u64) -> (u64, u64, u64, u64) {
     let creator_share = amount - platform_share - ecosystem_share - holder_share; // 80%
     (creator_share, holder_share, platform_share, ecosystem_share)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tier(name: &str, price: u64, max_visibility: u8) -> PatronTierConfig {
+        PatronTierConfig { name: name.to_string(), price, max_visibility, holder_share_bps: None }
+    }
+
+    fn config(tiers: Vec<PatronTierConfig>) -> CreatorPatronConfig {
+        CreatorPatronConfig {
+            creator: Pubkey::default(),
+            tiers,
+            is_active: true,
+            created_at: 0,
+            updated_at: 0,
+            trial_duration: None,
+        }
+    }
+
+    fn ladder() -> Vec<PatronTierConfig> {
+        vec![tier("Bronze", 100, 0), tier("Silver", 200, 1), tier("Gold", 500, 2)]
+    }
+
+    #[test]
+    fn validate_tiers_requires_a_priced_tier() {
+        assert!(CreatorPatronConfig::validate_tiers(&ladder()).is_ok());
+        assert!(CreatorPatronConfig::validate_tiers(&[tier("Bronze", 0, 0), tier("Silver", 200, 1)]).is_ok());
+        assert!(CreatorPatronConfig::validate_tiers(&[tier("Bronze", 0, 0)]).is_err());
+        assert!(CreatorPatronConfig::validate_tiers(&[]).is_err());
+        assert!(CreatorPatronConfig::validate_tiers(&[tier("", 100, 0)]).is_err());
+        assert!(CreatorPatronConfig::validate_tiers(&[tier("Gold", 100, MAX_PATRON_TIER_VISIBILITY + 1)]).is_err());
+
+        let mut share = tier("Gold", 100, 2);
+        share.holder_share_bps = Some(10000);
+        assert!(CreatorPatronConfig::validate_tiers(&[share.clone()]).is_ok());
+        share.holder_share_bps = Some(10001);
+        assert!(CreatorPatronConfig::validate_tiers(&[share]).is_err());
+    }
+
+    #[test]
+    fn tier_update_allows_price_edits_and_appends() {
+        let current = config(ladder());
+
+        let mut repriced = ladder();
+        repriced[1].price = 250;
+        repriced[0].price = 0;
+        assert!(current.validate_tier_update(&repriced).is_ok());
+
+        let mut appended = ladder();
+        appended.push(tier("Platinum", 1000, 2));
+        assert!(current.validate_tier_update(&appended).is_ok());
+    }
+
+    #[test]
+    fn tier_update_rejects_removing_reordering_or_changing_visibility() {
+        let current = config(ladder());
+        let locked: Error = ContentRegistryError::PatronTierLocked.into();
+
+        let removed = ladder()[..2].to_vec();
+        assert_eq!(current.validate_tier_update(&removed).unwrap_err(), locked);
+
+        let mut reordered = ladder();
+        reordered.swap(0, 2);
+        assert_eq!(current.validate_tier_update(&reordered).unwrap_err(), locked);
+
+        let mut rescoped = ladder();
+        rescoped[1].max_visibility = 2;
+        assert_eq!(current.validate_tier_update(&rescoped).unwrap_err(), locked);
+    }
+
+    #[test]
+    fn retired_tier_is_closed_to_new_subscriptions() {
+        let mut tiers = ladder();
+        tiers[2].price = 0;
+        let config = config(tiers);
+
+        assert_eq!(config.get_tier(2).unwrap_err(), ContentRegistryError::TierNotAvailable.into());
+        assert_eq!(config.get_tier(1).unwrap().price, 200);
+        assert_eq!(config.get_tier(3).unwrap_err(), ContentRegistryError::InvalidTier.into());
+        // Existing subscribers keep the retired tier's access
+        assert!(config.tiers[2].grants_visibility(2));
+    }
+
+    const MONTH: i64 = SUBSCRIPTION_VAULT_PERIOD;
+    const OPENED: i64 = 1_700_000_000;
+
+    fn vault(price_per_period: u64, periods: u16) -> SubscriptionVault {
+        SubscriptionVault {
+            subscriber: Pubkey::new_unique(),
+            scope: Pubkey::default(),
+            tier_index: None,
+            price_per_period,
+            periods_paid: periods,
+            periods_released: 0,
+            started_at: OPENED,
+        }
+    }
+
+    #[test]
+    fn vault_periods_start_monthly_up_to_periods_paid() {
+        let vault = vault(100, 3);
+        assert_eq!(vault.paid_through(), OPENED + 3 * MONTH);
+
+        assert_eq!(vault.periods_started(OPENED - 1), 0);
+        assert_eq!(vault.periods_started(OPENED), 1);
+        assert_eq!(vault.periods_started(OPENED + MONTH - 1), 1);
+        assert_eq!(vault.periods_started(OPENED + MONTH), 2);
+        assert_eq!(vault.periods_started(OPENED + 10 * MONTH), 3);
+    }
+
+    #[test]
+    fn vault_releases_only_unreleased_started_periods() {
+        let mut vault = vault(100, 3);
+        assert_eq!(vault.periods_due(OPENED), 1);
+
+        vault.periods_released = 1;
+        assert_eq!(vault.periods_due(OPENED + MONTH - 1), 0);
+        assert_eq!(vault.periods_due(OPENED + 2 * MONTH), 2);
+        assert_eq!(vault.amount_for(vault.periods_due(OPENED + 2 * MONTH)).unwrap(), 200);
+    }
+
+    #[test]
+    fn vault_cancel_refunds_unreleased_periods() {
+        let mut vault = vault(100, 12);
+        vault.periods_released = vault.periods_started(OPENED + MONTH);
+
+        assert_eq!(vault.cancel_unreleased().unwrap(), (10, 1_000));
+        assert_eq!(vault.periods_paid, 2);
+        // Access runs to the end of the current period
+        assert_eq!(vault.paid_through(), OPENED + 2 * MONTH);
+        assert!(vault.is_active(OPENED + 2 * MONTH - 1));
+        assert_eq!(vault.cancel_unreleased().unwrap(), (0, 0));
+    }
+
+    #[test]
+    fn vault_closes_once_released_and_ended() {
+        let mut vault = vault(100, 2);
+        vault.periods_released = 1;
+        assert!(!vault.is_finished(OPENED + 2 * MONTH));
+
+        vault.periods_released = 2;
+        assert!(!vault.is_finished(OPENED + 2 * MONTH - 1));
+        assert!(vault.is_finished(OPENED + 2 * MONTH));
+    }
+
+    #[test]
+    fn vault_amount_overflow_is_an_error() {
+        let vault = vault(u64::MAX / 2, MAX_SUBSCRIPTION_VAULT_PERIODS);
+        assert_eq!(
+            vault.amount_for(MAX_SUBSCRIPTION_VAULT_PERIODS).unwrap_err(),
+            ContentRegistryError::VaultAmountOverflow.into()
+        );
+        assert_eq!(vault.amount_for(2).unwrap(), u64::MAX - 1);
+    }
+}
@@ -1,4 +1,4 @@
-use solana_sha256_hasher::hash;
+use solana_sha256_hasher::{hash, hashv};
 
 /// Hash a CID string to derive PDA seeds.
 /// Uses Solana's hash to create a 32-byte hash.
@@ -6,3 +6,18 @@ pub fn hash_cid(cid: &str) -> [u8; 32] {
     let result = hash(cid.as_bytes());
     result.to_bytes()
 }
+
+/// Verify a Merkle proof for a wallet against an allowlist root.
+/// Leaves are sha256(wallet); parent nodes hash the sorted pair of children,
+/// so proofs don't need left/right flags.
+pub fn verify_allowlist_proof(proof: &[[u8; 32]], root: &[u8; 32], wallet: &[u8; 32]) -> bool {
+    let mut node = hash(wallet).to_bytes();
+    for sibling in proof {
+        node = if node <= *sibling {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        };
+    }
+    node == *root
+}